@@ -1,3 +1,3 @@
 cargo +nightly fmt --all --check
 RUSTFLAGS="-Dclippy::all" cargo +nightly clippy --all-targets --all-features
-cargo test
\ No newline at end of file
+cargo test --all-features
\ No newline at end of file
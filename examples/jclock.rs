@@ -33,48 +33,48 @@ type TuiDisplay = StaticPixelDisplay<ColorOctPixel, WIDTH, HEIGHT>;
 fn main() {
     let disp = TuiDisplay::new(RGBColor::GRAY.into());
 
-    let mut display = DisplayDriver::new(disp);
-
-    display.initialize().expect("Could not initialize display.");
-    display.set_target_frame_rate(10.);
-    display.set_on_update(move |disp, _| {
-        let now = Local::now();
-        #[allow(clippy::cast_possible_truncation)]
-        let hour = now.hour() as u8;
-        #[allow(clippy::cast_possible_truncation)]
-        let minute = now.minute() as u8;
-
-        let digits = [hour / 10, hour % 10, minute / 10, minute % 10];
-
-        let padding = 2;
-        let segment_width = WIDTH / 4 - padding * 2;
-        let segment_height = HEIGHT - padding * 2;
-
-        disp.draw(
-            &Rectangle {
-                x1: 0.,
-                y1: 0.,
-                x2: WIDTH as f32 - 1.,
-                y2: HEIGHT as f32 - 1.,
-                fill: PhantomData::<Filled>,
-            },
-            RGBColor::GRAY.into(),
-        );
-
-        for (i, digit) in digits.iter().enumerate() {
-            Segments::from_digit(*digit).draw_segments(
-                disp,
-                (segment_width + 2 * padding) * i + padding,
-                padding,
-                segment_width,
-                segment_height,
-                RGBColor::BLACK.into(),
+    let mut display = DisplayDriver::builder(disp)
+        .frame_rate(10.)
+        .on_update(move |disp, _| {
+            let now = Local::now();
+            #[allow(clippy::cast_possible_truncation)]
+            let hour = now.hour() as u8;
+            #[allow(clippy::cast_possible_truncation)]
+            let minute = now.minute() as u8;
+
+            let digits = [hour / 10, hour % 10, minute / 10, minute % 10];
+
+            let padding = 2;
+            let segment_width = WIDTH / 4 - padding * 2;
+            let segment_height = HEIGHT - padding * 2;
+
+            disp.draw(
+                &Rectangle {
+                    x1: 0.,
+                    y1: 0.,
+                    x2: WIDTH as f32 - 1.,
+                    y2: HEIGHT as f32 - 1.,
+                    fill: PhantomData::<Filled>,
+                },
+                RGBColor::GRAY.into(),
             );
-        }
 
-        UpdateStatus::Continue
-    });
+            for (i, digit) in digits.iter().enumerate() {
+                Segments::from_digit(*digit).draw_segments(
+                    disp,
+                    (segment_width + 2 * padding) * i + padding,
+                    padding,
+                    segment_width,
+                    segment_height,
+                    RGBColor::BLACK.into(),
+                );
+            }
+
+            UpdateStatus::Continue
+        })
+        .build();
 
+    display.initialize().expect("Could not initialize display.");
     display.update().expect("Could not update display.");
 }
 
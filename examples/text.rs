@@ -3,11 +3,12 @@
 #![allow(clippy::unwrap_used)]
 
 use console_display::{
-    character_display::StaticCharacterDisplay,
+    character_display::{
+        StaticCharacterDisplay,
+        TextCursor,
+    },
     color::TerminalColor,
-    console_display::DynamicConsoleDisplay,
     display_driver::DisplayDriver,
-    drawing::DynamicCanvas,
     pixel::character_pixel::CharacterPixel,
 };
 
@@ -20,35 +21,16 @@ fn main() {
             ),
         );
 
-    let mut x = 0;
-    let mut y = 0;
-    for i in "Lorem ipsum dolor sit amet,\n\
-        consetetur sadipscing elitr,\n\
-        sed diam nonumy eirmod tempor invidunt\n\
-        ut labore et dolore magna aliquyam erat,\n\
-        sed diam voluptua."
-        .chars()
-    {
-        if i == '\n' {
-            y += 1;
-            x = 0;
-            continue;
-        }
-        let pixel = CharacterPixel::build(
-            i,
-            TerminalColor::Default,
-            TerminalColor::Default,
+    let mut cursor = TextCursor::new(&mut char_disp);
+    cursor
+        .put_str(
+            "Lorem ipsum dolor sit amet,\n\
+            consetetur sadipscing elitr,\n\
+            sed diam nonumy eirmod tempor invidunt\n\
+            ut labore et dolore magna aliquyam erat,\n\
+            sed diam voluptua.",
         )
         .unwrap();
-        char_disp.set_pixel(x, y, pixel.into()).unwrap();
-        if x + pixel.width() > char_disp.width() {
-            y += 1;
-            x = 0;
-        }
-        else {
-            x += pixel.width();
-        }
-    }
 
     let mut display = DisplayDriver::new(char_disp);
 
@@ -1,5 +1,9 @@
 //! Provides abstractions over colors that are used in terminal context.
 
+use std::fmt;
+
+use crate::error::WRITING_TO_STRING_FAILED;
+
 /// Defines a color used to color text.
 pub const trait Color
 where
@@ -219,37 +223,12 @@ impl Color for TerminalColor {
         foreground_color: &Self,
         background_color: &Self,
     ) -> String {
-        let mut codes = Vec::new();
-
-        if let Self::ARGBColor(top_color) = foreground_color {
-            if let Self::ARGBColor(bottom_color) = *background_color {
-                let top_color = ARGBColor::blend(top_color, &bottom_color);
-                codes.push(format!(
-                    "\x1b[38;2;{};{};{}m",
-                    top_color.color.r,
-                    top_color.color.g,
-                    top_color.color.b, // foreground color
-                ));
-            }
-            codes.push(format!(
-                "\x1b[38;2;{};{};{}m",
-                top_color.color.r,
-                top_color.color.g,
-                top_color.color.b, // foreground color
-            ));
-        }
-        if let Self::ARGBColor(background_color) = background_color {
-            codes.push(format!(
-                "\x1b[48;2;{};{};{}m",
-                background_color.color.r,
-                background_color.color.g,
-                background_color.color.b, // background color
-            ));
-        }
-        if codes.is_empty() {
-            return text.to_owned();
-        }
-        format!("{}{text}{}", codes.join(""), "\x1b[0m")
+        Self::color_with_depth(
+            text,
+            foreground_color,
+            background_color,
+            ColorDepth::TrueColor,
+        )
     }
 
     fn distance(color1: &Self, color2: &Self) -> f32 {
@@ -277,6 +256,343 @@ impl Color for TerminalColor {
     }
 }
 
+impl TerminalColor {
+    /// Returns the error of rendering the colors with one color per group,
+    /// which is the sum of the distances between each color and the mix of its group.
+    #[must_use]
+    pub fn grouping_error<const N: usize>(
+        colors: &[Self; N],
+        grouping: &[bool; N],
+    ) -> f32 {
+        // Collected on the stack, as this is called for every candidate of `group_best`.
+        let mut groups =
+            ([ARGBColor::TRANSPARENT; N], [ARGBColor::TRANSPARENT; N]);
+        let mut lengths = (0, 0);
+        for (color, in_first) in colors.iter().zip(grouping) {
+            // Default colors are ignored, like by `mix`.
+            if let Self::ARGBColor(color) = color {
+                if *in_first {
+                    groups.0[lengths.0] = *color;
+                    lengths.0 += 1;
+                }
+                else {
+                    groups.1[lengths.1] = *color;
+                    lengths.1 += 1;
+                }
+            }
+        }
+        let mix = |group: &[ARGBColor]| {
+            if group.is_empty() {
+                Self::Default
+            }
+            else {
+                Self::ARGBColor(ARGBColor::mix(group))
+            }
+        };
+        let mixes =
+            (mix(&groups.0[..lengths.0]), mix(&groups.1[..lengths.1]));
+        colors
+            .iter()
+            .zip(grouping)
+            .map(|(color, in_first)| {
+                Self::distance(
+                    color,
+                    if *in_first { &mixes.0 } else { &mixes.1 },
+                )
+            })
+            .sum()
+    }
+
+    /// Splits the colors into two groups, like `group`, but picks the split with the lowest `grouping_error`.
+    /// Identical colors are kept in the same group,
+    /// so blocks with at most two distinct colors are split exactly by `group`.
+    /// Otherwise all splits of the distinct colors are scored,
+    /// which are at most 128 for the 8 colors of the crate's largest pixel type.
+    /// Blocks with more than 8 distinct colors fall back to `group`.
+    #[must_use]
+    pub fn group_best<const N: usize>(colors: &[Self; N]) -> [bool; N] {
+        const MAX_DISTINCT: usize = 8;
+
+        let grouping = Self::group(colors);
+        // Maps every color to the index of its first occurrence among the distinct colors.
+        let mut distinct = [0; N];
+        let mut distinct_len = 0;
+        let mut indices = [0; N];
+        for (i, color) in colors.iter().enumerate() {
+            let index = distinct[..distinct_len]
+                .iter()
+                .position(|&j| colors[j] == *color);
+            indices[i] = index.unwrap_or_else(|| {
+                distinct[distinct_len] = i;
+                distinct_len += 1;
+                distinct_len - 1
+            });
+        }
+        if distinct_len <= 2 || distinct_len > MAX_DISTINCT {
+            return grouping;
+        }
+
+        let mut best = (Self::grouping_error(colors, &grouping), grouping);
+        // Swapping both groups results in the same error,
+        // so the last distinct color can always be placed in the second group.
+        for mask in 1..1usize << (distinct_len - 1) {
+            let candidate: [bool; N] =
+                std::array::from_fn(|i| mask & (1 << indices[i]) != 0);
+            let error = Self::grouping_error(colors, &candidate);
+            if error < best.0 {
+                best = (error, candidate);
+            }
+        }
+        best.1
+    }
+
+    /// Mixes a list of colors into one by a weighted average.
+    /// Like `mix`, default colors are ignored and the remaining weights are renormalized.
+    /// If the slices differ in length, the longer one is truncated to the shorter one.
+    /// Returns the default color if no colors with a positive weight remain.
+    #[must_use]
+    pub fn mix_weighted(colors: &[Self], weights: &[f32]) -> Self {
+        let mut argb_colors = Vec::with_capacity(colors.len());
+        let mut argb_weights = Vec::with_capacity(colors.len());
+        for (color, weight) in colors.iter().zip(weights) {
+            if let Self::ARGBColor(argbcolor) = color &&
+                *weight > 0.
+            {
+                argb_colors.push(*argbcolor);
+                argb_weights.push(*weight);
+            }
+        }
+        if argb_colors.is_empty() {
+            return Self::Default;
+        }
+
+        Self::ARGBColor(ARGBColor::mix_weighted(
+            &argb_colors,
+            &argb_weights,
+        ))
+    }
+
+    /// Blends the top with the bottom color like `blend`, but mixes translucent colors in linear light.
+    #[must_use]
+    pub fn blend_linear(color_top: &Self, color_bottom: &Self) -> Self {
+        if let Self::ARGBColor(color_top) = color_top &&
+            let Self::ARGBColor(color_bottom) = color_bottom
+        {
+            return Self::ARGBColor(ARGBColor::blend_linear(
+                color_top,
+                color_bottom,
+            ));
+        }
+        Self::blend(color_top, color_bottom)
+    }
+
+    /// Converts the color to a gray of the same luma, keeping its opacity.
+    /// The default color is left unchanged.
+    #[must_use]
+    pub fn to_grayscale(&self) -> Self {
+        match self {
+            Self::Default => Self::Default,
+            Self::ARGBColor(color) => Self::ARGBColor(ARGBColor {
+                opacity: color.opacity,
+                color: color.color.to_grayscale(),
+            }),
+        }
+    }
+
+    /// Inverts the color, keeping its opacity.
+    /// The default color is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     RGBColor,
+    ///     TerminalColor,
+    /// };
+    ///
+    /// let black: TerminalColor = RGBColor::BLACK.into();
+    ///
+    /// assert_eq!(black.invert(), RGBColor::WHITE.into());
+    /// assert_eq!(TerminalColor::Default.invert(), TerminalColor::Default);
+    /// ```
+    #[must_use]
+    pub const fn invert(&self) -> Self {
+        match self {
+            Self::Default => Self::Default,
+            Self::ARGBColor(color) => Self::ARGBColor(ARGBColor {
+                opacity: color.opacity,
+                color: color.color.invert(),
+            }),
+        }
+    }
+
+    /// Returns the complementary color, keeping its opacity.
+    /// The default color is left unchanged.
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        match self {
+            Self::Default => Self::Default,
+            Self::ARGBColor(color) => Self::ARGBColor(ARGBColor {
+                opacity: color.opacity,
+                color: color.color.complement(),
+            }),
+        }
+    }
+
+    /// Colors a text with a foreground and background color,
+    /// emitting escape sequences supported by the given color depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     ColorDepth,
+    ///     RGBColor,
+    ///     TerminalColor,
+    /// };
+    ///
+    /// let msg = TerminalColor::color_with_depth(
+    ///     "test",
+    ///     &RGBColor::RED.into(),
+    ///     &TerminalColor::Default,
+    ///     ColorDepth::Ansi256,
+    /// );
+    ///
+    /// assert_eq!(msg, "\x1b[38;5;196mtest\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn color_with_depth(
+        text: &str,
+        foreground_color: &Self,
+        background_color: &Self,
+        color_depth: ColorDepth,
+    ) -> String {
+        let mut colored = String::new();
+        Self::write_color_with_depth(
+            &mut colored,
+            text,
+            foreground_color,
+            background_color,
+            color_depth,
+        )
+        .expect(WRITING_TO_STRING_FAILED);
+        colored
+    }
+
+    /// Writes a text colored with a foreground and background color to the writer,
+    /// without allocating intermediate strings.
+    /// The output is the same as the output of [`Color::color`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     Color,
+    ///     RGBColor,
+    ///     TerminalColor,
+    /// };
+    ///
+    /// let mut msg = String::new();
+    /// TerminalColor::write_color(
+    ///     &mut msg,
+    ///     "test",
+    ///     &RGBColor::RED.into(),
+    ///     &TerminalColor::Default,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(msg, "\x1b[38;2;255;0;0mtest\x1b[0m");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    pub fn write_color<W: fmt::Write>(
+        writer: &mut W,
+        text: &str,
+        foreground_color: &Self,
+        background_color: &Self,
+    ) -> fmt::Result {
+        Self::write_color_with_depth(
+            writer,
+            text,
+            foreground_color,
+            background_color,
+            ColorDepth::TrueColor,
+        )
+    }
+
+    /// Writes a text colored with a foreground and background color to the writer,
+    /// emitting escape sequences supported by the given color depth.
+    /// The output is the same as the output of [`TerminalColor::color_with_depth`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    pub fn write_color_with_depth<W: fmt::Write>(
+        writer: &mut W,
+        text: &str,
+        foreground_color: &Self,
+        background_color: &Self,
+        color_depth: ColorDepth,
+    ) -> fmt::Result {
+        if *foreground_color == Self::Default &&
+            *background_color == Self::Default
+        {
+            return writer.write_str(text);
+        }
+        Self::write_color_codes(
+            writer,
+            foreground_color,
+            background_color,
+            color_depth,
+        )?;
+        writer.write_str(text)?;
+        writer.write_str("\x1b[0m")
+    }
+
+    /// Returns the escape sequences selecting a foreground and background color,
+    /// or an empty string if both are the terminal default.
+    pub(crate) fn color_codes(
+        foreground_color: &Self,
+        background_color: &Self,
+        color_depth: ColorDepth,
+    ) -> String {
+        let mut codes = String::new();
+        Self::write_color_codes(
+            &mut codes,
+            foreground_color,
+            background_color,
+            color_depth,
+        )
+        .expect(WRITING_TO_STRING_FAILED);
+        codes
+    }
+
+    /// Writes the escape sequences selecting a foreground and background color,
+    /// or nothing if both are the terminal default.
+    pub(crate) fn write_color_codes<W: fmt::Write>(
+        writer: &mut W,
+        foreground_color: &Self,
+        background_color: &Self,
+        color_depth: ColorDepth,
+    ) -> fmt::Result {
+        if let Self::ARGBColor(top_color) = foreground_color {
+            if let Self::ARGBColor(bottom_color) = *background_color {
+                let top_color = ARGBColor::blend(top_color, &bottom_color);
+                color_depth
+                    .write_foreground_code(writer, top_color.color)?;
+            }
+            color_depth.write_foreground_code(writer, top_color.color)?;
+        }
+        if let Self::ARGBColor(background_color) = background_color {
+            color_depth
+                .write_background_code(writer, background_color.color)?;
+        }
+        Ok(())
+    }
+}
+
 // TODO: Check if this impl can be const
 impl From<RGBColor> for TerminalColor {
     fn from(value: RGBColor) -> Self {
@@ -290,6 +606,115 @@ impl const From<ARGBColor> for TerminalColor {
     }
 }
 
+/// Defines the set of colors a terminal is able to display.
+///
+/// `TrueColor` - Emits 24-bit colors.\
+/// `Ansi256` - Maps colors to the nearest entry of the xterm 256 color palette.\
+/// `Ansi16` - Maps colors to the nearest of the 16 standard terminal colors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum ColorDepth {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorDepth {
+    fn write_foreground_code<W: fmt::Write>(
+        self,
+        writer: &mut W,
+        color: RGBColor,
+    ) -> fmt::Result {
+        match self {
+            Self::TrueColor => write!(
+                writer,
+                "\x1b[38;2;{};{};{}m",
+                color.r, color.g, color.b
+            ),
+            Self::Ansi256 => {
+                write!(writer, "\x1b[38;5;{}m", color.to_ansi256())
+            }
+            Self::Ansi16 => match color.to_ansi16() {
+                index @ 0..8 => write!(writer, "\x1b[{}m", 30 + index),
+                index => write!(writer, "\x1b[{}m", 90 + index - 8),
+            },
+        }
+    }
+
+    pub(crate) fn background_code(self, color: RGBColor) -> String {
+        let mut code = String::new();
+        self.write_background_code(&mut code, color)
+            .expect(WRITING_TO_STRING_FAILED);
+        code
+    }
+
+    fn write_background_code<W: fmt::Write>(
+        self,
+        writer: &mut W,
+        color: RGBColor,
+    ) -> fmt::Result {
+        match self {
+            Self::TrueColor => write!(
+                writer,
+                "\x1b[48;2;{};{};{}m",
+                color.r, color.g, color.b
+            ),
+            Self::Ansi256 => {
+                write!(writer, "\x1b[48;5;{}m", color.to_ansi256())
+            }
+            Self::Ansi16 => match color.to_ansi16() {
+                index @ 0..8 => write!(writer, "\x1b[{}m", 40 + index),
+                index => write!(writer, "\x1b[{}m", 100 + index - 8),
+            },
+        }
+    }
+}
+
+/// Standard xterm values of the 16 system colors.
+pub(crate) const XTERM_SYSTEM_COLORS: [RGBColor; 16] = [
+    RGBColor { r: 0, g: 0, b: 0 },
+    RGBColor { r: 205, g: 0, b: 0 },
+    RGBColor { r: 0, g: 205, b: 0 },
+    RGBColor {
+        r: 205,
+        g: 205,
+        b: 0,
+    },
+    RGBColor { r: 0, g: 0, b: 238 },
+    RGBColor {
+        r: 205,
+        g: 0,
+        b: 205,
+    },
+    RGBColor {
+        r: 0,
+        g: 205,
+        b: 205,
+    },
+    RGBColor {
+        r: 229,
+        g: 229,
+        b: 229,
+    },
+    RGBColor {
+        r: 127,
+        g: 127,
+        b: 127,
+    },
+    RGBColor::RED,
+    RGBColor::GREEN,
+    RGBColor::YELLOW,
+    RGBColor {
+        r: 92,
+        g: 92,
+        b: 255,
+    },
+    RGBColor::MAGENTA,
+    RGBColor::CYAN,
+    RGBColor::WHITE,
+];
+
 /// Defines an RGB color used for foreground and background coloring of text.
 /// `r`, `g`, `b` are the red, green and blue components of the color respectively.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -333,7 +758,11 @@ impl Color for RGBColor {
         .sqrt()
     }
 
+    /// Returns black for an empty list.
     fn mix(colors: &[Self]) -> Self {
+        if colors.is_empty() {
+            return Self::BLACK;
+        }
         let mut sum = (0, 0, 0);
         for color in colors {
             sum.0 += usize::from(color.r);
@@ -391,69 +820,666 @@ impl RGBColor {
         g: 0,
         b: 255,
     };
-}
 
-/// Defines an ARGB color used for foreground and background coloring of text.
-/// `color` are the opaque RGB components of the color with an additional `opacity`.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct ARGBColor {
-    pub opacity: u8,
-    pub color: RGBColor,
-}
+    /// Renders the colors as a horizontal strip of full blocks, one cell per color,
+    /// e.g. to print a palette for debugging.
+    /// Consecutive equal colors share a single escape sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// println!("{}", RGBColor::swatch(&[RGBColor::RED, RGBColor::BLUE]));
+    /// ```
+    #[must_use]
+    pub fn swatch(colors: &[Self]) -> String {
+        colors
+            .chunk_by(|a, b| a == b)
+            .map(|run| {
+                TerminalColor::color(
+                    &"█".repeat(run.len()),
+                    &run[0].into(),
+                    &TerminalColor::Default,
+                )
+            })
+            .collect()
+    }
 
-// TODO: Check if this impl can be const
-impl Color for ARGBColor {
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_sign_loss)]
-    fn blend(color_top: &Self, color_bottom: &Self) -> Self {
-        let opacity_top = f32::from(color_top.opacity) / 255.;
-        let opacity_bottom = f32::from(color_bottom.opacity) / 255.;
-        let opacity_res =
-            opacity_bottom.mul_add(1. - opacity_top, opacity_top);
-        let red = f32::from(color_top.color.r).mul_add(
-            opacity_top,
-            f32::from(color_bottom.color.r) *
-                (1. - opacity_top) *
-                opacity_bottom,
-        ) / opacity_res;
-        let green = f32::from(color_top.color.g).mul_add(
-            opacity_top,
-            f32::from(color_bottom.color.g) *
-                (1. - opacity_top) *
-                opacity_bottom,
-        ) / opacity_res;
-        let blue = f32::from(color_top.color.b).mul_add(
-            opacity_top,
-            f32::from(color_bottom.color.b) *
-                (1. - opacity_top) *
-                opacity_bottom,
-        ) / opacity_res;
-        Self {
-            opacity: (opacity_res * 255.).clamp(0., 255.) as u8,
-            color: RGBColor {
-                r: red.clamp(0., 255.) as u8,
-                g: green.clamp(0., 255.) as u8,
-                b: blue.clamp(0., 255.) as u8,
-            },
+    /// Looks up one of the named color constants, e.g. `"red"` or `"dark gray"`.
+    /// The lookup ignores case, and spaces, hyphens and underscores between words.
+    /// Returns `None` for unknown names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from_name("Cyan"), Some(RGBColor::CYAN));
+    /// assert_eq!(RGBColor::from_name("orange"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        match name.as_str() {
+            "black" => Some(Self::BLACK),
+            "darkgray" | "darkgrey" => Some(Self::DARK_GRAY),
+            "gray" | "grey" => Some(Self::GRAY),
+            "lightgray" | "lightgrey" => Some(Self::LIGHT_GRAY),
+            "white" => Some(Self::WHITE),
+            "red" => Some(Self::RED),
+            "green" => Some(Self::GREEN),
+            "blue" => Some(Self::BLUE),
+            "yellow" => Some(Self::YELLOW),
+            "cyan" => Some(Self::CYAN),
+            "magenta" => Some(Self::MAGENTA),
+            _ => None,
         }
     }
 
-    fn color(
-        text: &str,
-        foreground_color: &Self,
-        background_color: &Self,
-    ) -> String {
-        format!(
-            "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m{}\x1b[0m",
-            foreground_color.color.r,
-            foreground_color.color.g,
-            foreground_color.color.b, // foreground color
-            background_color.color.r,
-            background_color.color.g,
-            background_color.color.b, // background color
-            text
-        )
-    }
+    /// Returns the color of the given index in the xterm 256 color palette.
+    /// Indices below 16 map to the standard xterm values of the system colors,
+    /// which may differ from the colors configured in a particular terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from_ansi256(196), RGBColor::RED);
+    /// assert_eq!(RGBColor::from_ansi256(231), RGBColor::WHITE);
+    /// ```
+    #[must_use]
+    pub const fn from_ansi256(index: u8) -> Self {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        match index {
+            0..16 => XTERM_SYSTEM_COLORS[index as usize],
+            16..232 => {
+                let index = index - 16;
+                Self {
+                    r: CUBE_LEVELS[(index / 36) as usize],
+                    g: CUBE_LEVELS[(index / 6 % 6) as usize],
+                    b: CUBE_LEVELS[(index % 6) as usize],
+                }
+            }
+            232.. => {
+                let level = 8 + (index - 232) * 10;
+                Self {
+                    r: level,
+                    g: level,
+                    b: level,
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the nearest color in the xterm 256 color palette.
+    /// Only the color cube and grayscale ramp (indices 16 to 255) are considered,
+    /// since the system colors are commonly redefined by terminal themes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::RED.to_ansi256(), 196);
+    /// assert_eq!(RGBColor::BLACK.to_ansi256(), 16);
+    /// ```
+    #[must_use]
+    pub fn to_ansi256(&self) -> u8 {
+        let cube = 16 +
+            36 * Self::cube_level(self.r) +
+            6 * Self::cube_level(self.g) +
+            Self::cube_level(self.b);
+        // The nearest gray is the one nearest to the mean of the channels.
+        let sum =
+            u16::from(self.r) + u16::from(self.g) + u16::from(self.b);
+        #[allow(clippy::cast_possible_truncation)]
+        let gray = 232 + (sum.saturating_sub(10) / 30).min(23) as u8;
+        if self.squared_distance(&Self::from_ansi256(gray)) <
+            self.squared_distance(&Self::from_ansi256(cube))
+        {
+            gray
+        }
+        else {
+            cube
+        }
+    }
+
+    /// Returns the index of the nearest level of the 6x6x6 color cube to a channel.
+    /// Values halfway between two levels are mapped to the lower one.
+    const fn cube_level(value: u8) -> u8 {
+        match value {
+            0..48 => 0,
+            48..116 => 1,
+            _ => (value - 36) / 40,
+        }
+    }
+
+    /// Returns the squared euclidean distance between the channels of two colors.
+    fn squared_distance(&self, other: &Self) -> u32 {
+        [(self.r, other.r), (self.g, other.g), (self.b, other.b)]
+            .into_iter()
+            .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+            .sum()
+    }
+
+    /// Returns the index of the nearest of the 16 standard terminal colors.
+    /// Indices 8 to 15 denote the bright variants of indices 0 to 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::WHITE.to_ansi16(), 15);
+    /// assert_eq!(RGBColor::BLACK.to_ansi16(), 0);
+    /// ```
+    #[must_use]
+    pub fn to_ansi16(&self) -> u8 {
+        let mut nearest = 0;
+        let mut min_distance = f32::MAX;
+        for index in 0..16 {
+            let distance =
+                Self::distance(self, &XTERM_SYSTEM_COLORS[index as usize]);
+            if distance < min_distance {
+                min_distance = distance;
+                nearest = index;
+            }
+        }
+        nearest
+    }
+
+    /// Constructs a color from hue, saturation and value.
+    /// The hue is given in degrees and wraps around at 360.
+    /// Saturation and value are clamped to the range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from_hsv(0., 1., 1.), RGBColor::RED);
+    /// assert_eq!(RGBColor::from_hsv(480., 1., 1.), RGBColor::GREEN);
+    /// assert_eq!(RGBColor::from_hsv(123., 0., 1.), RGBColor::WHITE);
+    /// ```
+    #[must_use]
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let saturation = saturation.clamp(0., 1.);
+        let value = value.clamp(0., 1.);
+        let chroma = value * saturation;
+        Self::from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Constructs a color from hue, saturation and lightness.
+    /// The hue is given in degrees and wraps around at 360.
+    /// Saturation and lightness are clamped to the range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from_hsl(0., 1., 0.5), RGBColor::RED);
+    /// assert_eq!(RGBColor::from_hsl(240., 1., 0.5), RGBColor::BLUE);
+    /// assert_eq!(RGBColor::from_hsl(0., 1., 1.), RGBColor::WHITE);
+    /// ```
+    #[must_use]
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let saturation = saturation.clamp(0., 1.);
+        let lightness = lightness.clamp(0., 1.);
+        let chroma =
+            (1. - 2f32.mul_add(lightness, -1.).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, chroma.mul_add(-0.5, lightness))
+    }
+
+    /// Returns the hue (in degrees from 0 to 360), saturation and value of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::CYAN.to_hsv(), (180., 1., 1.));
+    /// ```
+    #[must_use]
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let saturation = if max > 0. { (max - min) / max } else { 0. };
+        (hue, saturation, max)
+    }
+
+    /// Returns the hue (in degrees from 0 to 360), saturation and lightness of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::MAGENTA.to_hsl(), (300., 1., 0.5));
+    /// ```
+    #[must_use]
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let lightness = f32::midpoint(max, min);
+        let saturation = if max > min {
+            (max - min) / (1. - 2f32.mul_add(lightness, -1.).abs())
+        }
+        else {
+            0.
+        };
+        (hue, saturation, lightness)
+    }
+
+    /// Inverts every channel of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::BLACK.invert(), RGBColor::WHITE);
+    /// assert_eq!(RGBColor::WHITE.invert(), RGBColor::BLACK);
+    /// ```
+    #[must_use]
+    pub const fn invert(&self) -> Self {
+        Self {
+            r: u8::MAX - self.r,
+            g: u8::MAX - self.g,
+            b: u8::MAX - self.b,
+        }
+    }
+
+    /// Returns the complementary color by rotating the hue by 180 degrees,
+    /// keeping saturation and value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::RED.complement(), RGBColor::CYAN);
+    /// assert_eq!(RGBColor::GRAY.complement(), RGBColor::GRAY);
+    /// ```
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        self.rotate_hue(180.)
+    }
+
+    /// Rotates the hue of the color by the given number of degrees,
+    /// keeping saturation and value.
+    /// Negative angles rotate in the opposite direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::RED.rotate_hue(240.), RGBColor::BLUE);
+    /// assert_eq!(RGBColor::RED.rotate_hue(-120.), RGBColor::BLUE);
+    /// ```
+    #[must_use]
+    pub fn rotate_hue(&self, degrees: f32) -> Self {
+        let (hue, saturation, value) = self.to_hsv();
+        Self::from_hsv((hue + degrees).rem_euclid(360.), saturation, value)
+    }
+
+    /// Adds `delta` to the saturation of the color, keeping hue and value.
+    /// The resulting saturation is clamped to the range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::RED.adjust_saturation(-1.), RGBColor::WHITE);
+    /// ```
+    #[must_use]
+    pub fn adjust_saturation(&self, delta: f32) -> Self {
+        let (hue, saturation, value) = self.to_hsv();
+        Self::from_hsv(hue, saturation + delta, value)
+    }
+
+    /// Adds `delta` to the value of the color, keeping hue and saturation.
+    /// The resulting value is clamped to the range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::RED.adjust_value(-1.), RGBColor::BLACK);
+    /// ```
+    #[must_use]
+    pub fn adjust_value(&self, delta: f32) -> Self {
+        let (hue, saturation, value) = self.to_hsv();
+        Self::from_hsv(hue, saturation, value + delta)
+    }
+
+    /// Linearly interpolates between the colors `a` and `b`.
+    /// `t` is clamped to the range from 0 to 1, where 0 returns `a` and 1 returns `b`.
+    /// Channel values are rounded to the nearest integer,
+    /// with exact halves rounded down to stay consistent with `mix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(
+    ///     RGBColor::lerp(&RGBColor::BLACK, &RGBColor::WHITE, 0.5),
+    ///     RGBColor::GRAY
+    /// );
+    /// assert_eq!(
+    ///     RGBColor::lerp(&RGBColor::RED, &RGBColor::BLUE, 0.),
+    ///     RGBColor::RED
+    /// );
+    /// ```
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+        Self {
+            r: lerp_channel(a.r, b.r, t),
+            g: lerp_channel(a.g, b.g, t),
+            b: lerp_channel(a.b, b.b, t),
+        }
+    }
+
+    /// Converts the color to a gray of the same luma, using the Rec. 709 weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::WHITE.to_grayscale(), RGBColor::WHITE);
+    /// assert_eq!(
+    ///     RGBColor::BLUE.to_grayscale(),
+    ///     RGBColor {
+    ///         r: 18,
+    ///         g: 18,
+    ///         b: 18
+    ///     }
+    /// );
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn to_grayscale(&self) -> Self {
+        let luma = 0.0722f32.mul_add(
+            f32::from(self.b),
+            0.2126f32
+                .mul_add(f32::from(self.r), 0.7152 * f32::from(self.g)),
+        );
+        let level = luma.round().clamp(0., 255.) as u8;
+        Self {
+            r: level,
+            g: level,
+            b: level,
+        }
+    }
+
+    /// Returns the red, green and blue components in linear light in the range from 0 to 1,
+    /// using the sRGB transfer function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::WHITE.to_linear(), (1., 1., 1.));
+    /// assert_eq!(RGBColor::BLACK.to_linear(), (0., 0., 0.));
+    /// ```
+    #[must_use]
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        let channel = |value: u8| {
+            let value = f32::from(value) / 255.;
+            if value <= 0.040_45 {
+                value / 12.92
+            }
+            else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        (channel(self.r), channel(self.g), channel(self.b))
+    }
+
+    /// Constructs a color from red, green and blue components in linear light.
+    /// The components are clamped to the range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::RGBColor;
+    ///
+    /// let color = RGBColor {
+    ///     r: 12,
+    ///     g: 128,
+    ///     b: 250,
+    /// };
+    ///
+    /// assert_eq!(RGBColor::from_linear(color.to_linear()), color);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_linear((r, g, b): (f32, f32, f32)) -> Self {
+        let channel = |value: f32| {
+            let value = value.clamp(0., 1.);
+            let value = if value <= 0.003_130_8 {
+                value * 12.92
+            }
+            else {
+                1.055f32.mul_add(value.powf(1. / 2.4), -0.055)
+            };
+            (value * 255.).round().clamp(0., 255.) as u8
+        };
+        Self {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+        }
+    }
+
+    /// Mixes a list of colors into one by averaging them in linear light.
+    /// Compared to `mix`, this avoids the darkening of mixes between bright and dark colors.
+    /// Returns black for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     Color,
+    ///     RGBColor,
+    /// };
+    ///
+    /// let colors = [RGBColor::BLACK, RGBColor::WHITE];
+    ///
+    /// let linear = RGBColor::mix_linear(&colors);
+    /// let srgb = RGBColor::mix(&colors);
+    ///
+    /// assert!(linear.r > srgb.r);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mix_linear(colors: &[Self]) -> Self {
+        if colors.is_empty() {
+            return Self::BLACK;
+        }
+        let mut sum = (0., 0., 0.);
+        for color in colors {
+            let (r, g, b) = color.to_linear();
+            sum.0 += r;
+            sum.1 += g;
+            sum.2 += b;
+        }
+        let colors_len = colors.len() as f32;
+        Self::from_linear((
+            sum.0 / colors_len,
+            sum.1 / colors_len,
+            sum.2 / colors_len,
+        ))
+    }
+
+    /// Mixes a list of colors into one by a weighted average of their channels.
+    /// If the slices differ in length, the longer one is truncated to the shorter one.
+    /// Negative weights are treated as zero.
+    /// Returns black if there are no colors or all weights are zero.
+    /// Channels are rounded down like by `mix`, so equal weights result in the same color as `mix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     Color,
+    ///     RGBColor,
+    /// };
+    ///
+    /// let mix = RGBColor::mix_weighted(
+    ///     &[RGBColor::BLACK, RGBColor::WHITE],
+    ///     &[3., 1.],
+    /// );
+    ///
+    /// assert_eq!(mix, RGBColor { r: 63, g: 63, b: 63 });
+    /// assert_eq!(
+    ///     RGBColor::mix_weighted(&[RGBColor::BLACK, RGBColor::WHITE], &[1., 1.]),
+    ///     RGBColor::mix(&[RGBColor::BLACK, RGBColor::WHITE]),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn mix_weighted(colors: &[Self], weights: &[f32]) -> Self {
+        let mut sum = (0., 0., 0.);
+        let mut total_weight = 0.;
+        for (color, weight) in colors.iter().zip(weights) {
+            let weight = f64::from(weight.max(0.));
+            sum.0 += weight * f64::from(color.r);
+            sum.1 += weight * f64::from(color.g);
+            sum.2 += weight * f64::from(color.b);
+            total_weight += weight;
+        }
+        if total_weight <= 0. {
+            return Self::BLACK;
+        }
+        Self {
+            r: floor_channel(sum.0 / total_weight),
+            g: floor_channel(sum.1 / total_weight),
+            b: floor_channel(sum.2 / total_weight),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn from_hue_chroma(hue: f32, chroma: f32, offset: f32) -> Self {
+        let hue = hue.rem_euclid(360.) / 60.;
+        let x = chroma * (1. - (hue % 2. - 1.).abs());
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+        let channel = |value: f32| {
+            ((value + offset) * 255.).round().clamp(0., 255.) as u8
+        };
+        Self {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+        }
+    }
+
+    /// Returns the hue in degrees as well as the largest and smallest channel in the range from 0 to 1.
+    #[allow(clippy::float_cmp)]
+    fn hue_max_min(self) -> (f32, f32, f32) {
+        let r = f32::from(self.r) / 255.;
+        let g = f32::from(self.g) / 255.;
+        let b = f32::from(self.b) / 255.;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let hue = if delta == 0. {
+            0.
+        }
+        else if max == r {
+            60. * ((g - b) / delta).rem_euclid(6.)
+        }
+        else if max == g {
+            60. * ((b - r) / delta + 2.)
+        }
+        else {
+            60. * ((r - g) / delta + 4.)
+        };
+        (hue, max, min)
+    }
+}
+
+/// Defines an ARGB color used for foreground and background coloring of text.
+/// `color` are the opaque RGB components of the color with an additional `opacity`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ARGBColor {
+    pub opacity: u8,
+    pub color: RGBColor,
+}
+
+// TODO: Check if this impl can be const
+impl Color for ARGBColor {
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn blend(color_top: &Self, color_bottom: &Self) -> Self {
+        let opacity_top = f32::from(color_top.opacity) / 255.;
+        let opacity_bottom = f32::from(color_bottom.opacity) / 255.;
+        let opacity_res =
+            opacity_bottom.mul_add(1. - opacity_top, opacity_top);
+        let red = f32::from(color_top.color.r).mul_add(
+            opacity_top,
+            f32::from(color_bottom.color.r) *
+                (1. - opacity_top) *
+                opacity_bottom,
+        ) / opacity_res;
+        let green = f32::from(color_top.color.g).mul_add(
+            opacity_top,
+            f32::from(color_bottom.color.g) *
+                (1. - opacity_top) *
+                opacity_bottom,
+        ) / opacity_res;
+        let blue = f32::from(color_top.color.b).mul_add(
+            opacity_top,
+            f32::from(color_bottom.color.b) *
+                (1. - opacity_top) *
+                opacity_bottom,
+        ) / opacity_res;
+        Self {
+            opacity: (opacity_res * 255.).clamp(0., 255.) as u8,
+            color: RGBColor {
+                r: red.clamp(0., 255.) as u8,
+                g: green.clamp(0., 255.) as u8,
+                b: blue.clamp(0., 255.) as u8,
+            },
+        }
+    }
+
+    fn color(
+        text: &str,
+        foreground_color: &Self,
+        background_color: &Self,
+    ) -> String {
+        format!(
+            "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m{}\x1b[0m",
+            foreground_color.color.r,
+            foreground_color.color.g,
+            foreground_color.color.b, // foreground color
+            background_color.color.r,
+            background_color.color.g,
+            background_color.color.b, // background color
+            text
+        )
+    }
 
     fn distance(color1: &Self, color2: &Self) -> f32 {
         // Equivalent to d = sqrt(r²+g²+b²+a²)
@@ -462,20 +1488,48 @@ impl Color for ARGBColor {
         )
     }
 
+    /// Mixes the colors weighted by their opacity,
+    /// so that (nearly) transparent colors barely affect the resulting color.
+    /// The resulting opacity is the rounded average opacity.
+    /// Returns a transparent color for an empty list.
     fn mix(colors: &[Self]) -> Self {
+        if colors.is_empty() {
+            return Self::TRANSPARENT;
+        }
+
         let mut sum_opacity = 0;
+        let mut sum = (0, 0, 0);
         for color in colors {
-            sum_opacity += usize::from(color.opacity);
+            let opacity = usize::from(color.opacity);
+            sum_opacity += opacity;
+            sum.0 += usize::from(color.color.r) * opacity;
+            sum.1 += usize::from(color.color.g) * opacity;
+            sum.2 += usize::from(color.color.b) * opacity;
         }
 
         let colors_len = colors.len();
 
         #[allow(clippy::cast_possible_truncation)]
-        Self {
-            opacity: (sum_opacity / colors_len).clamp(0, 255) as u8,
-            color: RGBColor::mix(
+        let channel = |sum: usize| (sum / sum_opacity).clamp(0, 255) as u8;
+        let color = if sum_opacity == 0 {
+            // Without any opacity to weight by, fall back to an unweighted mix.
+            RGBColor::mix(
                 &colors.iter().map(|x| x.color).collect::<Vec<_>>(),
-            ),
+            )
+        }
+        else {
+            RGBColor {
+                r: channel(sum.0),
+                g: channel(sum.1),
+                b: channel(sum.2),
+            }
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        Self {
+            opacity: ((sum_opacity + colors_len / 2) / colors_len)
+                .clamp(0, 255) as u8,
+            color,
         }
     }
 }
@@ -485,6 +1539,152 @@ impl ARGBColor {
         opacity: 0,
         color: RGBColor::BLACK,
     };
+
+    /// Mixes a list of colors into one by a weighted average of their channels and opacities.
+    /// Like `mix`, the channels are additionally weighted by the opacities,
+    /// rounded down, and the opacity is rounded to the nearest value,
+    /// so equal weights result in the same color as `mix`.
+    /// Behaves like `RGBColor::mix_weighted` regarding mismatched lengths and invalid weights.
+    /// Returns a transparent color if there are no colors or all weights are zero.
+    #[must_use]
+    pub fn mix_weighted(colors: &[Self], weights: &[f32]) -> Self {
+        let mut sum_opacity = 0.;
+        let mut sum = (0., 0., 0.);
+        let mut total_weight = 0.;
+        for (color, weight) in colors.iter().zip(weights) {
+            let weight = f64::from(weight.max(0.));
+            let opacity = weight * f64::from(color.opacity);
+            sum_opacity += opacity;
+            sum.0 += opacity * f64::from(color.color.r);
+            sum.1 += opacity * f64::from(color.color.g);
+            sum.2 += opacity * f64::from(color.color.b);
+            total_weight += weight;
+        }
+        if total_weight <= 0. {
+            return Self::TRANSPARENT;
+        }
+        let color = if sum_opacity <= 0. {
+            // Without any opacity to weight by, fall back to mixing by the weights alone.
+            RGBColor::mix_weighted(
+                &colors.iter().map(|x| x.color).collect::<Vec<_>>(),
+                weights,
+            )
+        }
+        else {
+            RGBColor {
+                r: floor_channel(sum.0 / sum_opacity),
+                g: floor_channel(sum.1 / sum_opacity),
+                b: floor_channel(sum.2 / sum_opacity),
+            }
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        Self {
+            opacity: (sum_opacity / total_weight).round().clamp(0., 255.)
+                as u8,
+            color,
+        }
+    }
+
+    /// Blends the top with the bottom color like `blend`, but mixes the color channels in linear light.
+    /// This avoids the darkening that occurs when blending bright colors in sRGB space.
+    /// The opacity is blended the same way as in `blend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     ARGBColor,
+    ///     Color,
+    ///     RGBColor,
+    /// };
+    ///
+    /// let top = ARGBColor {
+    ///     opacity: 128,
+    ///     color: RGBColor::WHITE,
+    /// };
+    /// let bottom = RGBColor::BLACK.into();
+    ///
+    /// let linear = ARGBColor::blend_linear(&top, &bottom);
+    /// let srgb = ARGBColor::blend(&top, &bottom);
+    ///
+    /// assert!(linear.color.r > srgb.color.r);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn blend_linear(color_top: &Self, color_bottom: &Self) -> Self {
+        let opacity_top = f32::from(color_top.opacity) / 255.;
+        let opacity_bottom = f32::from(color_bottom.opacity) / 255.;
+        let opacity_res =
+            opacity_bottom.mul_add(1. - opacity_top, opacity_top);
+        if opacity_res <= 0. {
+            return *color_bottom;
+        }
+        let top = color_top.color.to_linear();
+        let bottom = color_bottom.color.to_linear();
+        let channel = |top: f32, bottom: f32| {
+            top.mul_add(
+                opacity_top,
+                bottom * (1. - opacity_top) * opacity_bottom,
+            ) / opacity_res
+        };
+        Self {
+            opacity: (opacity_res * 255.).clamp(0., 255.) as u8,
+            color: RGBColor::from_linear((
+                channel(top.0, bottom.0),
+                channel(top.1, bottom.1),
+                channel(top.2, bottom.2),
+            )),
+        }
+    }
+
+    /// Linearly interpolates between the colors `a` and `b`,
+    /// interpolating the opacity and the color channels independently.
+    /// `t` is clamped to the range from 0 to 1, where 0 returns `a` and 1 returns `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use console_display::color::{
+    ///     ARGBColor,
+    ///     RGBColor,
+    /// };
+    ///
+    /// let color = ARGBColor::lerp(
+    ///     &RGBColor::WHITE.into(),
+    ///     &ARGBColor::TRANSPARENT,
+    ///     0.5,
+    /// );
+    ///
+    /// assert_eq!(color.opacity, 127);
+    /// assert_eq!(color.color, RGBColor::GRAY);
+    /// ```
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+        Self {
+            opacity: lerp_channel(a.opacity, b.opacity, t),
+            color: RGBColor::lerp(&a.color, &b.color, t),
+        }
+    }
+}
+
+/// Rounds a mixed channel down like the integer division of `mix`,
+/// tolerating the rounding errors of floating point weights.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn floor_channel(value: f64) -> u8 {
+    (value + 1e-6).floor().clamp(0., 255.) as u8
+}
+
+/// Interpolates a single channel, rounding exact halves down.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn lerp_channel(a: u8, b: u8, t: f32) -> u8 {
+    let a: f32 = a.into();
+    let b: f32 = b.into();
+    ((b - a).mul_add(t, a) - 0.5).ceil().clamp(0., 255.) as u8
 }
 
 impl const From<RGBColor> for ARGBColor {
@@ -539,6 +1739,163 @@ mod tests {
             assert_eq!(grouping[0], grouping[2]);
         }
 
+        #[test]
+        fn hsv_round_trip() {
+            let color = RGBColor {
+                r: 200,
+                g: 30,
+                b: 90,
+            };
+            let (hue, saturation, value) = color.to_hsv();
+            assert_eq!(RGBColor::from_hsv(hue, saturation, value), color);
+        }
+
+        #[test]
+        fn rotate_hue() {
+            let rotated = RGBColor::RED.rotate_hue(120.);
+            assert!(rotated.r.abs_diff(RGBColor::GREEN.r) <= 1);
+            assert!(rotated.g.abs_diff(RGBColor::GREEN.g) <= 1);
+            assert!(rotated.b.abs_diff(RGBColor::GREEN.b) <= 1);
+            assert_eq!(RGBColor::RED.rotate_hue(360.), RGBColor::RED);
+        }
+
+        #[test]
+        fn hsl_round_trip() {
+            let color = RGBColor {
+                r: 12,
+                g: 150,
+                b: 240,
+            };
+            let (hue, saturation, lightness) = color.to_hsl();
+            assert_eq!(
+                RGBColor::from_hsl(hue, saturation, lightness),
+                color
+            );
+        }
+
+        #[test]
+        fn ansi256_round_trip() {
+            for index in 16..=u8::MAX {
+                assert_eq!(
+                    RGBColor::from_ansi256(index).to_ansi256(),
+                    index
+                );
+            }
+        }
+
+        #[test]
+        fn ansi256_nearest() {
+            for r in (0..=u8::MAX).step_by(15) {
+                for g in (0..=u8::MAX).step_by(15) {
+                    for b in (0..=u8::MAX).step_by(15) {
+                        let color = RGBColor { r, g, b };
+                        let nearest = (16..=u8::MAX)
+                            .min_by_key(|&index| {
+                                color.squared_distance(
+                                    &RGBColor::from_ansi256(index),
+                                )
+                            })
+                            .unwrap();
+                        assert_eq!(color.to_ansi256(), nearest);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn ansi16() {
+            assert_eq!(RGBColor::WHITE.to_ansi16(), 15);
+            assert_eq!(RGBColor::BLACK.to_ansi16(), 0);
+            assert_eq!(
+                RGBColor {
+                    r: 200,
+                    g: 10,
+                    b: 0
+                }
+                .to_ansi16(),
+                1
+            );
+        }
+
+        #[test]
+        fn linear_round_trip() {
+            for value in 0..=u8::MAX {
+                let color = RGBColor {
+                    r: value,
+                    g: value,
+                    b: value,
+                };
+                assert_eq!(
+                    RGBColor::from_linear(color.to_linear()),
+                    color
+                );
+            }
+        }
+
+        #[test]
+        fn mix_linear() {
+            let colors = [RGBColor::BLACK, RGBColor::WHITE];
+            let srgb = RGBColor::mix(&colors);
+            let linear = RGBColor::mix_linear(&colors);
+            assert_eq!(srgb, RGBColor::GRAY);
+            // Half of the light intensity is encoded as 188 in sRGB.
+            assert_eq!(
+                linear,
+                RGBColor {
+                    r: 188,
+                    g: 188,
+                    b: 188
+                }
+            );
+        }
+
+        #[test]
+        fn grayscale() {
+            assert_eq!(RGBColor::WHITE.to_grayscale(), RGBColor::WHITE);
+            assert_eq!(RGBColor::BLACK.to_grayscale(), RGBColor::BLACK);
+            // 0.7152 * 255 = 182.376
+            assert_eq!(
+                RGBColor::GREEN.to_grayscale(),
+                RGBColor {
+                    r: 182,
+                    g: 182,
+                    b: 182
+                }
+            );
+        }
+
+        #[test]
+        fn lerp() {
+            let a = RGBColor {
+                r: 10,
+                g: 200,
+                b: 30,
+            };
+            let b = RGBColor {
+                r: 110,
+                g: 0,
+                b: 31,
+            };
+            assert_eq!(RGBColor::lerp(&a, &b, 0.), a);
+            assert_eq!(RGBColor::lerp(&a, &b, 1.), b);
+            assert_eq!(RGBColor::lerp(&a, &b, -3.), a);
+            assert_eq!(RGBColor::lerp(&a, &b, 7.), b);
+            assert_eq!(
+                RGBColor::lerp(&a, &b, 0.26),
+                RGBColor {
+                    r: 36,
+                    g: 148,
+                    b: 30
+                }
+            );
+        }
+
+        #[test]
+        fn hsv_clamp() {
+            assert_eq!(RGBColor::from_hsv(-120., 2., 1.), RGBColor::BLUE);
+            assert_eq!(RGBColor::from_hsv(0., 1., -1.), RGBColor::BLACK);
+        }
+
         #[test]
         fn group_equal() {
             let colors =
@@ -548,11 +1905,78 @@ mod tests {
             assert_eq!(grouping[1], grouping[2]);
             assert_eq!(grouping[0], grouping[2]);
         }
+
+        #[test]
+        fn swatch() {
+            let swatch = RGBColor::swatch(&[
+                RGBColor::RED,
+                RGBColor::RED,
+                RGBColor::BLUE,
+            ]);
+            assert!(swatch.contains("38;2;255;0;0m██\x1b"));
+            assert!(swatch.contains("38;2;0;0;255m█\x1b"));
+            assert_eq!(swatch.matches("38;2;").count(), 2);
+            assert_eq!(RGBColor::swatch(&[]), "");
+        }
+
+        #[test]
+        fn from_name() {
+            assert_eq!(
+                RGBColor::from_name("white"),
+                Some(RGBColor::WHITE)
+            );
+            assert_eq!(
+                RGBColor::from_name("DARK_GRAY"),
+                Some(RGBColor::DARK_GRAY)
+            );
+            assert_eq!(
+                RGBColor::from_name("dark gray"),
+                Some(RGBColor::DARK_GRAY)
+            );
+            assert_eq!(RGBColor::from_name("chartreuse"), None);
+        }
+
+        #[test]
+        fn complement() {
+            assert_eq!(RGBColor::GREEN.complement(), RGBColor::MAGENTA);
+            assert_eq!(RGBColor::BLUE.complement(), RGBColor::YELLOW);
+            let color = RGBColor {
+                r: 200,
+                g: 100,
+                b: 50,
+            };
+            assert_eq!(color.complement().complement(), color);
+        }
     }
 
     mod terminal_color {
         use super::*;
 
+        #[test]
+        fn group_best() {
+            let colors: [TerminalColor; 8] = [
+                RGBColor::BLACK.into(),
+                RGBColor::GRAY.into(),
+                RGBColor::RED.into(),
+                RGBColor::BLACK.into(),
+                RGBColor::GRAY.into(),
+                RGBColor::RED.into(),
+                RGBColor::WHITE.into(),
+                TerminalColor::Default,
+            ];
+            let best = TerminalColor::group_best(&colors);
+            assert_eq!(best[0], best[3]);
+            assert_eq!(best[1], best[4]);
+            assert_eq!(best[2], best[5]);
+            assert!(
+                TerminalColor::grouping_error(&colors, &best) <=
+                    TerminalColor::grouping_error(
+                        &colors,
+                        &TerminalColor::group(&colors)
+                    )
+            );
+        }
+
         #[test]
         fn color_none() {
             let msg = TerminalColor::color(
@@ -573,6 +1997,90 @@ mod tests {
             assert!(msg.chars().count() > 4);
         }
 
+        #[test]
+        fn color_ansi256() {
+            let msg = TerminalColor::color_with_depth(
+                "test",
+                &RGBColor::RED.into(),
+                &RGBColor::RED.into(),
+                ColorDepth::Ansi256,
+            );
+            assert_eq!(
+                msg,
+                "\x1b[38;5;196m\x1b[38;5;196m\x1b[48;5;196mtest\x1b[0m"
+            );
+        }
+
+        #[test]
+        fn color_ansi256_default() {
+            let msg = TerminalColor::color_with_depth(
+                "test",
+                &TerminalColor::Default,
+                &TerminalColor::Default,
+                ColorDepth::Ansi256,
+            );
+            assert_eq!(msg, "test");
+        }
+
+        #[test]
+        fn color_ansi16() {
+            let msg = TerminalColor::color_with_depth(
+                "test",
+                &RGBColor::WHITE.into(),
+                &TerminalColor::Default,
+                ColorDepth::Ansi16,
+            );
+            assert_eq!(msg, "\x1b[97mtest\x1b[0m");
+            let msg = TerminalColor::color_with_depth(
+                "test",
+                &TerminalColor::Default,
+                &RGBColor::BLACK.into(),
+                ColorDepth::Ansi16,
+            );
+            assert_eq!(msg, "\x1b[40mtest\x1b[0m");
+        }
+
+        #[test]
+        fn write_color() {
+            let mut msg = String::new();
+            TerminalColor::write_color(
+                &mut msg,
+                "test",
+                &RGBColor::RED.into(),
+                &RGBColor::BLUE.into(),
+            )
+            .unwrap();
+            assert_eq!(
+                msg,
+                "\x1b[38;2;255;0;0m\x1b[38;2;255;0;0m\x1b[48;2;0;0;255mtest\x1b[0m"
+            );
+
+            let translucent = ARGBColor {
+                opacity: 100,
+                color: RGBColor::RED,
+            }
+            .into();
+            for (foreground, background) in [
+                (TerminalColor::Default, TerminalColor::Default),
+                (RGBColor::RED.into(), TerminalColor::Default),
+                (TerminalColor::Default, RGBColor::BLUE.into()),
+                (translucent, RGBColor::BLUE.into()),
+            ] {
+                let mut msg = String::new();
+                TerminalColor::write_color(
+                    &mut msg,
+                    "test",
+                    &foreground,
+                    &background,
+                )
+                .unwrap();
+                assert_eq!(
+                    msg,
+                    TerminalColor::color("test", &foreground, &background)
+                );
+            }
+        }
+
         #[test]
         fn distance() {
             let dist_prio = TerminalColor::distance(
@@ -638,6 +2146,52 @@ mod tests {
             assert_eq!(mix, RGBColor::GRAY.into());
         }
 
+        #[test]
+        fn mix_weighted_default() {
+            let colors = [
+                RGBColor::BLACK.into(),
+                TerminalColor::Default,
+                RGBColor::WHITE.into(),
+            ];
+            let mix = TerminalColor::mix_weighted(&colors, &[1., 5., 1.]);
+            assert_eq!(mix, RGBColor::GRAY.into());
+            assert_eq!(
+                TerminalColor::mix_weighted(&colors[1..], &[1.]),
+                TerminalColor::Default
+            );
+        }
+
+        #[test]
+        fn mix_weighted_equal_weights() {
+            let translucent = |opacity, r| {
+                TerminalColor::from(ARGBColor {
+                    opacity,
+                    color: RGBColor { r, g: 1, b: 255 },
+                })
+            };
+            let colors = [
+                translucent(255, 0),
+                translucent(100, 1),
+                translucent(51, 1),
+                TerminalColor::Default,
+            ];
+            assert_eq!(
+                TerminalColor::mix_weighted(&colors, &[0.1; 4]),
+                TerminalColor::mix(&colors)
+            );
+            assert_eq!(
+                TerminalColor::mix_weighted(&colors[1..3], &[3.; 2]),
+                TerminalColor::mix(&colors[1..3])
+            );
+        }
+
+        #[test]
+        fn mix_weighted_truncates() {
+            let colors = [RGBColor::RED.into(), RGBColor::BLUE.into()];
+            let mix = TerminalColor::mix_weighted(&colors, &[2.]);
+            assert_eq!(mix, RGBColor::RED.into());
+        }
+
         #[test]
         fn blend_translucent_default() {
             let translucent_red = ARGBColor::mix(&[
@@ -674,6 +2228,76 @@ mod tests {
     mod argb_color {
         use super::*;
 
+        #[test]
+        fn mix_opacity() {
+            let opaque = ARGBColor {
+                opacity: 255,
+                color: RGBColor::RED,
+            };
+            let mix = ARGBColor::mix(&[
+                opaque,
+                ARGBColor::TRANSPARENT,
+                ARGBColor::TRANSPARENT,
+                ARGBColor::TRANSPARENT,
+            ]);
+            assert_eq!(mix.opacity, 64);
+            assert_eq!(mix.color, RGBColor::RED);
+
+            let translucent = ARGBColor {
+                opacity: 51,
+                color: RGBColor::BLUE,
+            };
+            let mix = ARGBColor::mix(&[opaque, translucent]);
+            assert_eq!(mix.opacity, 153);
+            assert_eq!(
+                mix.color,
+                RGBColor {
+                    r: 212,
+                    g: 0,
+                    b: 42
+                }
+            );
+        }
+
+        #[test]
+        fn mix_transparent() {
+            let transparent = ARGBColor {
+                opacity: 0,
+                color: RGBColor::WHITE,
+            };
+            let mix =
+                ARGBColor::mix(&[transparent, ARGBColor::TRANSPARENT]);
+            assert_eq!(mix.opacity, 0);
+            assert_eq!(mix.color, RGBColor::GRAY);
+            assert_eq!(ARGBColor::mix(&[]), ARGBColor::TRANSPARENT);
+            assert_eq!(RGBColor::mix(&[]), RGBColor::BLACK);
+        }
+
+        #[test]
+        fn blend_linear() {
+            let top = ARGBColor {
+                opacity: 128,
+                color: RGBColor::WHITE,
+            };
+            let bottom = ARGBColor {
+                opacity: 128,
+                color: RGBColor::BLACK,
+            };
+            let linear = ARGBColor::blend_linear(&top, &bottom);
+            let srgb = ARGBColor::blend(&top, &bottom);
+            assert_eq!(linear.opacity, srgb.opacity);
+            assert!(linear.color.r > srgb.color.r);
+            assert_eq!(linear.color.r, linear.color.g);
+            assert_eq!(linear.color.g, linear.color.b);
+        }
+
+        #[test]
+        fn blend_linear_opaque() {
+            let top = RGBColor::RED.into();
+            let bottom = RGBColor::BLUE.into();
+            assert_eq!(ARGBColor::blend_linear(&top, &bottom), top);
+        }
+
         #[test]
         fn color() {
             let msg = ARGBColor::color(
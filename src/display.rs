@@ -1,4 +1,6 @@
 pub mod character_display;
 pub mod console_display;
 pub mod display_driver;
+#[cfg(feature = "image")]
+pub mod gif_recorder;
 pub mod pixel_display;
@@ -1,8 +1,18 @@
-use std::marker::PhantomData;
+use std::{
+    iter,
+    marker::PhantomData,
+    mem,
+};
 
 use num_traits::NumCast;
+use unicode_width::UnicodeWidthChar;
 
 use crate::{
+    color::{
+        RGBColor,
+        TerminalColor,
+        XTERM_SYSTEM_COLORS,
+    },
     console_display::{
         DynamicConsoleDisplay,
         StaticConsoleDisplay,
@@ -14,6 +24,7 @@ use crate::{
         DrawingError,
         OFFSET_SHOULD_BE_0_OR_1,
     },
+    impl_getters,
     optional_const_generics::{
         CompileTime,
         Dimension,
@@ -27,12 +38,18 @@ use crate::{
         },
     },
     widget::{
+        DataCell,
         DynamicWidget,
+        Render,
         StaticWidget,
         StringData,
+        single_widget::HorizontalAlign,
     },
 };
 
+/// Number of columns between two tab stops, unless configured otherwise.
+const DEFAULT_TAB_WIDTH: usize = 8;
+
 pub type DynamicCharacterDisplay<CharacterPixel> =
     CharacterDisplay<RunTime, RunTime, CharacterPixel>;
 pub type StaticCharacterDisplay<
@@ -52,6 +69,8 @@ pub struct CharacterDisplay<W: Dimension, H: Dimension, CharacterPixel> {
     width: usize,
     height: usize,
     data: Box<[CharacterPixel]>,
+    fill: CharacterPixel,
+    tab_width: usize,
 }
 
 impl CharacterDisplay<RunTime, RunTime, CharacterPixel> {
@@ -81,8 +100,127 @@ impl CharacterDisplay<RunTime, RunTime, CharacterPixel> {
             }
         }
 
-        Self::build_from_data(width, height, &data)
-            .expect(DATA_DOES_NOT_MATCH_DIMENSIONS)
+        let mut display = Self::build_from_data(width, height, &data)
+            .expect(DATA_DOES_NOT_MATCH_DIMENSIONS);
+        display.fill = fill;
+        display
+    }
+
+    /// Builds a display sized to fit the text exactly and writes the text into it.
+    /// The width is the display width of the longest line, so double-width characters count twice.
+    /// The height is the number of lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text contains control characters other than line breaks.
+    pub fn fit(
+        text: &str,
+        foreground: TerminalColor,
+        background: TerminalColor,
+    ) -> Result<Self, DrawingError> {
+        let width = text
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|character| {
+                        UnicodeWidthChar::width(character).unwrap_or(0)
+                    })
+                    .sum()
+            })
+            .max()
+            .unwrap_or(0);
+        let height = text.lines().count();
+
+        let mut display = Self::new(
+            width,
+            height,
+            CharacterPixel::new::<' '>(foreground, background),
+        );
+        let mut cursor = TextCursor::new(&mut display);
+        cursor.set_colors(foreground, background);
+        for (y, line) in text.lines().enumerate() {
+            cursor.set_position(0, y);
+            cursor.put_str(line)?;
+        }
+        Ok(display)
+    }
+
+    /// Builds a display of the given size from text colored with SGR escape sequences,
+    /// like the output of `StringData::to_ansi_string`.
+    /// Supported are true colors (38;2 and 48;2), the basic colors (30-37 and 40-47),
+    /// the default colors (39 and 49) and resets.
+    /// Other escape sequences and parameters, including hyperlinks, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text contains control characters other than line breaks
+    /// or does not fit into the display.
+    pub fn from_ansi(
+        width: usize,
+        height: usize,
+        text: &str,
+    ) -> Result<Self, DisplayError> {
+        let mut display =
+            Self::new(width, height, CharacterPixel::default());
+        let mut foreground = TerminalColor::Default;
+        let mut background = TerminalColor::Default;
+        let (mut x, mut y) = (0, 0);
+        let mut characters = text.chars();
+        while let Some(character) = characters.next() {
+            match character {
+                '\x1b' => {
+                    match characters.next() {
+                        Some('[') => {}
+                        // Operating system commands like hyperlinks end with ST or BEL.
+                        Some(']') => {
+                            let mut previous = None;
+                            for character in characters.by_ref() {
+                                if character == '\x07' ||
+                                    previous == Some('\x1b') &&
+                                        character == '\\'
+                                {
+                                    break;
+                                }
+                                previous = Some(character);
+                            }
+                            continue;
+                        }
+                        _ => continue,
+                    }
+                    let mut parameters = String::new();
+                    let mut command = None;
+                    for character in characters.by_ref() {
+                        if ('\x40'..='\x7e').contains(&character) {
+                            command = Some(character);
+                            break;
+                        }
+                        parameters.push(character);
+                    }
+                    if command == Some('m') {
+                        apply_sgr(
+                            &parameters,
+                            &mut foreground,
+                            &mut background,
+                        );
+                    }
+                }
+                '\n' => {
+                    x = 0;
+                    y += 1;
+                }
+                '\r' => {}
+                _ => {
+                    (x, y) = display.write_str(
+                        x,
+                        y,
+                        character.encode_utf8(&mut [0; 4]),
+                        foreground,
+                        background,
+                    )?;
+                }
+            }
+        }
+        Ok(display)
     }
 
     /// Builds a display struct from the given data with the specified dimensions.
@@ -126,6 +264,8 @@ impl CharacterDisplay<RunTime, RunTime, CharacterPixel> {
             width,
             height,
             data: new_data.into_boxed_slice(),
+            fill: CharacterPixel::default(),
+            tab_width: DEFAULT_TAB_WIDTH,
         })
     }
 }
@@ -137,6 +277,19 @@ impl<const WIDTH: usize, const HEIGHT: usize>
         CharacterPixel,
     >
 {
+    /// Returns the width of the display in characters, which is `WIDTH` for character displays.
+    /// Usable in const fns taking a display, unlike `width_characters`.
+    #[must_use]
+    pub const fn width_chars(&self) -> usize {
+        WIDTH
+    }
+
+    /// Returns the height of the display in characters, which is `HEIGHT` for character displays.
+    #[must_use]
+    pub const fn height_chars(&self) -> usize {
+        HEIGHT
+    }
+
     /// Convenience method to build a blank display struct with specified dimensions
     ///
     /// # Panics
@@ -166,7 +319,10 @@ impl<const WIDTH: usize, const HEIGHT: usize>
             }
         }
 
-        Self::build_from_data(&data).expect(DATA_DOES_NOT_MATCH_DIMENSIONS)
+        let mut display = Self::build_from_data(&data)
+            .expect(DATA_DOES_NOT_MATCH_DIMENSIONS);
+        display.fill = fill;
+        display
     }
 
     /// Builds a display struct with the specified dimensions from the given data.
@@ -208,10 +364,345 @@ impl<const WIDTH: usize, const HEIGHT: usize>
             width: WIDTH,
             height: HEIGHT,
             data: new_data.into_boxed_slice(),
+            fill: CharacterPixel::default(),
+            tab_width: DEFAULT_TAB_WIDTH,
         })
     }
 }
 
+impl<W: Dimension, H: Dimension> CharacterDisplay<W, H, CharacterPixel> {
+    impl_getters!(pub const tab_width: usize);
+
+    /// Returns the display width of the widest row, summing the widths of its characters.
+    /// This equals the width of the display, unless a double-width character overflows a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     character_display::DynamicCharacterDisplay,
+    ///     color::TerminalColor,
+    ///     pixel::character_pixel::CharacterPixel,
+    /// };
+    ///
+    /// let display = DynamicCharacterDisplay::<CharacterPixel>::fit(
+    ///     "あい\nab",
+    ///     TerminalColor::Default,
+    ///     TerminalColor::Default,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(display.logical_width(), 4);
+    /// ```
+    #[must_use]
+    pub fn logical_width(&self) -> usize {
+        self.rows()
+            .map(|row| layout_row(row).map(|cell| cell.width()).sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the cells of the display row by row.
+    /// Every row starts at a multiple of the width, as double-width characters are followed by copies.
+    fn rows(&self) -> impl Iterator<Item = &[CharacterPixel]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// Sets the number of columns between two tab stops used when writing text.
+    /// Defaults to 8.
+    pub const fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width;
+    }
+
+    /// Returns the cell that cleared cells are reset to.
+    /// This is a space in the colors of the fill passed to `new`,
+    /// or a space with default colors for displays built from data.
+    #[must_use]
+    pub fn blank(&self) -> CharacterPixel {
+        CharacterPixel::new::<' '>(
+            self.fill.foreground(),
+            self.fill.background(),
+        )
+    }
+
+    /// Resets all cells of a row to the blank cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row is out of bounds.
+    pub fn clear_row(&mut self, y: usize) -> Result<(), DisplayError> {
+        self.clear_region(0, y, self.width, 1)
+    }
+
+    /// Resets all cells in the rectangle with the top left corner (x, y) to the blank cell.
+    /// Double-width characters that are only partially inside the region are cleared completely.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the region exceeds the display.
+    pub fn clear_region(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), DisplayError> {
+        let (right, bottom) =
+            (x.saturating_add(width), y.saturating_add(height));
+        if right > self.width || bottom > self.height {
+            return Err(DisplayError::CoordinatesOutOfBounds(
+                right.saturating_sub(1),
+                self.width,
+                bottom.saturating_sub(1),
+                self.height,
+            ));
+        }
+        if width == 0 {
+            return Ok(());
+        }
+        let blank = self.blank();
+        for row in y..bottom {
+            let row_data =
+                &mut self.data[row * self.width..(row + 1) * self.width];
+            // Widens the region to whole characters, laid out like in `string_data`.
+            let (mut start, mut end) = (x, right);
+            let mut position = 0;
+            while position < row_data.len() {
+                let cell = row_data[position];
+                let cell_width =
+                    if cell.is_copy() { 1 } else { cell.width() };
+                let next = position + cell_width;
+                if position < x && next > x {
+                    start = position;
+                }
+                if position < x + width && next > x + width {
+                    end = next.min(row_data.len());
+                }
+                position = next;
+            }
+            row_data[start..end].fill(blank);
+        }
+        Ok(())
+    }
+
+    /// Writes the text with its top left corner at (x, y) and returns the cursor position after the last character.
+    /// Each character advances the cursor by its display width.
+    /// A line feed moves the cursor back to column x of the next row.
+    /// A tab is expanded to spaces up to the next tab stop, counted from column x.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text contains control characters other than line feeds and tabs
+    /// or a character does not fit into the display.
+    /// Text is not clipped, characters before the failing one are still written.
+    pub fn write_str(
+        &mut self,
+        x: usize,
+        y: usize,
+        text: &str,
+        foreground: TerminalColor,
+        background: TerminalColor,
+    ) -> Result<(usize, usize), DisplayError> {
+        let (mut cursor_x, mut cursor_y) = (x, y);
+        for character in text.chars() {
+            if character == '\n' {
+                cursor_x = x;
+                cursor_y += 1;
+                continue;
+            }
+            if character == '\t' {
+                let tab_width = self.tab_width.max(1);
+                let tab_stop =
+                    x + ((cursor_x - x) / tab_width + 1) * tab_width;
+                let spaces =
+                    tab_stop.min(self.width).saturating_sub(cursor_x);
+                (cursor_x, cursor_y) = self.write_str(
+                    cursor_x,
+                    cursor_y,
+                    &" ".repeat(spaces),
+                    foreground,
+                    background,
+                )?;
+                continue;
+            }
+            let pixel =
+                CharacterPixel::build(character, foreground, background)?;
+            if cursor_x + pixel.width() > self.width ||
+                cursor_y >= self.height
+            {
+                return Err(DisplayError::CoordinatesOutOfBounds(
+                    (cursor_x + pixel.width()).saturating_sub(1),
+                    self.width,
+                    cursor_y,
+                    self.height,
+                ));
+            }
+            self.data[cursor_x + cursor_y * self.width] = pixel;
+            cursor_x += pixel.width();
+        }
+        Ok((cursor_x, cursor_y))
+    }
+
+    /// Writes the text with its top left corner at (x, y), wrapping it into lines at most `width` columns wide.
+    /// Lines are preferably broken at spaces, words wider than `width` are broken between characters.
+    /// Line feeds in the text are kept.
+    /// Returns the cursor position after the last character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text contains control characters other than line feeds
+    /// or the wrapped text does not fit into the display.
+    pub fn write_wrapped(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        text: &str,
+        foreground: TerminalColor,
+        background: TerminalColor,
+    ) -> Result<(usize, usize), DisplayError> {
+        self.write_str(
+            x,
+            y,
+            &wrap(text, width).join("\n"),
+            foreground,
+            background,
+        )
+    }
+
+    /// Writes the text aligned within a field of `width` columns starting at (x, y).
+    /// The rest of the field is filled with spaces in the given colors.
+    /// Each line of the text is aligned separately, lines wider than the field start at x.
+    /// Returns the cursor position after the end of the field in the last row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text contains control characters other than line feeds
+    /// or the field does not fit into the display.
+    pub fn write_aligned(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        text: &str,
+        align: HorizontalAlign,
+        foreground: TerminalColor,
+        background: TerminalColor,
+    ) -> Result<(usize, usize), DisplayError> {
+        let lines = text
+            .split('\n')
+            .map(|line| {
+                let line_width: usize = line
+                    .chars()
+                    .map(|character| {
+                        UnicodeWidthChar::width(character).unwrap_or(0)
+                    })
+                    .sum();
+                let space = width.saturating_sub(line_width);
+                let left = align.offset(space);
+                format!(
+                    "{}{line}{}",
+                    " ".repeat(left),
+                    " ".repeat(space - left)
+                )
+            })
+            .collect::<Vec<_>>();
+        self.write_str(x, y, &lines.join("\n"), foreground, background)
+    }
+}
+
+/// Applies the parameters of an SGR escape sequence to the colors.
+fn apply_sgr(
+    parameters: &str,
+    foreground: &mut TerminalColor,
+    background: &mut TerminalColor,
+) {
+    // Empty or invalid parameters are treated as 0, like terminals do.
+    let mut parameters = parameters
+        .split(';')
+        .map(|parameter| parameter.parse::<u8>().unwrap_or(0));
+    while let Some(parameter) = parameters.next() {
+        match parameter {
+            0 => {
+                *foreground = TerminalColor::Default;
+                *background = TerminalColor::Default;
+            }
+            30..=37 => {
+                *foreground = XTERM_SYSTEM_COLORS
+                    [usize::from(parameter - 30)]
+                .into();
+            }
+            40..=47 => {
+                *background = XTERM_SYSTEM_COLORS
+                    [usize::from(parameter - 40)]
+                .into();
+            }
+            39 => *foreground = TerminalColor::Default,
+            49 => *background = TerminalColor::Default,
+            38 | 48 => {
+                let color = match parameters.next() {
+                    Some(2) => Some(RGBColor {
+                        r: parameters.next().unwrap_or(0),
+                        g: parameters.next().unwrap_or(0),
+                        b: parameters.next().unwrap_or(0),
+                    }),
+                    Some(5) => {
+                        parameters.next();
+                        None
+                    }
+                    _ => None,
+                };
+                if let Some(color) = color {
+                    if parameter == 38 {
+                        *foreground = color.into();
+                    }
+                    else {
+                        *background = color.into();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Breaks the text into lines at most `width` columns wide, preferably at spaces.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let char_width =
+        |character: char| UnicodeWidthChar::width(character).unwrap_or(0);
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+            let word_width: usize = word.chars().map(char_width).sum();
+            if line_width > 0 && line_width + 1 + word_width > width {
+                lines.push(mem::take(&mut line));
+                line_width = 0;
+            }
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            for character in word.chars() {
+                if line_width > 0 &&
+                    line_width + char_width(character) > width
+                {
+                    lines.push(mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push(character);
+                line_width += char_width(character);
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
 impl<W: Dimension, H: Dimension> DynamicConsoleDisplay<CharacterPixel>
     for CharacterDisplay<W, H, CharacterPixel>
 {
@@ -230,6 +721,16 @@ impl<W: Dimension, H: Dimension> DynamicConsoleDisplay<CharacterPixel>
     default fn data_mut(&mut self) -> &mut Box<[CharacterPixel]> {
         &mut self.data
     }
+
+    /// Resets all cells of the display to the blank cell,
+    /// a space in the colors of the fill given to `new`.
+    default fn clear(&mut self)
+    where
+        [(); CharacterPixel::WIDTH * CharacterPixel::HEIGHT]:,
+        CharacterPixelData: Default,
+    {
+        self.data.fill(self.blank());
+    }
 }
 
 impl<const WIDTH: usize, const HEIGHT: usize>
@@ -269,37 +770,20 @@ impl<W: Dimension, H: Dimension> DynamicWidget
     }
 
     default fn string_data(&self) -> StringData {
-        let mut result = Vec::new();
-        let mut row = Vec::new();
-        let mut width = 0;
-
-        let mut iter = self.data.iter();
-        while let Some(cell) = iter.next() {
-            if width >= self.width_characters() {
-                result.push(row);
-                row = Vec::new();
-                width = 0;
-            }
-
-            if cell.is_copy() {
-                row.push(CharacterPixel::default().into());
-                width += 1;
-                continue;
-            }
-
-            row.push((*cell).into());
-            width += cell.width();
-
-            for _ in 1..cell.width() {
-                iter.next();
-            }
-        }
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
 
-        if !row.is_empty() {
-            result.push(row);
+impl<W: Dimension, H: Dimension> Render
+    for CharacterDisplay<W, H, CharacterPixel>
+{
+    fn render_into(&self, buf: &mut StringData) {
+        buf.clear_rows(self.height_characters());
+        for (row, cells) in buf.data.iter_mut().zip(self.rows()) {
+            row.extend(layout_row(cells).map(DataCell::from));
         }
-
-        StringData { data: result }
     }
 }
 
@@ -319,38 +803,28 @@ impl<const WIDTH: usize, const HEIGHT: usize> DynamicWidget
     }
 
     fn string_data(&self) -> StringData {
-        let mut result = Vec::new();
-        let mut row = Vec::new();
-        let mut width = 0;
-
-        let mut iter = self.data.iter();
-        while let Some(cell) = iter.next() {
-            if width >= Self::WIDTH_CHARACTERS {
-                result.push(row);
-                row = Vec::new();
-                width = 0;
-            }
-
-            if cell.is_copy() {
-                row.push(CharacterPixel::default().into());
-                width += 1;
-                continue;
-            }
-
-            row.push((*cell).into());
-            width += cell.width();
-
-            for _ in 1..cell.width() {
-                iter.next();
-            }
-        }
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
 
-        if !row.is_empty() {
-            result.push(row);
+/// Returns the characters of a row as they are rendered.
+/// The copies following a double-width character are skipped,
+/// while copies without their character are rendered as blanks.
+fn layout_row(
+    row: &[CharacterPixel],
+) -> impl Iterator<Item = CharacterPixel> + '_ {
+    let mut position = 0;
+    iter::from_fn(move || {
+        let cell = row.get(position)?;
+        if cell.is_copy() {
+            position += 1;
+            return Some(CharacterPixel::default());
         }
-
-        StringData { data: result }
-    }
+        position += cell.width().max(1);
+        Some(*cell)
+    })
 }
 
 impl<W: Dimension, H: Dimension, S: Pixel<U = CharacterPixelData>>
@@ -548,9 +1022,200 @@ impl<const WIDTH: usize, const HEIGHT: usize> const StaticWidget
     const HEIGHT_CHARACTERS: usize = HEIGHT;
 }
 
+/// Defines the direction in which a `TextCursor` advances.
+///
+/// `Ltr` - Characters are placed from left to right, starting at the left edge.\
+/// `Rtl` - Characters are placed from right to left, starting at the right edge.
+///
+/// Only the placement of the cells is mirrored.
+/// There is no bidirectional reordering or contextual shaping,
+/// so scripts relying on joined forms (e.g. Arabic) are shown in their isolated forms.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+/// Streams characters onto a character display, keeping track of the cursor position.
+///
+/// Characters that do not fit into the current row wrap to the next one,
+/// taking the width of double-width characters into account.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     character_display::{
+///         StaticCharacterDisplay,
+///         TextCursor,
+///     },
+///     pixel::character_pixel::CharacterPixel,
+/// };
+///
+/// let mut disp = StaticCharacterDisplay::<CharacterPixel, 4, 2>::new(
+///     CharacterPixel::default(),
+/// );
+/// let mut cursor = TextCursor::new(&mut disp);
+///
+/// cursor.put_str("abcあ").expect("Text should fit.");
+///
+/// assert_eq!((cursor.x(), cursor.y()), (2, 1));
+/// ```
+pub struct TextCursor<'a, T: DynamicConsoleDisplay<CharacterPixel>> {
+    display: &'a mut T,
+    x: usize,
+    y: usize,
+    foreground: TerminalColor,
+    background: TerminalColor,
+    direction: TextDirection,
+    tab_width: usize,
+}
+
+impl<'a, T: DynamicConsoleDisplay<CharacterPixel>> TextCursor<'a, T> {
+    /// Creates a cursor at the top left corner of the display using default colors.
+    pub const fn new(display: &'a mut T) -> Self {
+        Self {
+            display,
+            x: 0,
+            y: 0,
+            foreground: TerminalColor::Default,
+            background: TerminalColor::Default,
+            direction: TextDirection::Ltr,
+            tab_width: DEFAULT_TAB_WIDTH,
+        }
+    }
+
+    /// Returns the column of the cursor.
+    /// In right-to-left mode, columns are counted from the right edge of the display.
+    #[must_use]
+    pub const fn x(&self) -> usize {
+        self.x
+    }
+
+    /// Returns the row of the cursor.
+    #[must_use]
+    pub const fn y(&self) -> usize {
+        self.y
+    }
+
+    /// Moves the cursor to the specified column and row.
+    pub const fn set_position(&mut self, x: usize, y: usize) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Sets the colors used for subsequently written characters.
+    pub const fn set_colors(
+        &mut self,
+        foreground: TerminalColor,
+        background: TerminalColor,
+    ) {
+        self.foreground = foreground;
+        self.background = background;
+    }
+
+    /// Sets the direction in which subsequently written characters are placed.
+    pub const fn set_text_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    #[must_use]
+    pub const fn text_direction(&self) -> TextDirection {
+        self.direction
+    }
+
+    /// Sets the number of columns between two tab stops.
+    /// Defaults to 8.
+    pub const fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width;
+    }
+
+    #[must_use]
+    pub const fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Moves the cursor to the start of the next row.
+    pub const fn newline(&mut self) {
+        self.x = 0;
+        self.y += 1;
+    }
+
+    /// Writes a character at the cursor position and advances the cursor by its width.
+    /// A line feed moves the cursor to the next row.
+    /// A tab writes spaces up to the next tab stop, or up to the end of the row.
+    /// If the character does not fit into the remaining row, it is wrapped to the next one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the character is a control character
+    /// or the cursor has moved past the bottom of the display.
+    pub fn put_char(
+        &mut self,
+        character: char,
+    ) -> Result<(), DrawingError> {
+        if character == '\n' {
+            self.newline();
+            return Ok(());
+        }
+        if character == '\t' {
+            let tab_width = self.tab_width.max(1);
+            let tab_stop = (self.x / tab_width + 1) * tab_width;
+            for _ in self.x..tab_stop.min(self.display.width()) {
+                self.put_char(' ')?;
+            }
+            return Ok(());
+        }
+        let pixel = CharacterPixel::build(
+            character,
+            self.foreground,
+            self.background,
+        )
+        .map_err(DisplayError::from)?;
+        if self.x + pixel.width() > self.display.width() {
+            self.newline();
+        }
+        let x = match self.direction {
+            TextDirection::Ltr => self.x,
+            TextDirection::Rtl => {
+                self.display.width().saturating_sub(self.x + pixel.width())
+            }
+        };
+        self.display.set_pixel(
+            NumCast::from(x)
+                .ok_or(DisplayError::CoordinatesToUsizeConversionFailed)?,
+            NumCast::from(self.y)
+                .ok_or(DisplayError::CoordinatesToUsizeConversionFailed)?,
+            pixel.into(),
+        )?;
+        self.x += pixel.width();
+        Ok(())
+    }
+
+    /// Writes all characters of the string, as if calling `put_char` for each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while writing a character.
+    pub fn put_str(&mut self, text: &str) -> Result<(), DrawingError> {
+        for character in text.chars() {
+            self.put_char(character)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::color::TerminalColor;
+    use crate::color::{
+        RGBColor,
+        TerminalColor,
+    };
 
     use super::*;
 
@@ -607,6 +1272,402 @@ mod tests {
         assert!(character_display.is_err());
     }
 
+    #[test]
+    fn fit() {
+        let display = DynamicCharacterDisplay::<CharacterPixel>::fit(
+            "abc\nあいう",
+            TerminalColor::Default,
+            TerminalColor::Default,
+        )
+        .unwrap();
+        assert_eq!((display.width(), display.height()), (6, 2));
+        assert_eq!(display.string_data().height(), 2);
+
+        assert!(
+            DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "a\tb",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn logical_width_mixed_row() {
+        let display = DynamicCharacterDisplay::<CharacterPixel>::fit(
+            "aあb\ncd",
+            TerminalColor::Default,
+            TerminalColor::Default,
+        )
+        .unwrap();
+        assert_eq!(display.logical_width(), 4);
+        assert_eq!(display.logical_width(), display.width_characters());
+        assert_eq!(display.string_data().to_plain_string(), "aあb\ncd  ");
+    }
+
+    #[test]
+    fn write_str() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 4, 3>::new(
+                CharacterPixel::default(),
+            );
+        let cursor = display
+            .write_str(
+                1,
+                1,
+                "hi\nok",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+        assert_eq!(cursor, (3, 2));
+        assert_eq!(
+            display.string_data().to_plain_string(),
+            "    \n hi \n ok "
+        );
+
+        assert_eq!(
+            display.write_str(
+                3,
+                0,
+                "ab",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            ),
+            Err(DisplayError::CoordinatesOutOfBounds(4, 4, 0, 3))
+        );
+    }
+
+    #[test]
+    fn write_wrapped() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 20, 10>::new(
+                CharacterPixel::default(),
+            );
+        let cursor = display
+            .write_wrapped(
+                0,
+                0,
+                10,
+                "The quick brown fox jumps over the lazy dog\n\
+                 あいうえおかきくけこ incomprehensibilities",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+        assert_eq!(cursor, (1, 9));
+
+        let text = display.string_data().to_plain_string();
+        let rows = text.lines().map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                "The quick",
+                "brown fox",
+                "jumps over",
+                "the lazy",
+                "dog",
+                "あいうえお",
+                "かきくけこ",
+                "incomprehe",
+                "nsibilitie",
+                "s",
+            ]
+        );
+        assert!(rows.iter().all(|row| {
+            row.chars()
+                .map(|character| {
+                    UnicodeWidthChar::width(character).unwrap_or(0)
+                })
+                .sum::<usize>() <=
+                10
+        }));
+    }
+
+    #[test]
+    fn write_aligned() {
+        let write = |text: &str, align| {
+            let mut display =
+                StaticCharacterDisplay::<CharacterPixel, 8, 1>::new(
+                    CharacterPixel::new::<'x'>(
+                        TerminalColor::Default,
+                        TerminalColor::Default,
+                    ),
+                );
+            let cursor = display
+                .write_aligned(
+                    1,
+                    0,
+                    6,
+                    text,
+                    align,
+                    TerminalColor::Default,
+                    TerminalColor::Default,
+                )
+                .unwrap();
+            assert_eq!(cursor, (7, 0));
+            display.string_data().to_plain_string()
+        };
+
+        assert_eq!(write("ab", HorizontalAlign::Left), "xab    x");
+        assert_eq!(write("ab", HorizontalAlign::Center), "x  ab  x");
+        assert_eq!(write("ab", HorizontalAlign::Right), "x    abx");
+        assert_eq!(write("abc", HorizontalAlign::Center), "x abc  x");
+        assert_eq!(write("あ", HorizontalAlign::Right), "x    あx");
+    }
+
+    #[test]
+    fn from_ansi() {
+        let mut display = DynamicCharacterDisplay::<CharacterPixel>::new(
+            4,
+            2,
+            CharacterPixel::default(),
+        );
+        display
+            .write_str(
+                0,
+                0,
+                "ab",
+                RGBColor::RED.into(),
+                RGBColor::BLUE.into(),
+            )
+            .unwrap();
+        display
+            .write_str(
+                1,
+                1,
+                "cd",
+                RGBColor::GREEN.into(),
+                TerminalColor::Default,
+            )
+            .unwrap();
+        let text = display.string_data().to_ansi_string();
+        let parsed = DynamicCharacterDisplay::<CharacterPixel>::from_ansi(
+            4, 2, &text,
+        )
+        .unwrap();
+        assert_eq!(parsed.string_data(), display.string_data());
+
+        let parsed = DynamicCharacterDisplay::<CharacterPixel>::from_ansi(
+            2,
+            1,
+            "\x1b[31;44ma\x1b[0mb",
+        )
+        .unwrap();
+        let data = parsed.string_data().data;
+        assert_eq!(data[0][0].foreground, XTERM_SYSTEM_COLORS[1].into());
+        assert_eq!(data[0][0].background, XTERM_SYSTEM_COLORS[4].into());
+        assert_eq!(data[0][1].foreground, TerminalColor::Default);
+        assert_eq!(data[0][1].character, 'b');
+    }
+
+    #[test]
+    fn tab() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 12, 2>::new(
+                CharacterPixel::new::<'x'>(
+                    TerminalColor::Default,
+                    TerminalColor::Default,
+                ),
+            );
+        let cursor = display
+            .write_str(
+                0,
+                0,
+                "a\tb",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+        assert_eq!(cursor, (9, 0));
+        display.set_tab_width(4);
+        display
+            .write_str(
+                1,
+                1,
+                "ab\tc\t",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+        assert_eq!(
+            display.string_data().to_plain_string(),
+            "a       bxxx\nxab  c   xxx"
+        );
+
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 12, 1>::new(
+                CharacterPixel::default(),
+            );
+        let mut cursor = TextCursor::new(&mut display);
+        cursor.put_str("a\tb").unwrap();
+        assert_eq!((cursor.x(), cursor.y()), (9, 0));
+        assert_eq!(
+            display.string_data().to_plain_string(),
+            "a       b   "
+        );
+    }
+
+    #[test]
+    fn clear_region() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 4, 3>::new(
+                CharacterPixel::new::<'x'>(
+                    TerminalColor::Default,
+                    TerminalColor::Default,
+                ),
+            );
+        let mut cursor = TextCursor::new(&mut display);
+        cursor.put_str("abcdefghijkl").unwrap();
+        display.clear_region(1, 0, 2, 2).unwrap();
+        assert_eq!(
+            display.string_data().to_string(),
+            "a  d\r\ne  h\r\nijkl"
+        );
+        display.clear_row(2).unwrap();
+        assert_eq!(
+            display.string_data().to_string(),
+            "a  d\r\ne  h\r\n    "
+        );
+        assert!(display.clear_region(3, 0, 2, 1).is_err());
+        assert_eq!(
+            display.clear_region(1, usize::MAX, 1, 2),
+            Err(DisplayError::CoordinatesOutOfBounds(
+                1,
+                4,
+                usize::MAX - 1,
+                3
+            ))
+        );
+        assert!(display.clear_region(usize::MAX, 0, 1, 1).is_err());
+        assert!(display.clear_row(usize::MAX).is_err());
+        display.clear();
+        assert_eq!(
+            display.string_data().to_string(),
+            "    \r\n    \r\n    "
+        );
+    }
+
+    #[test]
+    fn clear_keeps_colors() {
+        let fill = CharacterPixel::new::<' '>(
+            TerminalColor::Default,
+            RGBColor::RED.into(),
+        );
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 2, 1>::new(fill);
+        TextCursor::new(&mut display).put_str("ab").unwrap();
+        display.clear();
+        assert_eq!(display.data(), &[fill, fill]);
+    }
+
+    #[test]
+    fn clear_replaces_fill_character() {
+        let fill = CharacterPixel::new::<'x'>(
+            RGBColor::GREEN.into(),
+            RGBColor::RED.into(),
+        );
+        let space = CharacterPixel::new::<' '>(
+            RGBColor::GREEN.into(),
+            RGBColor::RED.into(),
+        );
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 2, 1>::new(fill);
+        display.clear();
+        assert_eq!(display.data(), &[space, space]);
+    }
+
+    #[test]
+    fn clear_region_wide() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 4, 1>::new(
+                CharacterPixel::default(),
+            );
+        TextCursor::new(&mut display).put_str("aあb").unwrap();
+        display.clear_region(2, 0, 1, 1).unwrap();
+        assert_eq!(display.string_data().to_string(), "a  b");
+    }
+
+    // Fails to compile if `width_chars` or `height_chars` stop being const fns.
+    const fn area_chars<const W: usize, const H: usize>(
+        display: &StaticCharacterDisplay<CharacterPixel, W, H>,
+    ) -> usize {
+        display.width_chars() * display.height_chars()
+    }
+
+    #[test]
+    fn dimensions_chars() {
+        let display = StaticCharacterDisplay::<CharacterPixel, 5, 3>::new(
+            CharacterPixel::default(),
+        );
+        assert_eq!(display.width_chars(), 5);
+        assert_eq!(display.height_chars(), 3);
+        assert_eq!(area_chars(&display), 15);
+    }
+
+    #[test]
+    fn text_cursor_wrap() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 5, 3>::new(
+                CharacterPixel::default(),
+            );
+        let mut cursor = TextCursor::new(&mut display);
+        cursor.put_str("abcあdあxyあ").unwrap();
+        assert_eq!((cursor.x(), cursor.y()), (2, 2));
+
+        let expected = [
+            ('a', 0, 0),
+            ('b', 1, 0),
+            ('c', 2, 0),
+            ('あ', 3, 0),
+            ('d', 0, 1),
+            ('あ', 1, 1),
+            ('x', 3, 1),
+            ('y', 4, 1),
+            ('あ', 0, 2),
+        ];
+        for (character, x, y) in expected {
+            assert_eq!(
+                display.pixel(x, y),
+                Ok(CharacterPixel::try_from(character).unwrap().into())
+            );
+        }
+    }
+
+    #[test]
+    fn text_cursor_rtl() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 4, 2>::new(
+                CharacterPixel::default(),
+            );
+        let mut cursor = TextCursor::new(&mut display);
+        cursor.set_text_direction(TextDirection::Rtl);
+        cursor.put_str("abあc").unwrap();
+        assert_eq!((cursor.x(), cursor.y()), (1, 1));
+
+        let expected =
+            [('a', 3, 0), ('b', 2, 0), ('あ', 0, 0), ('c', 3, 1)];
+        for (character, x, y) in expected {
+            assert_eq!(
+                display.pixel(x, y),
+                Ok(CharacterPixel::try_from(character).unwrap().into())
+            );
+        }
+    }
+
+    #[test]
+    fn text_cursor_overflow() {
+        let mut display =
+            StaticCharacterDisplay::<CharacterPixel, 2, 1>::new(
+                CharacterPixel::default(),
+            );
+        let mut cursor = TextCursor::new(&mut display);
+        assert!(cursor.put_str("ab").is_ok());
+        assert!(cursor.put_char('c').is_err());
+        assert!(cursor.put_char('\u{7}').is_err());
+    }
+
     #[test]
     fn run_time_build_from_data_success() {
         let character_display =
@@ -1,8 +1,23 @@
+#[cfg(feature = "image")]
+use std::path::Path;
+
 use num_traits::NumCast;
 
+#[cfg(feature = "image")]
+use crate::{
+    color::{
+        Color,
+        RGBColor,
+        TerminalColor,
+    },
+    error::IMAGE_TOO_LARGE,
+};
 use crate::{
     constraint,
-    drawing::DynamicCanvas,
+    drawing::{
+        DynamicCanvas,
+        Sprite,
+    },
     error::{
         COULD_NOT_CAST_X_COORD,
         COULD_NOT_CAST_Y_COORD,
@@ -79,6 +94,418 @@ pub trait DynamicConsoleDisplay<T: Pixel>: DynamicCanvas<T> {
         Ok(())
     }
 
+    /// Sets the pixels of the rectangle with the top left corner at (`x`, `y`)
+    /// and the given width and height to the provided data in row-major order.
+    /// Pixels outside the rectangle are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the data is not `w * h`
+    /// or the rectangle does not fit inside the display.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn set_pixels_region(
+        &mut self,
+        x: usize,
+        y: usize,
+        w: usize,
+        h: usize,
+        data: &[T::U],
+    ) -> Result<(), DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        if w.checked_mul(h) != Some(data.len()) {
+            return Err(DisplayError::MismatchedDimensions(
+                w.saturating_mul(h),
+                data.len(),
+            ));
+        }
+        let (right, bottom) = (x.saturating_add(w), y.saturating_add(h));
+        if right > self.width() || bottom > self.height() {
+            return Err(DisplayError::CoordinatesOutOfBounds(
+                right.saturating_sub(1),
+                self.width(),
+                bottom.saturating_sub(1),
+                self.height(),
+            ));
+        }
+        for (dy, row) in data.chunks_exact(w.max(1)).enumerate() {
+            for (dx, &value) in row.iter().enumerate() {
+                self.set_pixel(
+                    NumCast::from(x + dx).expect(COULD_NOT_CAST_X_COORD),
+                    NumCast::from(y + dy).expect(COULD_NOT_CAST_Y_COORD),
+                    value,
+                )
+                .expect(PIXEL_INDEX_OUT_OF_RANGE);
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the display to an image, drawing every pixel as a square of `cell_px` by `cell_px` image pixels.
+    /// Default colors are drawn black, see `to_image_with_background` to choose another color.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the size of the image does not fit into a `u32`.
+    #[cfg(feature = "image")]
+    #[must_use]
+    fn to_image(&self, cell_px: u32) -> image::RgbImage
+    where
+        T: Pixel<U = TerminalColor>,
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        self.to_image_with_background(cell_px, RGBColor::BLACK)
+    }
+
+    /// Renders the display to an image, drawing every pixel as a square of `cell_px` by `cell_px` image pixels.
+    /// Default colors are drawn in the background color, and translucent colors are blended onto it.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the size of the image does not fit into a `u32`.
+    #[cfg(feature = "image")]
+    #[must_use]
+    fn to_image_with_background(
+        &self,
+        cell_px: u32,
+        background: RGBColor,
+    ) -> image::RgbImage
+    where
+        T: Pixel<U = TerminalColor>,
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let width: u32 =
+            NumCast::from(self.width()).expect(COULD_NOT_CAST_X_COORD);
+        let height: u32 =
+            NumCast::from(self.height()).expect(COULD_NOT_CAST_Y_COORD);
+        let background = TerminalColor::from(background);
+        let pixels = self.pixels();
+        image::RgbImage::from_fn(
+            width.checked_mul(cell_px).expect(IMAGE_TOO_LARGE),
+            height.checked_mul(cell_px).expect(IMAGE_TOO_LARGE),
+            |x, y| {
+                let index = (x / cell_px) as usize +
+                    (y / cell_px) as usize * self.width();
+                let color = match TerminalColor::blend(
+                    &pixels[index],
+                    &background,
+                ) {
+                    TerminalColor::ARGBColor(color) => color.color,
+                    TerminalColor::Default => RGBColor::BLACK,
+                };
+                image::Rgb([color.r, color.g, color.b])
+            },
+        )
+    }
+
+    /// Renders the display to an image like `to_image` and saves it as a PNG file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be written.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the size of the image does not fit into a `u32`.
+    #[cfg(feature = "image")]
+    fn save_png(
+        &self,
+        path: impl AsRef<Path>,
+        cell_px: u32,
+    ) -> image::ImageResult<()>
+    where
+        T: Pixel<U = TerminalColor>,
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        self.to_image(cell_px)
+            .save_with_format(path, image::ImageFormat::Png)
+    }
+
+    /// Sets every pixel of the display to the provided value.
+    /// Writes the pixel blocks directly instead of going through `set_pixel`.
+    fn fill(&mut self, value: T::U)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        for pixel in self.data_mut().iter_mut() {
+            pixel.pixels_mut().fill(value);
+        }
+    }
+
+    /// Resets every pixel of the display to the default value of its pixel type,
+    /// e.g. `false` for monochrome pixels or the default terminal color.
+    fn clear(&mut self)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+        T::U: Default,
+    {
+        self.fill(T::U::default());
+    }
+
+    /// Shifts all pixels by the given offset.
+    /// Positive offsets move the content right and down.
+    /// Pixels pushed past the edges are discarded, and newly exposed pixels are set to `fill`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn scroll(&mut self, dx: isize, dy: isize, fill: T::U)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let (width, height) = (self.width(), self.height());
+        let pixels = self.pixels();
+        let mut scrolled = vec![fill; pixels.len()];
+        for y in 0..height {
+            let Some(source_y) = y
+                .checked_sub_signed(dy)
+                .filter(|&source_y| source_y < height)
+            else {
+                continue;
+            };
+            for x in 0..width {
+                if let Some(source_x) = x
+                    .checked_sub_signed(dx)
+                    .filter(|&source_x| source_x < width)
+                {
+                    scrolled[x + y * width] =
+                        pixels[source_x + source_y * width];
+                }
+            }
+        }
+        self.set_pixels(&scrolled).expect(PIXEL_INDEX_OUT_OF_RANGE);
+    }
+
+    /// Shifts all pixels by the given offset, wrapping pixels pushed past an edge around to the opposite edge.
+    /// Positive offsets move the content right and down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn scroll_wrap(&mut self, dx: isize, dy: isize)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let (width, height) = (self.width(), self.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+        let pixels = self.pixels();
+        let offset_x = dx.rem_euclid(
+            NumCast::from(width).expect(COULD_NOT_CAST_X_COORD),
+        );
+        let offset_y = dy.rem_euclid(
+            NumCast::from(height).expect(COULD_NOT_CAST_Y_COORD),
+        );
+        let offset_x: usize =
+            NumCast::from(offset_x).expect(COULD_NOT_CAST_X_COORD);
+        let offset_y: usize =
+            NumCast::from(offset_y).expect(COULD_NOT_CAST_Y_COORD);
+        let mut scrolled = pixels.clone();
+        for y in 0..height {
+            for x in 0..width {
+                scrolled[(x + offset_x) % width +
+                    (y + offset_y) % height * width] =
+                    pixels[x + y * width];
+            }
+        }
+        self.set_pixels(&scrolled).expect(PIXEL_INDEX_OUT_OF_RANGE);
+    }
+
+    /// Mirrors the display along the vertical axis, swapping left and right.
+    /// Operates on logical pixels, so it works for all pixel block types.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn flip_horizontal(&mut self)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let width = self.width();
+        let mut pixels = self.pixels();
+        if width > 0 {
+            for row in pixels.chunks_mut(width) {
+                row.reverse();
+            }
+        }
+        self.set_pixels(&pixels).expect(PIXEL_INDEX_OUT_OF_RANGE);
+    }
+
+    /// Mirrors the display along the horizontal axis, swapping top and bottom.
+    /// Operates on logical pixels, so it works for all pixel block types.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn flip_vertical(&mut self)
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let width = self.width();
+        let pixels = self.pixels();
+        let flipped: Vec<T::U> = if width > 0 {
+            pixels.chunks(width).rev().flatten().copied().collect()
+        }
+        else {
+            pixels
+        };
+        self.set_pixels(&flipped).expect(PIXEL_INDEX_OUT_OF_RANGE);
+    }
+
+    /// Sets every pixel for which the predicate returns true to the provided value.
+    /// The predicate is only evaluated for coordinates inside the display,
+    /// so regions extending past the edges are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::DynamicConsoleDisplay,
+    ///     drawing::DynamicCanvas,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let mut disp = StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+    ///
+    /// // Shade the region below the diagonal.
+    /// disp.fill_where(|x, y| y > x, true);
+    ///
+    /// assert_eq!(disp.pixel(0, 3), Ok(true));
+    /// assert_eq!(disp.pixel(3, 0), Ok(false));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn fill_where<F: Fn(usize, usize) -> bool>(
+        &mut self,
+        predicate: F,
+        value: T::U,
+    ) where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        konst::for_range! { y in 0..self.height() =>
+            konst::for_range! { x in 0..self.width() =>
+                if predicate(x, y) {
+                    self.set_pixel(
+                        NumCast::from(x).expect(COULD_NOT_CAST_X_COORD),
+                        NumCast::from(y).expect(COULD_NOT_CAST_Y_COORD),
+                        value,
+                    )
+                    .expect(PIXEL_INDEX_OUT_OF_RANGE);
+                }
+            }
+        }
+    }
+
+    /// Returns the pixels of a rectangular region in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the region extends past the edges of the display.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    fn read_region(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<Vec<T::U>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let (right, bottom) =
+            (x.saturating_add(width), y.saturating_add(height));
+        if right > self.width() || bottom > self.height() {
+            return Err(DisplayError::CoordinatesOutOfBounds(
+                right.saturating_sub(1),
+                self.width(),
+                bottom.saturating_sub(1),
+                self.height(),
+            ));
+        }
+        // The region lies inside the display, so its area does not overflow.
+        let mut pixels = Vec::with_capacity(width * height);
+        konst::for_range! { region_y in y..bottom =>
+            konst::for_range! { region_x in x..right =>
+                pixels.push(
+                    self.pixel(
+                        NumCast::from(region_x)
+                            .expect(COULD_NOT_CAST_X_COORD),
+                        NumCast::from(region_y)
+                            .expect(COULD_NOT_CAST_Y_COORD),
+                    )
+                    .expect(PIXEL_INDEX_OUT_OF_RANGE),
+                );
+            }
+        }
+        Ok(pixels)
+    }
+
+    /// Captures a rectangular region of the display as a sprite,
+    /// which can later be stamped onto any canvas with the same pixel type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::DynamicConsoleDisplay,
+    ///     drawing::DynamicCanvas,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let mut disp = StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+    /// disp.set_pixel(0, 0, true).unwrap();
+    ///
+    /// let sprite = disp.capture_sprite(0, 0, 2, 2).unwrap();
+    /// sprite.stamp(&mut disp, 2, 2);
+    ///
+    /// assert_eq!(disp.pixel(2, 2), Ok(true));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the region extends past the edges of the display.
+    fn capture_sprite(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<Sprite<T>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        Sprite::build(
+            width,
+            height,
+            self.read_region(x, y, width, height)?,
+        )
+    }
+
     #[must_use]
     fn data(&self) -> &[T];
 
@@ -131,9 +558,56 @@ pub trait StaticConsoleDisplay<T: Pixel>:
     /// assert!(matches!(pixel, Err(_)));
     /// ```
     ///
-    /// # Errors
+    /// The coordinates are checked at compile time, so the last pixel can be queried,
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::StaticConsoleDisplay,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let disp = StaticPixelDisplay::<SinglePixel, 4, 4>::new(true);
+    ///
+    /// assert!(disp.pixel_static::<3, 3>());
+    /// ```
+    ///
+    /// while coordinates outside of the display do not compile.
+    ///
+    /// ```compile_fail
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::StaticConsoleDisplay,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let disp = StaticPixelDisplay::<SinglePixel, 4, 4>::new(true);
+    ///
+    /// assert!(disp.pixel_static::<4, 3>());
+    /// ```
+    ///
+    /// This also holds for pixel types spanning multiple subpixels.
+    ///
+    /// ```compile_fail
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::StaticConsoleDisplay,
+    ///     pixel::monochrome_pixel::QuadPixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
     ///
-    /// Returns an error if the pixel coordinates are out of bounds.
+    /// let disp = StaticPixelDisplay::<QuadPixel, 4, 4>::new(true);
+    ///
+    /// assert!(disp.pixel_static::<1, 4>());
+    /// ```
     ///
     /// # Panics
     ///
@@ -142,22 +616,34 @@ pub trait StaticConsoleDisplay<T: Pixel>:
     #[must_use]
     fn pixel_static<const X: usize, const Y: usize>(&self) -> T::U
     where
-        constraint!(X <= Self::WIDTH):,
-        constraint!(Y <= Self::HEIGHT):,
-        constraint!(X % T::WIDTH < T::WIDTH):,
-        constraint!(Y % T::HEIGHT < T::HEIGHT):,
+        constraint!(X < Self::WIDTH):,
+        constraint!(Y < Self::HEIGHT):,
         [(); T::WIDTH * T::HEIGHT]:,
     {
+        // The subpixel offsets are bounded by the block dimensions,
+        // so only the display bounds above need to be checked.
         let pixel = &self.data()
             [X / T::WIDTH + Y / T::HEIGHT * Self::WIDTH_CHARACTERS];
-        pixel.subpixel_static::<{ X % T::WIDTH }, { Y % T::HEIGHT }>()
+        pixel.pixels()[X % T::WIDTH + Y % T::HEIGHT * T::WIDTH]
     }
     // TODO: Update docs
     /// Set a pixel at the specified coordinate with a given value.
+    /// The coordinates are checked at compile time.
     ///
-    /// # Errors
+    /// ```compile_fail
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
     ///
-    /// Returns an error if the pixel coordinates are out of bounds.
+    /// use console_display::{
+    ///     console_display::StaticConsoleDisplay,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let mut disp = StaticPixelDisplay::<SinglePixel, 4, 4>::new(true);
+    ///
+    /// disp.set_pixel_static::<0, 4>(false);
+    /// ```
     ///
     /// # Panics
     ///
@@ -167,26 +653,108 @@ pub trait StaticConsoleDisplay<T: Pixel>:
         &mut self,
         value: T::U,
     ) where
-        constraint!(X <= Self::WIDTH):,
-        constraint!(Y <= Self::HEIGHT):,
-        constraint!(X % T::WIDTH < T::WIDTH):,
-        constraint!(Y % T::HEIGHT < T::HEIGHT):,
+        constraint!(X < Self::WIDTH):,
+        constraint!(Y < Self::HEIGHT):,
         [(); T::WIDTH * T::HEIGHT]:,
     {
         let pixel = &mut self.data_mut()
             [X / T::WIDTH + Y / T::HEIGHT * Self::WIDTH_CHARACTERS];
-        pixel.set_subpixel_static::<{ X % T::WIDTH }, { Y % T::HEIGHT }>(
-            value,
-        );
+        pixel.pixels_mut()[X % T::WIDTH + Y % T::HEIGHT * T::WIDTH] =
+            value;
+    }
+}
+
+/// Copies a rectangular region of logical pixels from one display to another.
+///
+/// The region is clipped to the bounds of both displays,
+/// so pixels outside of either display are skipped.
+/// The displays may use different pixel types, as long as they store the same values.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     console_display::copy_region,
+///     drawing::DynamicCanvas,
+///     pixel::monochrome_pixel::SinglePixel,
+///     pixel_display::StaticPixelDisplay,
+/// };
+///
+/// let mut src = StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+/// src.set_pixel(1, 1, true).unwrap();
+/// let mut dst = StaticPixelDisplay::<SinglePixel, 2, 2>::new(false);
+///
+/// copy_region(&src, 1, 1, &mut dst, 0, 0, 2, 2);
+///
+/// assert_eq!(dst.pixel(0, 0), Ok(true));
+/// ```
+///
+/// # Panics
+///
+/// This function panics if the index of a pixel is out of bounds.
+/// This should not happen and is subject to change in the future.
+#[allow(clippy::too_many_arguments)]
+pub fn copy_region<S: Pixel, T: Pixel<U = S::U>>(
+    src: &impl DynamicConsoleDisplay<S>,
+    src_x: usize,
+    src_y: usize,
+    dst: &mut impl DynamicConsoleDisplay<T>,
+    dst_x: usize,
+    dst_y: usize,
+    width: usize,
+    height: usize,
+) where
+    [(); S::WIDTH * S::HEIGHT]:,
+    [(); T::WIDTH * T::HEIGHT]:,
+{
+    let width = width
+        .min(src.width().saturating_sub(src_x))
+        .min(dst.width().saturating_sub(dst_x));
+    let height = height
+        .min(src.height().saturating_sub(src_y))
+        .min(dst.height().saturating_sub(dst_y));
+    for y in 0..height {
+        for x in 0..width {
+            let value = src
+                .pixel(
+                    NumCast::from(src_x + x)
+                        .expect(COULD_NOT_CAST_X_COORD),
+                    NumCast::from(src_y + y)
+                        .expect(COULD_NOT_CAST_Y_COORD),
+                )
+                .expect(PIXEL_INDEX_OUT_OF_RANGE);
+            dst.set_pixel(
+                NumCast::from(dst_x + x).expect(COULD_NOT_CAST_X_COORD),
+                NumCast::from(dst_y + y).expect(COULD_NOT_CAST_Y_COORD),
+                value,
+            )
+            .expect(PIXEL_INDEX_OUT_OF_RANGE);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        console_display::StaticConsoleDisplay,
-        pixel::monochrome_pixel::SinglePixel,
-        pixel_display::StaticPixelDisplay,
+        console_display::{
+            DynamicConsoleDisplay,
+            StaticConsoleDisplay,
+        },
+        drawing::DynamicCanvas,
+        error::DisplayError,
+        pixel::monochrome_pixel::{
+            OctPixel,
+            QuadPixel,
+            ShadePixel,
+            SinglePixel,
+        },
+        pixel_display::{
+            DynamicPixelDisplay,
+            StaticPixelDisplay,
+        },
     };
 
     #[test]
@@ -197,5 +765,219 @@ mod tests {
         assert!(!display.pixel_static::<2, 4>());
         assert!(display.pixel_static::<1, 3>());
         assert!(display.pixel_static::<0, 0>());
+        display.set_pixel_static::<9, 9>(false);
+        assert!(!display.pixel_static::<9, 9>());
+    }
+
+    #[test]
+    fn set_pixels_static() {
+        let mut display =
+            StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+        crate::set_pixels_static!(
+            display,
+            (0, 0) => true,
+            (1, 2) => true,
+            (3, 3) => true,
+        );
+        assert!(display.pixel_static::<0, 0>());
+        assert!(display.pixel_static::<1, 2>());
+        assert!(display.pixel_static::<3, 3>());
+        assert_eq!(display.pixels().iter().filter(|&&x| x).count(), 3);
+    }
+
+    #[test]
+    fn pixel_static_multi_subpixel() {
+        let mut display =
+            StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+        display.set_pixel_static::<3, 2>(true);
+        assert!(display.pixel_static::<3, 2>());
+        assert_eq!(display.pixel(3, 2), Ok(true));
+        assert!(!display.pixel_static::<2, 3>());
+    }
+
+    #[test]
+    fn set_pixels_region() {
+        let mut display =
+            DynamicPixelDisplay::<QuadPixel>::new(4, 4, false);
+        display
+            .set_pixels_region(1, 2, 2, 2, &[true, false, true, true])
+            .unwrap();
+        assert_eq!(
+            display.pixels(),
+            vec![
+                false, false, false, false, //
+                false, false, false, false, //
+                false, true, false, false, //
+                false, true, true, false, //
+            ]
+        );
+
+        assert_eq!(
+            display.set_pixels_region(0, 0, 2, 2, &[true; 3]),
+            Err(DisplayError::MismatchedDimensions(4, 3))
+        );
+        assert_eq!(
+            display.set_pixels_region(3, 0, 2, 1, &[true; 2]),
+            Err(DisplayError::CoordinatesOutOfBounds(4, 4, 0, 4))
+        );
+        assert_eq!(
+            display.set_pixels_region(3, 0, 2, 0, &[]),
+            Err(DisplayError::CoordinatesOutOfBounds(4, 4, 0, 4))
+        );
+        assert_eq!(
+            display.set_pixels_region(0, 0, usize::MAX, 2, &[true; 2]),
+            Err(DisplayError::MismatchedDimensions(usize::MAX, 2))
+        );
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn to_image() {
+        use crate::{
+            color::{
+                RGBColor,
+                TerminalColor,
+            },
+            pixel::color_pixel::ColorSinglePixel,
+        };
+
+        let mut display = DynamicPixelDisplay::<ColorSinglePixel>::new(
+            2,
+            2,
+            RGBColor::RED.into(),
+        );
+        let image = display.to_image(3);
+        assert_eq!(image.dimensions(), (6, 6));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0]);
+        assert_eq!(image.get_pixel(5, 5).0, [255, 0, 0]);
+
+        display.set_pixel(1, 1, TerminalColor::Default).unwrap();
+        let image = display.to_image_with_background(1, RGBColor::WHITE);
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0]);
+        assert_eq!(image.get_pixel(1, 1).0, [255, 255, 255]);
+    }
+
+    #[test]
+    fn fill_and_clear() {
+        let mut display =
+            StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+        display.fill(true);
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(display.pixel(x, y), Ok(true));
+            }
+        }
+        display.clear();
+        assert_eq!(display.pixels(), vec![false; 16]);
+    }
+
+    #[test]
+    fn scroll() {
+        let mut display =
+            DynamicPixelDisplay::<ShadePixel>::build_from_data(
+                4,
+                2,
+                &[0, 1, 2, 3, 4, 5, 6, 7],
+            )
+            .unwrap();
+        display.scroll(-1, 0, 9);
+        assert_eq!(display.pixels(), vec![1, 2, 3, 9, 5, 6, 7, 9]);
+        display.scroll(2, 1, 0);
+        assert_eq!(display.pixels(), vec![0, 0, 0, 0, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn scroll_wrap() {
+        let mut display =
+            DynamicPixelDisplay::<ShadePixel>::build_from_data(
+                4,
+                2,
+                &[0, 1, 2, 3, 4, 5, 6, 7],
+            )
+            .unwrap();
+        display.scroll_wrap(-1, 0);
+        assert_eq!(display.pixels(), vec![1, 2, 3, 0, 5, 6, 7, 4]);
+        display.scroll_wrap(5, 3);
+        assert_eq!(display.pixels(), vec![4, 5, 6, 7, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn scroll_block_pixels() {
+        let mut display =
+            StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+        display.set_pixel(0, 0, true).unwrap();
+        display.scroll(1, 1, false);
+        assert_eq!(display.pixel(1, 1), Ok(true));
+        assert_eq!(display.pixels().iter().filter(|&&x| x).count(), 1);
+    }
+
+    #[test]
+    fn copy_region() {
+        let mut src = StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+        src.set_pixel(1, 1, true).unwrap();
+        src.set_pixel(2, 2, true).unwrap();
+        let mut dst = StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+        super::copy_region(&src, 1, 1, &mut dst, 3, 3, 2, 2);
+        assert_eq!(dst.pixel(3, 3), Ok(true));
+        assert_eq!(dst.pixels().iter().filter(|&&x| x).count(), 1);
+
+        super::copy_region(&src, 1, 1, &mut dst, 0, 0, 2, 2);
+        assert_eq!(
+            dst.read_region(0, 0, 2, 2),
+            Ok(vec![true, false, false, true])
+        );
+    }
+
+    #[test]
+    fn read_region_out_of_bounds() {
+        let display = StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+        assert_eq!(
+            display.read_region(3, 0, 2, 1),
+            Err(DisplayError::CoordinatesOutOfBounds(4, 4, 0, 4))
+        );
+        assert_eq!(
+            display.read_region(1, usize::MAX, 1, 2),
+            Err(DisplayError::CoordinatesOutOfBounds(
+                1,
+                4,
+                usize::MAX - 1,
+                4
+            ))
+        );
+        assert!(
+            display.read_region(0, 0, usize::MAX, usize::MAX).is_err()
+        );
+    }
+
+    #[test]
+    fn flip() {
+        let mut display = StaticPixelDisplay::<OctPixel, 4, 8>::new(false);
+        display.set_pixel(0, 0, true).unwrap();
+        display.set_pixel(1, 0, true).unwrap();
+        display.set_pixel(0, 5, true).unwrap();
+
+        display.flip_horizontal();
+        assert_eq!(display.pixel(3, 0), Ok(true));
+        assert_eq!(display.pixel(2, 0), Ok(true));
+        assert_eq!(display.pixel(3, 5), Ok(true));
+        assert_eq!(display.pixels().iter().filter(|&&x| x).count(), 3);
+
+        display.flip_vertical();
+        assert_eq!(display.pixel(3, 7), Ok(true));
+        assert_eq!(display.pixel(2, 7), Ok(true));
+        assert_eq!(display.pixel(3, 2), Ok(true));
+        assert_eq!(display.pixels().iter().filter(|&&x| x).count(), 3);
+    }
+
+    #[test]
+    fn fill_where() {
+        let mut display =
+            StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+        display.fill_where(|x, y| x + y < 3, true);
+        for y in 0..4 {
+            for x in 0..4 {
+                assert_eq!(display.pixel(x, y), Ok(x + y < 3));
+            }
+        }
     }
 }
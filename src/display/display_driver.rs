@@ -1,8 +1,11 @@
 use std::{
+    collections::VecDeque,
     io::{
         self,
+        IsTerminal,
         Write,
     },
+    mem,
     ops::{
         Deref,
         DerefMut,
@@ -22,14 +25,30 @@ use crossterm::{
         EnableMouseCapture,
         Event,
         KeyCode,
+        KeyEvent,
         KeyModifiers,
     },
     terminal,
 };
 
-use crate::widget::{
-    DynamicWidget,
-    single_widget::PaddingWidget,
+use unicode_width::UnicodeWidthChar;
+
+#[cfg(feature = "image")]
+use crate::gif_recorder::GifRecorder;
+use crate::{
+    color::{
+        ColorDepth,
+        TerminalColor,
+    },
+    pixel::character_pixel::CharacterPixel,
+    widget::{
+        DynamicWidget,
+        HitTest,
+        Render,
+        StringData,
+        WidgetId,
+        single_widget::PaddingWidget,
+    },
 };
 
 pub enum UpdateStatus {
@@ -37,8 +56,64 @@ pub enum UpdateStatus {
     Continue,
 }
 
+/// Defines whether colors are stripped from the output of the display driver.
+///
+/// `Auto` - Strips colors if the output is not a terminal, e.g. when redirected to a file.\
+/// `Always` - Always prints plain characters without escape sequences.\
+/// `Never` - Always prints colors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum ColorStripping {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Number of recent frames the average frame rate is computed from.
+const FRAME_TIME_WINDOW: usize = 60;
+
+/// Changes made to the terminal by `initialize`,
+/// so `suspend` and dropping the driver only undo those.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct TerminalState {
+    raw_mode: bool,
+    mouse_capture: bool,
+    alternate_screen: bool,
+    /// Whether the size, line wrapping and cursor of the screen were changed.
+    screen: bool,
+}
+
 type UpdateFunction<T: DynamicWidget> =
-    dyn FnMut(&mut DisplayDriver<T>, Option<Event>) -> UpdateStatus;
+    dyn FnMut(&mut DisplayDriver<T>, Vec<Event>) -> UpdateStatus;
+type ResizeFunction<T: DynamicWidget> =
+    dyn FnMut(&mut DisplayDriver<T>, (u16, u16), (u16, u16));
+
+/// Represents a sequence of key events together with the frame they occurred in.
+/// Frames are counted relative to the start of the recording.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputLog {
+    events: Vec<(usize, KeyEvent)>,
+}
+
+impl InputLog {
+    /// Creates an empty input log.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Returns the recorded events in the order they occurred.
+    #[must_use]
+    pub fn events(&self) -> &[(usize, KeyEvent)] {
+        &self.events
+    }
+
+    /// Appends an event occurring in the given frame.
+    pub fn push(&mut self, frame: usize, event: KeyEvent) {
+        self.events.push((frame, event));
+    }
+}
 
 /// Represents a display driver responsible for handling the interaction between the displays and the terminal.
 pub struct DisplayDriver<T: DynamicWidget> {
@@ -46,7 +121,26 @@ pub struct DisplayDriver<T: DynamicWidget> {
     original_height: u16,
     display: PaddingWidget<T>,
     on_update: Option<Box<UpdateFunction<T>>>,
+    on_resize: Option<Box<ResizeFunction<T>>>,
     target_frame_time: Duration,
+    color_depth: ColorDepth,
+    output: Box<dyn Write>,
+    output_is_terminal: bool,
+    color_stripping: ColorStripping,
+    frame: usize,
+    recording: Option<(usize, InputLog)>,
+    replay: Option<(usize, VecDeque<(usize, KeyEvent)>)>,
+    mouse_capture: bool,
+    alternate_screen: bool,
+    exit_key: Option<KeyEvent>,
+    previous_frame: Option<StringData>,
+    frame_buffer: StringData,
+    clear_color: TerminalColor,
+    terminal_size: Option<(u16, u16)>,
+    terminal_state: TerminalState,
+    frame_times: VecDeque<Duration>,
+    #[cfg(feature = "image")]
+    recorder: Option<GifRecorder>,
 }
 
 impl<T: DynamicWidget> DisplayDriver<T> {
@@ -64,61 +158,351 @@ impl<T: DynamicWidget> DisplayDriver<T> {
             display: PaddingWidget::new(widget, 0, 0, 0, 0),
             target_frame_time: Duration::ZERO,
             on_update: None,
+            on_resize: None,
+            color_depth: ColorDepth::TrueColor,
+            output: Box::new(io::stdout()),
+            output_is_terminal: io::stdout().is_terminal(),
+            color_stripping: ColorStripping::Auto,
+            frame: 0,
+            recording: None,
+            replay: None,
+            mouse_capture: true,
+            alternate_screen: true,
+            exit_key: Some(KeyEvent::new(
+                KeyCode::Char('c'),
+                KeyModifiers::CONTROL,
+            )),
+            previous_frame: None,
+            frame_buffer: StringData::default(),
+            clear_color: TerminalColor::Default,
+            terminal_size: None,
+            terminal_state: TerminalState::default(),
+            frame_times: VecDeque::with_capacity(FRAME_TIME_WINDOW),
+            #[cfg(feature = "image")]
+            recorder: None,
         }
     }
 
-    /// Prints the display to the terminal.
+    /// Returns a builder to configure a display driver for the given widget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     display_driver::DisplayDriver,
+    ///     pixel::monochrome_pixel::SinglePixel,
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let driver = DisplayDriver::builder(
+    ///     StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+    /// )
+    /// .frame_rate(60.)
+    /// .mouse_capture(false)
+    /// .build();
+    ///
+    /// assert!(!driver.mouse_capture());
+    /// ```
+    pub fn builder(widget: T) -> DisplayDriverBuilder<T> {
+        DisplayDriverBuilder {
+            driver: Self::new(widget),
+        }
+    }
+
+    /// Prints the display to the output, which is the terminal by default.
+    /// See `print_display_to` for the format of the output.
+    ///
+    /// # Errors
+    ///
+    /// May return an error if write! is unsuccessful
+    /// or the recorder could not write the frame.
+    pub fn print_display(&mut self) -> Result<(), io::Error> {
+        let mut frame = mem::take(&mut self.frame_buffer);
+        self.display.render_into(&mut frame);
+        let result = self.print_frame(&frame);
+        #[cfg(feature = "image")]
+        let result = result.and(self.capture_frame(&frame));
+        self.frame_buffer = frame;
+        result
+    }
+
+    /// Prints the display to the given writer, e.g. a buffer for inspecting the output.
+    /// If colors are stripped, the display is printed as plain characters
+    /// followed by a line break, without moving the cursor.
     ///
     /// # Errors
     ///
     /// May return an error if write! is unsuccessful.
-    pub fn print_display(&self) -> Result<(), io::Error> {
-        let mut stdout = io::stdout();
+    pub fn print_display_to<W: Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), io::Error> {
+        self.write_frame(writer, &self.display.string_data())
+    }
+
+    /// Prints a rendered frame of the display to the output.
+    fn print_frame(
+        &mut self,
+        string_data: &StringData,
+    ) -> Result<(), io::Error> {
+        let mut output =
+            mem::replace(&mut self.output, Box::new(io::sink()));
+        let result = self.write_frame(&mut output, string_data);
+        self.output = output;
+        result
+    }
+
+    /// Writes a rendered frame of the display to the given writer.
+    fn write_frame<W: Write>(
+        &self,
+        writer: &mut W,
+        string_data: &StringData,
+    ) -> Result<(), io::Error> {
+        if self.strips_colors() {
+            for row in string_data.iter() {
+                let line: String =
+                    row.iter().map(|cell| cell.character).collect();
+                write!(writer, "{line}\r\n")?;
+            }
+        }
+        else {
+            write!(writer, "\x1B[H")?;
+            write!(writer, "{}", string_data.render(self.color_depth))?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Prints only the cells that changed since the last frame printed with this method,
+    /// moving the cursor to each run of changed cells.
+    /// Falls back to printing the whole display with `print_display`
+    /// if there is no previous frame, the dimensions changed (e.g. after a resize)
+    /// or colors are stripped.
+    ///
+    /// # Errors
+    ///
+    /// May return an error if write! is unsuccessful
+    /// or the recorder could not write the frame.
+    pub fn print_display_diff(&mut self) -> Result<(), io::Error> {
+        // The current and the previous frame alternate between two buffers.
+        let mut string_data = mem::take(&mut self.frame_buffer);
+        self.display.render_into(&mut string_data);
+
+        let previous_frame = self.previous_frame.take();
+        match &previous_frame {
+            Some(previous)
+                if !self.strips_colors() &&
+                    previous.width() == string_data.width() &&
+                    previous.height() == string_data.height() =>
+            {
+                for (y, (row, previous_row)) in
+                    string_data.iter().zip(previous.iter()).enumerate()
+                {
+                    // Rows with a different number of cells are shifted, so they are printed entirely.
+                    let same_layout = row.len() == previous_row.len();
+                    let mut column = 0;
+                    let mut cursor_in_place = false;
+                    for (x, cell) in row.iter().enumerate() {
+                        let changed = !same_layout ||
+                            previous_row.get(x) != Some(cell);
+                        if changed {
+                            if !cursor_in_place {
+                                write!(
+                                    self.output,
+                                    "\x1B[{};{}H",
+                                    y + 1,
+                                    column + 1
+                                )?;
+                            }
+                            write!(
+                                self.output,
+                                "{}",
+                                cell.render(self.color_depth)
+                            )?;
+                        }
+                        cursor_in_place = changed;
+                        column += UnicodeWidthChar::width(cell.character)
+                            .unwrap_or(0);
+                    }
+                }
+                self.output.flush()?;
+            }
+            _ => self.print_frame(&string_data)?,
+        }
+        #[cfg(feature = "image")]
+        let captured = self.capture_frame(&string_data);
+
+        if let Some(previous) = previous_frame {
+            self.frame_buffer = previous;
+        }
+        self.previous_frame = Some(string_data);
+        #[cfg(feature = "image")]
+        captured?;
+        Ok(())
+    }
+
+    /// Centers the widget on a terminal of the given size by adjusting the padding.
+    /// If the size changed since the last call, the resize callback is invoked first.
+    /// Then the whole screen is cleared to the clear color,
+    /// so no cells of a previous frame remain outside the widget, and the next frame is printed entirely.
+    fn resize(
+        &mut self,
+        width: u16,
+        height: u16,
+    ) -> Result<(), io::Error> {
+        let previous_size = self.terminal_size.replace((width, height));
+        let size_changed = previous_size != Some((width, height));
+        if size_changed &&
+            let Some(previous_size) = previous_size &&
+            let Some(mut callback) = self.on_resize.take()
+        {
+            callback(self, previous_size, (width, height));
+            self.on_resize = Some(callback);
+        }
 
-        write!(stdout, "\x1B[H")?;
-        write!(stdout, "{}", self.display.string_data())?;
+        let padding_vertical =
+            (height as usize).saturating_sub(self.height_characters());
+        let padding_horizontal =
+            (width as usize).saturating_sub(self.width_characters());
 
+        self.display.set_padding_left(padding_horizontal / 2);
+        self.display.set_padding_top(padding_vertical / 2);
+        self.display.set_padding_right(
+            padding_horizontal - padding_horizontal / 2,
+        );
+        self.display
+            .set_padding_bottom(padding_vertical - padding_vertical / 2);
+
+        if !size_changed {
+            return Ok(());
+        }
+        self.previous_frame = None;
+        if self.strips_colors() {
+            return Ok(());
+        }
+        if let TerminalColor::ARGBColor(color) = self.clear_color {
+            write!(
+                self.output,
+                "{}",
+                self.color_depth.background_code(color.color)
+            )?;
+        }
+        crossterm::execute!(
+            self.output,
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        write!(self.output, "\x1B[0m")?;
         Ok(())
     }
 
     /// Initializes the display driver.
     /// This function enables terminal raw mode and
     /// sets the dimensions of the screen to match the widget's dimensions.
-    /// It enters alternate screen mode,
+    /// It enters alternate screen mode, unless disabled with `set_use_alternate_screen`,
     /// hides the cursor and disables line wrapping.
+    /// Mouse events are captured, unless disabled with `set_mouse_capture`.
+    /// Escape sequences are written to the output set with `set_output`,
+    /// unless colors are stripped, e.g. because the output is not a terminal.
+    /// Raw mode is only enabled if the standard input is a terminal.
     ///
     /// # Errors
     ///
     /// Returns an error when any on the actions above fail.
     /// Note that resizing the terminal does not fail, if the terminal does not support it.
     pub fn initialize(&mut self) -> Result<(), io::Error> {
-        let mut stdout = io::stdout();
+        self.previous_frame = None;
 
         // enables terminal raw mode
-        terminal::enable_raw_mode()?;
+        if io::stdin().is_terminal() {
+            terminal::enable_raw_mode()?;
+            self.terminal_state.raw_mode = true;
+        }
+
+        if self.strips_colors() {
+            return Ok(());
+        }
 
+        if self.mouse_capture {
+            crossterm::execute!(self.output, EnableMouseCapture)?;
+            self.terminal_state.mouse_capture = true;
+        }
+
+        let (width, height) = (
+            self.child().width_characters(),
+            self.child().height_characters(),
+        );
+        if self.alternate_screen {
+            crossterm::execute!(
+                self.output,
+                terminal::EnterAlternateScreen
+            )?;
+            self.terminal_state.alternate_screen = true;
+        }
+        self.terminal_state.screen = true;
         crossterm::execute!(
-            stdout,
-            terminal::EnterAlternateScreen, // use alternate screen
+            self.output,
             terminal::SetSize(
-                self.child()
-                    .width_characters()
-                    .try_into()
-                    .unwrap_or(u16::MAX),
-                self.child()
-                    .height_characters()
-                    .try_into()
-                    .unwrap_or(u16::MAX)
+                width.try_into().unwrap_or(u16::MAX),
+                height.try_into().unwrap_or(u16::MAX)
             ), // set dimensions of screen
-            terminal::DisableLineWrap,      // disable line wrapping
+            terminal::DisableLineWrap, // disable line wrapping
             terminal::Clear(terminal::ClearType::All), // clear screen
-            cursor::Hide,                   // hide cursor blinking
-            EnableMouseCapture,             // capture mouse movement
+            cursor::Hide,              // hide cursor blinking
         )?;
 
         Ok(())
     }
 
+    /// Temporarily hands the terminal back, e.g. to run an external program like an editor.
+    /// Leaves the alternate screen, shows the cursor and disables raw mode,
+    /// as far as `initialize` changed them.
+    /// Call `resume` to continue rendering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when any of the actions above fail.
+    pub fn suspend(&mut self) -> Result<(), io::Error> {
+        let state = mem::take(&mut self.terminal_state);
+        if state.alternate_screen {
+            crossterm::execute!(
+                self.output,
+                terminal::LeaveAlternateScreen
+            )?;
+        }
+        if state.screen {
+            crossterm::execute!(
+                self.output,
+                terminal::EnableLineWrap, // enable line wrapping
+                cursor::Show,             // show cursor blinking
+            )?;
+        }
+
+        if state.mouse_capture {
+            crossterm::execute!(self.output, DisableMouseCapture)?;
+        }
+
+        if state.raw_mode {
+            terminal::disable_raw_mode()?;
+        }
+
+        Ok(())
+    }
+
+    /// Takes back the terminal after `suspend` by initializing it again
+    /// and repainting the whole display.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if initializing or printing the display fails.
+    pub fn resume(&mut self) -> Result<(), io::Error> {
+        self.initialize()?;
+        self.print_display()
+    }
+
     const fn original_width(&self) -> &u16 {
         &self.original_width
     }
@@ -135,13 +519,38 @@ impl<T: DynamicWidget> DisplayDriver<T> {
         &mut self.display
     }
 
-    pub fn set_on_update<F>(&mut self, on_update: F)
+    /// Sets the callback invoked by `update` once per frame with the latest event of the frame.
+    /// Earlier events of the same frame are discarded, use `set_on_update_all` to receive them.
+    /// Replaces the callback set with `set_on_update_all`.
+    pub fn set_on_update<F>(&mut self, mut on_update: F)
     where
         F: FnMut(&mut Self, Option<Event>) -> UpdateStatus + 'static,
+    {
+        self.on_update = Some(Box::new(move |driver, events| {
+            on_update(driver, events.into_iter().last())
+        }));
+    }
+
+    /// Sets the callback invoked by `update` once per frame with all events of the frame
+    /// in the order they occurred.
+    /// Replaces the callback set with `set_on_update`.
+    pub fn set_on_update_all<F>(&mut self, on_update: F)
+    where
+        F: FnMut(&mut Self, Vec<Event>) -> UpdateStatus + 'static,
     {
         self.on_update = Some(Box::new(on_update));
     }
 
+    /// Sets a callback invoked by `update` when the size of the terminal changes between frames.
+    /// The callback receives the previous and the new size as (width, height) in characters.
+    /// It is invoked before the widget is centered, so the widget may be resized to fit.
+    pub fn set_on_resize<F>(&mut self, on_resize: F)
+    where
+        F: FnMut(&mut Self, (u16, u16), (u16, u16)) + 'static,
+    {
+        self.on_resize = Some(Box::new(on_resize));
+    }
+
     pub const fn set_target_frame_time(&mut self, frame_time: Duration) {
         self.target_frame_time = frame_time;
     }
@@ -158,12 +567,253 @@ impl<T: DynamicWidget> DisplayDriver<T> {
         1. / self.target_frame_time.as_secs_f32()
     }
 
+    /// Sets the color depth used when printing the display.
+    /// Lower color depths are useful for terminals that do not support 24-bit colors.
+    pub const fn set_color_depth(&mut self, color_depth: ColorDepth) {
+        self.color_depth = color_depth;
+    }
+
+    pub const fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Redirects the output of `print_display` and `update`, e.g. to a file or an in-memory buffer.
+    /// Whether the output is a terminal is used to decide if colors are stripped automatically.
+    pub fn set_output<W: Write + 'static>(
+        &mut self,
+        output: W,
+        is_terminal: bool,
+    ) {
+        self.output_is_terminal = is_terminal;
+        self.output = Box::new(output);
+    }
+
+    /// Sets whether colors are stripped from the output.
+    pub const fn set_color_stripping(
+        &mut self,
+        color_stripping: ColorStripping,
+    ) {
+        self.color_stripping = color_stripping;
+    }
+
+    pub const fn color_stripping(&self) -> ColorStripping {
+        self.color_stripping
+    }
+
+    /// Returns whether colors are currently stripped from the output,
+    /// taking automatic detection into account.
+    pub const fn strips_colors(&self) -> bool {
+        match self.color_stripping {
+            ColorStripping::Auto => !self.output_is_terminal,
+            ColorStripping::Always => true,
+            ColorStripping::Never => false,
+        }
+    }
+
+    /// Sets whether mouse events are captured while the driver is running.
+    /// Takes effect on the next call to `initialize`.
+    pub const fn set_mouse_capture(&mut self, mouse_capture: bool) {
+        self.mouse_capture = mouse_capture;
+    }
+
+    pub const fn mouse_capture(&self) -> bool {
+        self.mouse_capture
+    }
+
+    /// Sets whether the display is rendered on the alternate screen, which is the default.
+    /// Without the alternate screen, the last rendered frame remains in the scrollback
+    /// after the driver is dropped.
+    /// Takes effect on the next call to `initialize`.
+    pub const fn set_use_alternate_screen(
+        &mut self,
+        alternate_screen: bool,
+    ) {
+        self.alternate_screen = alternate_screen;
+    }
+
+    pub const fn use_alternate_screen(&self) -> bool {
+        self.alternate_screen
+    }
+
+    /// Enables mouse capture immediately, so mouse events are passed to the update callback.
+    /// Capture is disabled again when the driver is suspended or dropped.
+    /// To configure capture before calling `initialize`, use `set_mouse_capture` instead.
+    /// Nothing is written to the output while colors are stripped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the escape sequences could not be written to the output.
+    pub fn enable_mouse_capture(&mut self) -> Result<(), io::Error> {
+        self.mouse_capture = true;
+        if !self.strips_colors() {
+            crossterm::execute!(self.output, EnableMouseCapture)?;
+            self.terminal_state.mouse_capture = true;
+        }
+        Ok(())
+    }
+
+    /// Disables mouse capture immediately, so the terminal handles the mouse again,
+    /// e.g. to select text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the escape sequences could not be written to the output.
+    pub fn disable_mouse_capture(&mut self) -> Result<(), io::Error> {
+        self.mouse_capture = false;
+        if self.terminal_state.mouse_capture {
+            crossterm::execute!(self.output, DisableMouseCapture)?;
+            self.terminal_state.mouse_capture = false;
+        }
+        Ok(())
+    }
+
+    /// Returns the duration of the last completed frame of the update loop,
+    /// including the time slept to match the target frame rate.
+    /// Returns zero if no frame has been completed yet.
+    pub fn last_frame_time(&self) -> Duration {
+        self.frame_times.back().copied().unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns the average frame rate over the last frames of the update loop.
+    /// Returns zero if no frame has been completed yet.
+    pub fn average_frame_rate(&self) -> f32 {
+        let total: Duration = self.frame_times.iter().sum();
+        if total.is_zero() {
+            return 0.;
+        }
+        self.frame_times.len() as f32 / total.as_secs_f32()
+    }
+
+    /// Sets the color the screen is cleared to when the size of the terminal changes.
+    /// The padding centering the widget is filled with this color as well.
+    pub fn set_clear_color(&mut self, clear_color: TerminalColor) {
+        self.clear_color = clear_color;
+        self.display.set_fill(CharacterPixel::new::<' '>(
+            TerminalColor::Default,
+            clear_color,
+        ));
+    }
+
+    pub const fn clear_color(&self) -> TerminalColor {
+        self.clear_color
+    }
+
+    /// Sets the key that exits the update loop, Ctrl-C by default.
+    /// Key events match if they have the same code and at least the modifiers of the exit key.
+    /// Pass `None` to disable the built-in exit,
+    /// so the loop only ends when the update callback returns `UpdateStatus::Break`.
+    pub const fn set_exit_key(&mut self, exit_key: Option<KeyEvent>) {
+        self.exit_key = exit_key;
+    }
+
+    pub const fn exit_key(&self) -> Option<KeyEvent> {
+        self.exit_key
+    }
+
+    /// Returns the widget at the specified terminal column and row, e.g. of a mouse event.
+    /// The padding centering the widget in the terminal is taken into account,
+    /// so identifiers are relative to the widget passed to the driver.
+    /// Returns `None` if the cell lies outside of the widget.
+    pub fn widget_at(&self, column: u16, row: u16) -> Option<WidgetId> {
+        let id = self.display.widget_at(column.into(), row.into())?;
+        let (&child, path) = id.path().split_first()?;
+        debug_assert_eq!(child, 0);
+        Some(WidgetId::new(path.to_vec()))
+    }
+
+    /// Starts recording the key events passed to the update callback.
+    /// A recording that is already in progress is discarded.
+    pub fn start_recording(&mut self) {
+        self.recording = Some((self.frame, InputLog::new()));
+    }
+
+    /// Stops recording and returns the recorded key events.
+    /// Returns an empty log if no recording was in progress.
+    pub fn stop_recording(&mut self) -> InputLog {
+        self.recording
+            .take()
+            .map(|(_, log)| log)
+            .unwrap_or_default()
+    }
+
+    /// Feeds the events of the log to the update loop in place of live input,
+    /// starting with the next frame.
+    /// Live input resumes once all events of the log have been replayed.
+    pub fn replay(&mut self, log: InputLog) {
+        self.replay = Some((self.frame, log.events.into()));
+    }
+
+    /// Sets a recorder capturing every frame printed with `print_display` or `print_display_diff`,
+    /// including the update loop, with the frame delay of the recorder.
+    /// Frames are captured as printed, including the padding centering the widget.
+    /// A recorder that is already set is discarded, see `take_recorder` to finish it first.
+    #[cfg(feature = "image")]
+    pub fn set_recorder(&mut self, recorder: GifRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Removes and returns the recorder, e.g. to end the GIF with `GifRecorder::finish`.
+    /// Returns `None` if no recorder is set.
+    #[cfg(feature = "image")]
+    pub const fn take_recorder(&mut self) -> Option<GifRecorder> {
+        self.recorder.take()
+    }
+
+    #[cfg(feature = "image")]
+    fn capture_frame(
+        &mut self,
+        frame: &StringData,
+    ) -> Result<(), io::Error> {
+        if let Some(recorder) = &mut self.recorder {
+            recorder
+                .capture(frame, recorder.frame_delay())
+                .map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn next_events(&mut self) -> Result<Vec<Event>, io::Error> {
+        let mut events = Vec::new();
+        if let Some((start, replay_events)) = &mut self.replay {
+            let frame = self.frame - *start;
+            while let Some((event_frame, _)) = replay_events.front() &&
+                *event_frame <= frame
+            {
+                events.extend(
+                    replay_events
+                        .pop_front()
+                        .map(|(_, event)| Event::Key(event)),
+                );
+            }
+            if replay_events.is_empty() {
+                self.replay = None;
+            }
+            return Ok(events);
+        }
+        while event::poll(Duration::from_millis(0))? {
+            events.push(event::read()?);
+        }
+        Ok(events)
+    }
+
+    fn is_exit_key(&self, event: &Event) -> bool {
+        let (Some(exit_key), Event::Key(key_event)) =
+            (self.exit_key, event)
+        else {
+            return false;
+        };
+        key_event.code == exit_key.code &&
+            key_event.modifiers.contains(exit_key.modifiers)
+    }
+
     /// This function encapsulates the update loop of the display.
     /// As such it may or may not return depending on the update callback set with
-    /// `set_on_update`.
-    /// This function prints the display.
-    /// Queries user input and exits on Ctrl-C.
+    /// `set_on_update` or `set_on_update_all`.
+    /// This function prints the display, rewriting only changed cells after the first frame.
+    /// Queries user input and exits on the exit key, Ctrl-C by default.
+    /// Exits as well if the output has been closed, e.g. because the reading end of a pipe went away.
     /// Forwards keystrokes to the provided callback and invokes it.
+    /// Invokes the resize callback when the size of the terminal changes.
     /// Sleeps so the target frame rate is not exceeded.
     ///
     /// # Errors
@@ -178,36 +828,36 @@ impl<T: DynamicWidget> DisplayDriver<T> {
                 Err(_) => (0, 0),
             };
 
-            let padding_vertical =
-                (height as usize).saturating_sub(self.height_characters());
-            let padding_horizontal =
-                (width as usize).saturating_sub(self.width_characters());
-
-            self.display.set_padding_left(padding_horizontal / 2);
-            self.display.set_padding_top(padding_vertical / 2);
-            self.display.set_padding_right(padding_horizontal / 2);
-            self.display.set_padding_bottom(padding_vertical / 2);
+            if let Err(error) = self
+                .resize(width, height)
+                .and_then(|()| self.print_display_diff())
+            {
+                if error.kind() == io::ErrorKind::BrokenPipe {
+                    break; // Exit when the output has been closed
+                }
+                return Err(error);
+            }
 
-            self.print_display()?;
+            let events = self.next_events()?;
 
-            let mut latest_event = None;
-            while event::poll(Duration::from_millis(0))? {
-                latest_event = Some(event::read()?);
+            if let Some((start, log)) = &mut self.recording {
+                for event in &events {
+                    if let Event::Key(key_event) = event {
+                        log.push(self.frame - *start, *key_event);
+                    }
+                }
             }
 
-            if let Some(crossterm::event::Event::Key(key_event)) =
-                latest_event &&
-                key_event.code == KeyCode::Char('c') &&
-                key_event.modifiers.contains(KeyModifiers::CONTROL)
-            {
-                break; // Exit on Ctrl-C
+            if events.iter().any(|event| self.is_exit_key(event)) {
+                break; // Exit on the exit key, Ctrl-C by default
             }
 
             let mut update_status = UpdateStatus::Continue;
             if let Some(mut callback) = self.on_update.take() {
-                update_status = callback(self, latest_event);
+                update_status = callback(self, events);
                 self.on_update = Some(callback);
             }
+            self.frame += 1;
             match update_status {
                 UpdateStatus::Break => break,
                 UpdateStatus::Continue => {}
@@ -216,11 +866,143 @@ impl<T: DynamicWidget> DisplayDriver<T> {
             thread::sleep(
                 self.target_frame_time.saturating_sub(start.elapsed()),
             );
+
+            if self.frame_times.len() == FRAME_TIME_WINDOW {
+                self.frame_times.pop_front();
+            }
+            self.frame_times.push_back(start.elapsed());
         }
         Ok(())
     }
 }
 
+/// Configures a `DisplayDriver` in a single expression.
+/// Created by `DisplayDriver::builder`.
+/// Every option corresponds to a setter on the driver.
+pub struct DisplayDriverBuilder<T: DynamicWidget> {
+    driver: DisplayDriver<T>,
+}
+
+impl<T: DynamicWidget> DisplayDriverBuilder<T> {
+    /// See `DisplayDriver::set_on_update`.
+    #[must_use]
+    pub fn on_update<F>(mut self, on_update: F) -> Self
+    where
+        F: FnMut(&mut DisplayDriver<T>, Option<Event>) -> UpdateStatus
+            + 'static,
+    {
+        self.driver.set_on_update(on_update);
+        self
+    }
+
+    /// See `DisplayDriver::set_on_update_all`.
+    #[must_use]
+    pub fn on_update_all<F>(mut self, on_update: F) -> Self
+    where
+        F: FnMut(&mut DisplayDriver<T>, Vec<Event>) -> UpdateStatus
+            + 'static,
+    {
+        self.driver.set_on_update_all(on_update);
+        self
+    }
+
+    /// See `DisplayDriver::set_on_resize`.
+    #[must_use]
+    pub fn on_resize<F>(mut self, on_resize: F) -> Self
+    where
+        F: FnMut(&mut DisplayDriver<T>, (u16, u16), (u16, u16)) + 'static,
+    {
+        self.driver.set_on_resize(on_resize);
+        self
+    }
+
+    /// See `DisplayDriver::set_target_frame_time`.
+    #[must_use]
+    pub const fn frame_time(mut self, frame_time: Duration) -> Self {
+        self.driver.set_target_frame_time(frame_time);
+        self
+    }
+
+    /// See `DisplayDriver::set_target_frame_rate`.
+    #[must_use]
+    pub fn frame_rate(mut self, frame_rate: f32) -> Self {
+        self.driver.set_target_frame_rate(frame_rate);
+        self
+    }
+
+    /// See `DisplayDriver::set_color_depth`.
+    #[must_use]
+    pub const fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.driver.set_color_depth(color_depth);
+        self
+    }
+
+    /// See `DisplayDriver::set_output`.
+    #[must_use]
+    pub fn output<W: Write + 'static>(
+        mut self,
+        output: W,
+        is_terminal: bool,
+    ) -> Self {
+        self.driver.set_output(output, is_terminal);
+        self
+    }
+
+    /// See `DisplayDriver::set_color_stripping`.
+    #[must_use]
+    pub const fn color_stripping(
+        mut self,
+        color_stripping: ColorStripping,
+    ) -> Self {
+        self.driver.set_color_stripping(color_stripping);
+        self
+    }
+
+    /// See `DisplayDriver::set_mouse_capture`.
+    #[must_use]
+    pub const fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.driver.set_mouse_capture(mouse_capture);
+        self
+    }
+
+    /// See `DisplayDriver::set_use_alternate_screen`.
+    #[must_use]
+    pub const fn use_alternate_screen(
+        mut self,
+        alternate_screen: bool,
+    ) -> Self {
+        self.driver.set_use_alternate_screen(alternate_screen);
+        self
+    }
+
+    /// See `DisplayDriver::set_exit_key`.
+    #[must_use]
+    pub const fn exit_key(mut self, exit_key: Option<KeyEvent>) -> Self {
+        self.driver.set_exit_key(exit_key);
+        self
+    }
+
+    /// Sets the key that exits the update loop, shorthand for `exit_key(Some(quit_key))`.
+    /// See `DisplayDriver::set_exit_key`.
+    #[must_use]
+    pub const fn quit_key(self, quit_key: KeyEvent) -> Self {
+        self.exit_key(Some(quit_key))
+    }
+
+    /// See `DisplayDriver::set_clear_color`.
+    #[must_use]
+    pub fn clear_color(mut self, clear_color: TerminalColor) -> Self {
+        self.driver.set_clear_color(clear_color);
+        self
+    }
+
+    /// Returns the configured driver.
+    #[must_use]
+    pub fn build(self) -> DisplayDriver<T> {
+        self.driver
+    }
+}
+
 impl<T: DynamicWidget> const Deref for DisplayDriver<T> {
     type Target = T;
 
@@ -236,43 +1018,112 @@ impl<T: DynamicWidget> const DerefMut for DisplayDriver<T> {
 }
 
 impl<T: DynamicWidget> Drop for DisplayDriver<T> {
+    /// Undoes the changes `initialize` made to the terminal.
     fn drop(&mut self) {
-        let mut stdout = io::stdout();
-
-        let _ = crossterm::execute!(
-            stdout,
-            terminal::EnableLineWrap, // disable line wrapping
-            terminal::LeaveAlternateScreen, // return to previous screen
-            cursor::Show,             // show cursor blinking
-            DisableMouseCapture,      // end mouse capture
-        );
+        let state = self.terminal_state;
+        if state.alternate_screen {
+            let _ = crossterm::execute!(
+                self.output,
+                terminal::LeaveAlternateScreen
+            );
+        }
+        if state.screen {
+            let _ = crossterm::execute!(
+                self.output,
+                terminal::EnableLineWrap, // enable line wrapping
+                cursor::Show,             // show cursor blinking
+            );
+        }
+
+        if state.mouse_capture {
+            let _ = crossterm::execute!(self.output, DisableMouseCapture);
+        }
 
         // reset dimensions of screen
-        if *self.original_width() != 0 && *self.orignal_height() != 0 {
+        let (width, height) =
+            (*self.original_width(), *self.orignal_height());
+        if state.screen && width != 0 && height != 0 {
             let _ = crossterm::execute!(
-                stdout,
-                terminal::SetSize(
-                    *self.original_width(),
-                    *self.orignal_height()
-                )
+                self.output,
+                terminal::SetSize(width, height)
             );
         }
 
         // disable terminal raw mode
-        let _ = terminal::disable_raw_mode();
+        if state.raw_mode {
+            let _ = terminal::disable_raw_mode();
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
+    use std::{
+        cell::RefCell,
+        io::{
+            self,
+            Write,
+        },
+        rc::Rc,
+        time::Duration,
+    };
+
+    use crossterm::event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifiers,
+    };
 
     use crate::{
-        display_driver::DisplayDriver,
-        pixel::monochrome_pixel::SinglePixel,
+        color::{
+            ColorDepth,
+            RGBColor,
+        },
+        display_driver::{
+            ColorStripping,
+            DisplayDriver,
+            InputLog,
+            UpdateStatus,
+        },
+        drawing::DynamicCanvas,
+        pixel::{
+            color_pixel::ColorSinglePixel,
+            monochrome_pixel::SinglePixel,
+        },
         pixel_display::StaticPixelDisplay,
+        widget::{
+            DynamicWidget,
+            Region,
+            WidgetId,
+            two_widget::HorizontalTilingWidget,
+        },
     };
 
+    /// Output shared with a driver, so the written bytes can be read after writing.
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl SharedOutput {
+        fn bytes(&self) -> Vec<u8> {
+            self.0.borrow().clone()
+        }
+
+        fn contents(&self) -> String {
+            String::from_utf8(self.bytes()).unwrap()
+        }
+    }
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
     #[test]
     fn child() {
         assert_eq!(
@@ -309,6 +1160,499 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn builder() {
+        let driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .frame_time(Duration::from_millis(20))
+            .mouse_capture(false)
+            .color_depth(ColorDepth::Ansi256)
+            .quit_key(KeyEvent::from(KeyCode::Esc))
+            .build();
+        assert_eq!(driver.target_frame_time(), Duration::from_millis(20));
+        assert!(!driver.mouse_capture());
+        assert_eq!(driver.color_depth(), ColorDepth::Ansi256);
+        assert_eq!(driver.exit_key(), Some(KeyEvent::from(KeyCode::Esc)));
+    }
+
+    #[test]
+    fn color_depth() {
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            );
+        assert_eq!(driver.color_depth(), ColorDepth::TrueColor);
+        driver.set_color_depth(ColorDepth::Ansi16);
+        assert_eq!(driver.color_depth(), ColorDepth::Ansi16);
+    }
+
+    fn print_to_buffer(color_stripping: ColorStripping) -> String {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<ColorSinglePixel, 2, 2>::new(
+                    RGBColor::RED.into(),
+                ),
+            );
+        driver.set_output(output.clone(), false);
+        driver.set_color_stripping(color_stripping);
+        driver.print_display().unwrap();
+        output.contents()
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn recorder() {
+        use image::{
+            AnimationDecoder,
+            codecs::gif::GifDecoder,
+        };
+
+        use crate::{
+            console_display::DynamicConsoleDisplay,
+            gif_recorder::GifRecorder,
+        };
+
+        let gif = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<ColorSinglePixel, 2, 2>::new(
+                    RGBColor::RED.into(),
+                ),
+            );
+        driver.set_output(Vec::new(), false);
+        driver.set_recorder(GifRecorder::new(gif.clone(), 2).unwrap());
+        driver.print_display().unwrap();
+        driver.fill(RGBColor::BLUE.into());
+        driver.print_display().unwrap();
+
+        let recorder = driver.take_recorder().unwrap();
+        assert_eq!(recorder.frame_count(), 2);
+        recorder.finish();
+
+        let frames = GifDecoder::new(io::Cursor::new(gif.bytes()))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_ne!(
+            frames[0].buffer().get_pixel(0, 0),
+            frames[1].buffer().get_pixel(0, 0)
+        );
+    }
+
+    #[test]
+    fn print_display_to() {
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 2, 1>::new(true),
+            );
+        driver.set_color_stripping(ColorStripping::Always);
+        let mut buffer = Vec::new();
+        driver.print_display_to(&mut buffer).unwrap();
+        assert_eq!(buffer, "██\r\n".as_bytes());
+
+        driver.set_color_stripping(ColorStripping::Never);
+        let mut buffer = Vec::new();
+        driver.print_display_to(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"\x1B[H"));
+    }
+
+    #[test]
+    fn print_display_diff() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<ColorSinglePixel, 2, 2>::new(
+                    RGBColor::RED.into(),
+                ),
+            );
+        driver.set_output(output.clone(), false);
+        driver.set_color_stripping(ColorStripping::Never);
+
+        driver.print_display_diff().unwrap();
+        let full = output.contents();
+        assert!(full.starts_with("\x1B[H"));
+
+        driver.print_display_diff().unwrap();
+        assert_eq!(output.contents(), full);
+
+        driver.set_pixel(1, 1, RGBColor::BLUE.into()).unwrap();
+        driver.print_display_diff().unwrap();
+        let output = output.contents();
+        let update = output.strip_prefix(&full).unwrap();
+        assert!(update.starts_with("\x1B[2;2H"));
+        assert_eq!(update.matches("\x1B[0m").count(), 1);
+    }
+
+    #[test]
+    fn clear_on_resize() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 2, 2>::new(true),
+            );
+        driver.set_output(output.clone(), false);
+        driver.set_color_stripping(ColorStripping::Never);
+        driver.set_clear_color(RGBColor::BLUE.into());
+        for (width, height) in [(6, 4), (4, 2), (7, 5)] {
+            driver.resize(width, height).unwrap();
+            driver.print_display_diff().unwrap();
+        }
+        let output = output.contents();
+        assert_eq!(output.matches("\x1B[2J").count(), 3);
+        let last_frame = &output[output.rfind("\x1B[2J").unwrap()..];
+        assert!(last_frame.contains("\x1B[H"));
+
+        let string_data = driver.display.string_data();
+        assert_eq!((string_data.width(), string_data.height()), (7, 5));
+        let widget = Region::new(2, 1, 2, 2);
+        for (y, row) in string_data.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if !widget.contains(x, y) {
+                    assert_eq!(cell.background, RGBColor::BLUE.into());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn on_resize() {
+        let resizes = Rc::new(RefCell::new(Vec::new()));
+        let callback_resizes = Rc::clone(&resizes);
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .color_stripping(ColorStripping::Always)
+            .on_resize(move |_, previous, new| {
+                callback_resizes.borrow_mut().push((previous, new));
+            })
+            .build();
+        driver.resize(4, 2).unwrap();
+        driver.resize(4, 2).unwrap();
+        driver.resize(6, 3).unwrap();
+        assert_eq!(*resizes.borrow(), vec![((4, 2), (6, 3))]);
+    }
+
+    #[test]
+    fn mouse_capture() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .output(output.clone(), false)
+            .color_stripping(ColorStripping::Never)
+            .mouse_capture(true)
+            .build();
+
+        driver.initialize().unwrap();
+        let initialized = output.contents();
+        assert!(initialized.contains("\x1b[?1000h"));
+
+        driver.disable_mouse_capture().unwrap();
+        assert!(!driver.mouse_capture());
+        let disabled = output.contents();
+        assert!(disabled[initialized.len()..].contains("\x1b[?1000l"));
+
+        driver.enable_mouse_capture().unwrap();
+        assert!(driver.mouse_capture());
+
+        drop(driver);
+    }
+
+    #[test]
+    fn without_alternate_screen() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .output(output.clone(), false)
+            .color_stripping(ColorStripping::Never)
+            .use_alternate_screen(false)
+            .build();
+
+        driver.initialize().unwrap();
+        driver.print_display().unwrap();
+        drop(driver);
+        let output = output.contents();
+        assert!(!output.contains("\x1b[?1049"));
+        assert!(output.contains("\x1b[?25l"));
+        assert!(output.contains("\x1b[?25h"));
+    }
+
+    #[test]
+    fn suspend_resume() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .output(output.clone(), false)
+            .color_stripping(ColorStripping::Never)
+            .mouse_capture(false)
+            .build();
+
+        driver.initialize().unwrap();
+        let initialized = output.contents();
+        driver.suspend().unwrap();
+        let suspended = output.contents();
+        assert!(suspended[initialized.len()..].contains("\x1b[?1049l"));
+        assert!(suspended[initialized.len()..].contains("\x1b[?25h"));
+
+        driver.resume().unwrap();
+        let resumed = output.contents();
+        let resumed = &resumed[suspended.len()..];
+        assert!(resumed.contains("\x1b[?1049h"));
+        assert!(resumed.contains('█'));
+
+        drop(driver);
+    }
+
+    #[test]
+    fn drop_stripping_colors() {
+        let output = SharedOutput::default();
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .output(output.clone(), false)
+            .color_stripping(ColorStripping::Always)
+            .build();
+
+        driver.initialize().unwrap();
+        driver.print_display().unwrap();
+        drop(driver);
+        let output = output.contents();
+        assert_eq!(output, "█\r\n");
+        assert!(!output.contains('\x1b'));
+    }
+
+    #[test]
+    fn drop_uninitialized() {
+        let output = SharedOutput::default();
+        let driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .output(output.clone(), false)
+            .color_stripping(ColorStripping::Never)
+            .build();
+
+        drop(driver);
+        let output = output.contents();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn set_output() {
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            );
+        driver.set_output(Vec::new(), false);
+        assert!(driver.strips_colors());
+        driver.set_output(Vec::new(), true);
+        assert!(!driver.strips_colors());
+    }
+
+    #[test]
+    fn strip_colors_auto() {
+        let output = print_to_buffer(ColorStripping::Auto);
+        assert!(!output.contains("\x1b["));
+        assert_eq!(output, "██\r\n██\r\n");
+    }
+
+    #[test]
+    fn strip_colors_never() {
+        let output = print_to_buffer(ColorStripping::Never);
+        assert!(output.contains("\x1b["));
+    }
+
+    #[test]
+    fn replay() {
+        let mut log = InputLog::new();
+        log.push(1, KeyEvent::from(KeyCode::Char('a')));
+        log.push(3, KeyEvent::from(KeyCode::Char('b')));
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            );
+        let callback_received = Rc::clone(&received);
+        let mut frame = 0;
+        driver.set_on_update(move |_, event| {
+            callback_received.borrow_mut().push((frame, event));
+            frame += 1;
+            if frame > 3 {
+                UpdateStatus::Break
+            }
+            else {
+                UpdateStatus::Continue
+            }
+        });
+        driver.start_recording();
+        driver.replay(log.clone());
+        driver.update().expect("Could not update display.");
+
+        assert_eq!(
+            *received.borrow(),
+            vec![
+                (0, None),
+                (1, Some(Event::Key(KeyEvent::from(KeyCode::Char('a'))))),
+                (2, None),
+                (3, Some(Event::Key(KeyEvent::from(KeyCode::Char('b'))))),
+            ]
+        );
+        assert_eq!(driver.stop_recording(), log);
+    }
+
+    #[test]
+    fn frame_statistics() {
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            );
+        assert_eq!(driver.last_frame_time(), Duration::ZERO);
+        assert!(driver.average_frame_rate().abs() < f32::EPSILON);
+
+        // Keeps the driver from polling the terminal for input.
+        let mut log = InputLog::new();
+        log.push(usize::MAX, KeyEvent::from(KeyCode::Char('a')));
+        driver.replay(log);
+
+        driver.set_target_frame_time(Duration::from_millis(10));
+        let mut frames = 0;
+        driver.set_on_update(move |_, _| {
+            frames += 1;
+            if frames > 5 {
+                UpdateStatus::Break
+            }
+            else {
+                UpdateStatus::Continue
+            }
+        });
+        driver.update().expect("Could not update display.");
+
+        assert!(driver.last_frame_time() >= Duration::from_millis(10));
+        let average = driver.average_frame_rate();
+        assert!(average <= 100. * 1.01);
+        assert!(average >= 100. * 0.5);
+    }
+
+    #[test]
+    fn exit_key() {
+        let mut log = InputLog::new();
+        log.push(
+            0,
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+        );
+        log.push(2, KeyEvent::from(KeyCode::Esc));
+
+        let frames = Rc::new(RefCell::new(0));
+        let callback_frames = Rc::clone(&frames);
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .exit_key(Some(KeyEvent::from(KeyCode::Esc)))
+            .on_update(move |_, _| {
+                *callback_frames.borrow_mut() += 1;
+                if *callback_frames.borrow() > 10 {
+                    UpdateStatus::Break
+                }
+                else {
+                    UpdateStatus::Continue
+                }
+            })
+            .build();
+        driver.replay(log);
+        driver.update().expect("Could not update display.");
+
+        // Ctrl-C is passed to the callback, Esc exits before it is invoked.
+        assert_eq!(*frames.borrow(), 2);
+    }
+
+    #[test]
+    fn on_update_all() {
+        let mut log = InputLog::new();
+        log.push(0, KeyEvent::from(KeyCode::Char('a')));
+        log.push(0, KeyEvent::from(KeyCode::Char('b')));
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let callback_received = Rc::clone(&received);
+        let mut driver =
+            DisplayDriver::builder(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            )
+            .on_update_all(move |_, events| {
+                callback_received.borrow_mut().push(events);
+                UpdateStatus::Break
+            })
+            .build();
+        driver.replay(log);
+        driver.update().expect("Could not update display.");
+
+        assert_eq!(
+            *received.borrow(),
+            vec![vec![
+                Event::Key(KeyEvent::from(KeyCode::Char('a'))),
+                Event::Key(KeyEvent::from(KeyCode::Char('b'))),
+            ]]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn broken_pipe() {
+        let (reader, writer) = io::pipe().unwrap();
+        let mut driver =
+            DisplayDriver::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+            );
+        driver.set_output(writer, false);
+
+        // Keeps the driver from polling the terminal for input.
+        let mut log = InputLog::new();
+        log.push(usize::MAX, KeyEvent::from(KeyCode::Char('a')));
+        driver.replay(log);
+
+        let frames = Rc::new(RefCell::new(0));
+        let callback_frames = Rc::clone(&frames);
+        let mut reader = Some(reader);
+        driver.set_on_update(move |_, _| {
+            // Close the reading end after the first frame has been printed.
+            drop(reader.take());
+            *callback_frames.borrow_mut() += 1;
+            if *callback_frames.borrow() > 3 {
+                UpdateStatus::Break
+            }
+            else {
+                UpdateStatus::Continue
+            }
+        });
+
+        assert!(driver.update().is_ok());
+        assert_eq!(*frames.borrow(), 1);
+        drop(driver);
+    }
+
+    #[test]
+    fn widget_at() {
+        let driver = DisplayDriver::new(HorizontalTilingWidget::new(
+            StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+            StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+        ));
+        assert_eq!(driver.widget_at(3, 1), Some(WidgetId::new(vec![1])));
+        assert_eq!(driver.widget_at(4, 1), None);
+    }
+
     #[test]
     fn deref() {
         assert_eq!(
@@ -0,0 +1,292 @@
+use std::{
+    io::Write,
+    time::Duration,
+};
+
+use image::{
+    Delay,
+    DynamicImage,
+    Frame,
+    ImageResult,
+    Rgba,
+    RgbaImage,
+    codecs::gif::{
+        GifEncoder,
+        Repeat,
+    },
+};
+use num_traits::NumCast;
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    color::{
+        Color,
+        RGBColor,
+        TerminalColor,
+    },
+    console_display::DynamicConsoleDisplay,
+    error::{
+        COULD_NOT_CAST_X_COORD,
+        COULD_NOT_CAST_Y_COORD,
+        IMAGE_TOO_LARGE,
+    },
+    pixel::Pixel,
+    widget::{
+        DataCell,
+        StringData,
+    },
+};
+
+/// Encodes frames of a display as an animated GIF, e.g. to document an animation.
+/// Frames are captured from rendered `StringData` or directly from color displays,
+/// and written to the writer as they are captured, so long recordings are not kept in memory.
+///
+/// Each character is drawn `cell_px` image pixels wide and twice as high,
+/// so half blocks, e.g. of `ColorDualPixel` displays, are drawn like `to_image` draws the pixels.
+/// Other characters are drawn in a mix of their foreground and background colors,
+/// as glyphs are not rasterized.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use std::{
+///     env,
+///     fs::{
+///         self,
+///         File,
+///     },
+///     time::Duration,
+/// };
+///
+/// use console_display::{
+///     color::RGBColor,
+///     gif_recorder::GifRecorder,
+///     pixel::color_pixel::ColorSinglePixel,
+///     pixel_display::DynamicPixelDisplay,
+/// };
+///
+/// let display = DynamicPixelDisplay::<ColorSinglePixel>::new(
+///     2,
+///     2,
+///     RGBColor::RED.into(),
+/// );
+///
+/// let path = env::temp_dir().join("console_display_recording.gif");
+/// let mut recorder = GifRecorder::new(File::create(&path).unwrap(), 4).unwrap();
+/// recorder.capture_display(&display, Duration::from_millis(50)).unwrap();
+/// recorder.finish();
+///
+/// assert!(fs::read(&path).unwrap().starts_with(b"GIF89a"));
+/// # fs::remove_file(&path).unwrap();
+/// ```
+pub struct GifRecorder {
+    cell_px: u32,
+    encoder: GifEncoder<Box<dyn Write>>,
+    frame_count: usize,
+    frame_delay: Duration,
+    default_foreground: RGBColor,
+    default_background: RGBColor,
+}
+
+impl GifRecorder {
+    /// Creates a recorder writing a GIF looping forever to the writer,
+    /// drawing every pixel or half of a character as a square of `cell_px` by `cell_px` image pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the GIF could not be set up to loop.
+    pub fn new<W: Write + 'static>(
+        writer: W,
+        cell_px: u32,
+    ) -> ImageResult<Self> {
+        let mut encoder =
+            GifEncoder::new(Box::new(writer) as Box<dyn Write>);
+        encoder.set_repeat(Repeat::Infinite)?;
+        Ok(Self {
+            cell_px,
+            encoder,
+            frame_count: 0,
+            frame_delay: Duration::from_millis(100),
+            default_foreground: RGBColor::WHITE,
+            default_background: RGBColor::BLACK,
+        })
+    }
+
+    /// Sets the delay of frames captured by a `DisplayDriver`, 100 ms by default.
+    pub const fn set_frame_delay(&mut self, frame_delay: Duration) {
+        self.frame_delay = frame_delay;
+    }
+
+    pub const fn frame_delay(&self) -> Duration {
+        self.frame_delay
+    }
+
+    /// Sets the colors default terminal colors are drawn in, white on black by default.
+    /// Translucent colors are blended onto the default background.
+    pub const fn set_default_colors(
+        &mut self,
+        foreground: RGBColor,
+        background: RGBColor,
+    ) {
+        self.default_foreground = foreground;
+        self.default_background = background;
+    }
+
+    /// Returns the number of frames captured so far.
+    #[must_use]
+    pub const fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Captures a frame rendered by a widget, shown for the given delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame could not be encoded or written.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the size of the image does not fit into a `u32`.
+    pub fn capture(
+        &mut self,
+        string_data: &StringData,
+        delay: Duration,
+    ) -> ImageResult<()> {
+        let width: u32 = NumCast::from(string_data.width())
+            .expect(COULD_NOT_CAST_X_COORD);
+        let height: u32 = NumCast::from(string_data.height())
+            .expect(COULD_NOT_CAST_Y_COORD);
+        let background = self.default_background;
+        let mut image = RgbaImage::from_pixel(
+            width.checked_mul(self.cell_px).expect(IMAGE_TOO_LARGE),
+            height.checked_mul(2 * self.cell_px).expect(IMAGE_TOO_LARGE),
+            Rgba([background.r, background.g, background.b, u8::MAX]),
+        );
+        for (y, row) in (0..).zip(string_data.iter()) {
+            let mut x = 0;
+            for cell in row {
+                let cell_width: u32 =
+                    UnicodeWidthChar::width(cell.character)
+                        .and_then(|width| width.try_into().ok())
+                        .unwrap_or(0);
+                let (top, bottom) = self.cell_colors(cell);
+                for dx in 0..cell_width {
+                    self.draw_square(&mut image, x + dx, 2 * y, top);
+                    self.draw_square(
+                        &mut image,
+                        x + dx,
+                        2 * y + 1,
+                        bottom,
+                    );
+                }
+                x += cell_width;
+            }
+        }
+        self.encode(image, delay)
+    }
+
+    /// Captures a frame of a color display like `to_image`, shown for the given delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame could not be encoded or written.
+    pub fn capture_display<T, D>(
+        &mut self,
+        display: &D,
+        delay: Duration,
+    ) -> ImageResult<()>
+    where
+        T: Pixel<U = TerminalColor>,
+        D: DynamicConsoleDisplay<T> + ?Sized,
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let image = display.to_image_with_background(
+            self.cell_px,
+            self.default_background,
+        );
+        self.encode(DynamicImage::ImageRgb8(image).into_rgba8(), delay)
+    }
+
+    /// Ends the GIF and releases the writer.
+    /// The encoder writes the trailer of the GIF when dropped,
+    /// so errors writing it can not be reported.
+    /// Nothing is written if no frames were captured.
+    pub fn finish(self) {
+        drop(self.encoder);
+    }
+
+    fn encode(
+        &mut self,
+        image: RgbaImage,
+        delay: Duration,
+    ) -> ImageResult<()> {
+        self.encoder.encode_frame(Frame::from_parts(
+            image,
+            0,
+            0,
+            Delay::from_saturating_duration(delay),
+        ))?;
+        self.frame_count += 1;
+        Ok(())
+    }
+
+    /// Returns the colors of the upper and lower half of a character.
+    fn cell_colors(&self, cell: &DataCell) -> (RGBColor, RGBColor) {
+        let foreground =
+            self.opaque(cell.foreground, self.default_foreground);
+        let background =
+            self.opaque(cell.background, self.default_background);
+        match cell.character {
+            ' ' => (background, background),
+            '█' => (foreground, foreground),
+            '▀' => (foreground, background),
+            '▄' => (background, foreground),
+            _ => {
+                let mixed = RGBColor::mix(&[foreground, background]);
+                (mixed, mixed)
+            }
+        }
+    }
+
+    /// Blends the color onto the default background,
+    /// drawing default colors in the given color instead.
+    fn opaque(&self, color: TerminalColor, default: RGBColor) -> RGBColor {
+        match color {
+            TerminalColor::Default => default,
+            TerminalColor::ARGBColor(_) => {
+                match TerminalColor::blend(
+                    &color,
+                    &self.default_background.into(),
+                ) {
+                    TerminalColor::ARGBColor(color) => color.color,
+                    TerminalColor::Default => default,
+                }
+            }
+        }
+    }
+
+    /// Fills the square of the pixel or half character at the given position.
+    fn draw_square(
+        &self,
+        image: &mut RgbaImage,
+        x: u32,
+        y: u32,
+        color: RGBColor,
+    ) {
+        let color = Rgba([color.r, color.g, color.b, u8::MAX]);
+        for dy in 0..self.cell_px {
+            for dx in 0..self.cell_px {
+                // Rows wider than the first one are cut off.
+                if let Some(pixel) = image.get_pixel_mut_checked(
+                    x * self.cell_px + dx,
+                    y * self.cell_px + dy,
+                ) {
+                    *pixel = color;
+                }
+            }
+        }
+    }
+}
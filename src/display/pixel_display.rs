@@ -1,8 +1,27 @@
 use core::array;
 use std::marker::PhantomData;
 
+#[cfg(feature = "image")]
+use image::{
+    DynamicImage,
+    imageops::FilterType,
+};
 use num_traits::NumCast;
+#[cfg(feature = "rayon")]
+use rayon::{
+    iter::{
+        IndexedParallelIterator,
+        IntoParallelRefMutIterator,
+        ParallelIterator,
+    },
+    slice::ParallelSlice,
+};
 
+#[cfg(feature = "image")]
+use crate::color::{
+    RGBColor,
+    TerminalColor,
+};
 use crate::{
     console_display::{
         DynamicConsoleDisplay,
@@ -20,9 +39,14 @@ use crate::{
         Dimension,
         RunTime,
     },
-    pixel::Pixel,
+    pixel::{
+        Pixel,
+        monochrome_pixel::CustomPixel,
+    },
     widget::{
+        DataCell,
         DynamicWidget,
+        Render,
         StaticWidget,
         StringData,
     },
@@ -124,11 +148,141 @@ impl<T: Pixel> PixelDisplay<RunTime, RunTime, T> {
             data: multi_pixels.into_boxed_slice(),
         })
     }
+
+    /// Changes the dimensions of the display.
+    /// Pixels in the region covered by both the old and new dimensions keep their values,
+    /// new pixels are set to `fill`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the new dimensions are not multiples of the pixel dimensions.
+    /// The display is left unchanged in that case.
+    pub fn resize(
+        &mut self,
+        new_width: usize,
+        new_height: usize,
+        fill: T::U,
+    ) -> Result<(), DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let pixels = self.pixels();
+        let mut data = Vec::with_capacity(new_width * new_height);
+        for y in 0..new_height {
+            for x in 0..new_width {
+                data.push(if x < self.width && y < self.height {
+                    pixels[x + y * self.width]
+                }
+                else {
+                    fill
+                });
+            }
+        }
+        // Keeps properties of the blocks besides their pixels, e.g. the table of `CustomPixel`.
+        let template = self.data.first().copied();
+        *self = Self::build_from_data(new_width, new_height, &data)?;
+        if let Some(template) = template {
+            for block in &mut self.data {
+                let pixels = *block.pixels();
+                *block = template;
+                *block.pixels_mut() = pixels;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "image")]
+impl<T: Pixel<U = TerminalColor>> PixelDisplay<RunTime, RunTime, T>
+where
+    [(); T::WIDTH * T::HEIGHT]:,
+{
+    /// Builds a display showing the image pixel by pixel.
+    /// The image is padded with black to a multiple of the dimensions of the pixel block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     console_display::DynamicConsoleDisplay,
+    ///     pixel::color_pixel::ColorOctPixel,
+    ///     pixel_display::DynamicPixelDisplay,
+    /// };
+    /// use image::{
+    ///     DynamicImage,
+    ///     RgbImage,
+    /// };
+    ///
+    /// let image = DynamicImage::ImageRgb8(RgbImage::new(3, 3));
+    /// let display = DynamicPixelDisplay::<ColorOctPixel>::from_image(&image);
+    ///
+    /// assert_eq!((display.width(), display.height()), (4, 4));
+    /// ```
+    #[must_use]
+    pub fn from_image(image: &DynamicImage) -> Self {
+        Self::from_image_with(image, None, FilterType::Triangle)
+    }
+
+    /// Builds a display showing the image like `from_image`.
+    /// Images larger than the maximum dimensions are first scaled down with the filter to fit,
+    /// preserving the aspect ratio.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the data generated from the image does not match the dimensions of the display.
+    /// This should not happen and is subject to change in the future.
+    #[must_use]
+    pub fn from_image_with(
+        image: &DynamicImage,
+        max_dimensions: Option<(u32, u32)>,
+        filter: FilterType,
+    ) -> Self {
+        let rgb = match max_dimensions {
+            Some((max_width, max_height))
+                if image.width() > max_width ||
+                    image.height() > max_height =>
+            {
+                image.resize(max_width, max_height, filter).to_rgb8()
+            }
+            _ => image.to_rgb8(),
+        };
+
+        let width = rgb.width() as usize;
+        let height = rgb.height() as usize;
+        let padded_width = width.next_multiple_of(T::WIDTH);
+        let padded_height = height.next_multiple_of(T::HEIGHT);
+
+        let mut data =
+            vec![RGBColor::BLACK.into(); padded_width * padded_height];
+        for (x, y, pixel) in rgb.enumerate_pixels() {
+            let [r, g, b] = pixel.0;
+            data[x as usize + y as usize * padded_width] =
+                RGBColor { r, g, b }.into();
+        }
+        Self::build_from_data(padded_width, padded_height, &data)
+            .expect(DATA_DOES_NOT_MATCH_DIMENSIONS)
+    }
 }
 
 impl<const WIDTH: usize, const HEIGHT: usize, T: Pixel>
     PixelDisplay<CompileTime<WIDTH>, CompileTime<HEIGHT>, T>
 {
+    /// Returns the width of the display in characters, i.e. `WIDTH` divided by the width of a pixel block.
+    /// As a const fn, this can be used in const fns taking a display, unlike `width_characters`.
+    #[must_use]
+    pub const fn width_chars(&self) -> usize {
+        Self::WIDTH_CHARACTERS
+    }
+
+    /// Returns the height of the display in characters, i.e. `HEIGHT` divided by the height of a pixel block.
+    #[must_use]
+    pub const fn height_chars(&self) -> usize {
+        Self::HEIGHT_CHARACTERS
+    }
+
     /// Convenience method to create a blank display struct with specified dimensions known at compile time.
     // TODO: Check if this fn can be const
     pub fn new(fill: T::U) -> Self
@@ -182,6 +336,167 @@ impl<const WIDTH: usize, const HEIGHT: usize, T: Pixel>
     }
 }
 
+impl<W: Dimension, H: Dimension, const PW: usize, const PH: usize>
+    PixelDisplay<W, H, CustomPixel<PW, PH>>
+where
+    CustomPixel<PW, PH>: Pixel,
+    [(); PW * PH]:,
+    [(); 1 << (PW * PH)]:,
+{
+    /// Displays all pixels using the provided table instead of `CustomPixel::DEFAULT_CHARS`.
+    /// The table is kept when the display is resized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     pixel::monochrome_pixel::CustomPixel,
+    ///     pixel_display::DynamicPixelDisplay,
+    ///     widget::DynamicWidget,
+    /// };
+    ///
+    /// const CHARS: [char; 4] = [' ', '\'', '.', ':'];
+    ///
+    /// let display = DynamicPixelDisplay::<CustomPixel<1, 2>>::new(1, 2, true)
+    ///     .with_chars(&CHARS);
+    ///
+    /// assert_eq!(display.string_data().to_string(), ":");
+    /// ```
+    #[must_use]
+    pub fn with_chars(
+        mut self,
+        chars: &'static [char; 1 << (PW * PH)],
+    ) -> Self {
+        for block in &mut self.data {
+            block.set_chars(chars);
+        }
+        self
+    }
+}
+
+impl<W: Dimension, H: Dimension, T: Pixel> PixelDisplay<W, H, T> {
+    /// Rebuilds the display with a different pixel block type at the same logical resolution,
+    /// e.g. to export an `OctPixel` display as a `QuadPixel` display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::{
+    ///     drawing::DynamicCanvas,
+    ///     pixel::monochrome_pixel::{
+    ///         OctPixel,
+    ///         QuadPixel,
+    ///     },
+    ///     pixel_display::StaticPixelDisplay,
+    /// };
+    ///
+    /// let mut disp = StaticPixelDisplay::<OctPixel, 4, 8>::new(false);
+    /// disp.set_pixel(3, 5, true).unwrap();
+    ///
+    /// let converted = disp.convert::<QuadPixel>().unwrap();
+    ///
+    /// assert_eq!(converted.pixel(3, 5), Ok(true));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of the display are not multiples of the dimensions of the new pixel type.
+    pub fn convert<S: Pixel<U = T::U>>(
+        &self,
+    ) -> Result<DynamicPixelDisplay<S>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        DynamicPixelDisplay::<S>::build_from_data(
+            self.width(),
+            self.height(),
+            &self.pixels(),
+        )
+    }
+
+    /// Copies the pixels of a rectangular region into a new, independent display.
+    /// Unlike `InsetWidget`, the result owns its data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the region extends past the edges of the display,
+    /// or if its dimensions are not multiples of the pixel dimensions.
+    pub fn subregion(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<DynamicPixelDisplay<T>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        DynamicPixelDisplay::<T>::build_from_data(
+            width,
+            height,
+            &self.read_region(x, y, width, height)?,
+        )
+    }
+
+    /// Returns a copy of the display rotated by 90 degrees clockwise.
+    /// The width and height of the result are swapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the swapped dimensions are not multiples of the pixel dimensions.
+    pub fn rotate_90_cw(
+        &self,
+    ) -> Result<DynamicPixelDisplay<T>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        self.rotate_90(|x, y, _, height| (height - 1 - y, x))
+    }
+
+    /// Returns a copy of the display rotated by 90 degrees counterclockwise.
+    /// The width and height of the result are swapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the swapped dimensions are not multiples of the pixel dimensions.
+    pub fn rotate_90_ccw(
+        &self,
+    ) -> Result<DynamicPixelDisplay<T>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        self.rotate_90(|x, y, width, _| (y, width - 1 - x))
+    }
+
+    /// Moves every pixel to the coordinates returned by `target`,
+    /// which receives the source coordinates and the dimensions of the display.
+    fn rotate_90<F: Fn(usize, usize, usize, usize) -> (usize, usize)>(
+        &self,
+        target: F,
+    ) -> Result<DynamicPixelDisplay<T>, DisplayError>
+    where
+        [(); T::WIDTH * T::HEIGHT]:,
+    {
+        let (width, height) = (self.width(), self.height());
+        let pixels = self.pixels();
+        let mut rotated = pixels.clone();
+        for y in 0..height {
+            for x in 0..width {
+                let (new_x, new_y) = target(x, y, width, height);
+                rotated[new_x + new_y * height] = pixels[x + y * width];
+            }
+        }
+        DynamicPixelDisplay::<T>::build_from_data(height, width, &rotated)
+    }
+}
+
 impl<W: Dimension, H: Dimension, T: Pixel> DynamicConsoleDisplay<T>
     for PixelDisplay<W, H, T>
 {
@@ -235,13 +550,18 @@ impl<W: Dimension, H: Dimension, T: Pixel> DynamicWidget
     }
 
     default fn string_data(&self) -> StringData {
-        StringData {
-            data: self
-                .data
-                .chunks(self.width_characters())
-                .map(|chunk| chunk.iter().map(|x| (*x).into()).collect())
-                .collect(),
-        }
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<W: Dimension, H: Dimension, T: Pixel> Render
+    for PixelDisplay<W, H, T>
+{
+    fn render_into(&self, buf: &mut StringData) {
+        buf.clear_rows(self.height_characters());
+        T::extend_rows(&self.data, self.width_characters(), &mut buf.data);
     }
 }
 
@@ -257,16 +577,52 @@ impl<T: Pixel, const WIDTH: usize, const HEIGHT: usize> DynamicWidget
     }
 
     fn string_data(&self) -> StringData {
-        StringData {
-            data: self
-                .data
-                .chunks(Self::WIDTH_CHARACTERS)
-                .map(|chunk| chunk.iter().map(|x| (*x).into()).collect())
-                .collect(),
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+/// Converts the pixels of a display to cells.
+trait ExtendRows: Pixel {
+    /// Extends every row by the cells of the corresponding chunk of `width` pixels.
+    fn extend_rows(
+        data: &[Self],
+        width: usize,
+        rows: &mut [Vec<DataCell>],
+    );
+}
+
+impl<T: Pixel> ExtendRows for T {
+    default fn extend_rows(
+        data: &[Self],
+        width: usize,
+        rows: &mut [Vec<DataCell>],
+    ) {
+        for (row, chunk) in rows.iter_mut().zip(data.chunks(width)) {
+            row.extend(chunk.iter().map(|x| Into::<DataCell>::into(*x)));
         }
     }
 }
 
+/// Converts the rows in parallel, as they are independent of each other.
+#[cfg(feature = "rayon")]
+impl<T: Pixel + Sync> ExtendRows for T {
+    fn extend_rows(
+        data: &[Self],
+        width: usize,
+        rows: &mut [Vec<DataCell>],
+    ) {
+        rows.par_iter_mut().zip(data.par_chunks(width)).for_each(
+            |(row, chunk)| {
+                row.extend(
+                    chunk.iter().map(|x| Into::<DataCell>::into(*x)),
+                );
+            },
+        );
+    }
+}
+
 impl<W: Dimension, H: Dimension, S: Pixel> DynamicCanvas<S>
     for PixelDisplay<W, H, S>
 {
@@ -369,7 +725,10 @@ impl<T: Pixel, const WIDTH: usize, const HEIGHT: usize> const StaticWidget
 
 #[cfg(test)]
 mod tests {
-    use crate::pixel::monochrome_pixel::SinglePixel;
+    use crate::pixel::monochrome_pixel::{
+        DualPixel,
+        SinglePixel,
+    };
 
     use super::*;
 
@@ -378,6 +737,83 @@ mod tests {
 
         use super::*;
 
+        #[cfg(feature = "image")]
+        #[test]
+        fn from_image() {
+            use image::{
+                DynamicImage,
+                Rgb,
+                RgbImage,
+                imageops::FilterType,
+            };
+
+            use crate::{
+                color::RGBColor,
+                pixel::color_pixel::ColorOctPixel,
+            };
+
+            let mut image = RgbImage::from_pixel(2, 4, Rgb([0, 0, 255]));
+            image.put_pixel(1, 3, Rgb([255, 0, 0]));
+            let image = DynamicImage::ImageRgb8(image);
+
+            let display =
+                DynamicPixelDisplay::<ColorOctPixel>::from_image(&image);
+            assert_eq!((display.width(), display.height()), (2, 4));
+            assert_eq!(display.pixel(0, 0), Ok(RGBColor::BLUE.into()));
+            assert_eq!(display.pixel(1, 3), Ok(RGBColor::RED.into()));
+
+            let display =
+                DynamicPixelDisplay::<ColorOctPixel>::from_image_with(
+                    &image,
+                    Some((1, 2)),
+                    FilterType::Nearest,
+                );
+            assert_eq!((display.width(), display.height()), (2, 4));
+            assert_eq!(display.pixel(1, 3), Ok(RGBColor::BLACK.into()));
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        fn string_data_parallel() {
+            use rand::{
+                RngExt,
+                rng,
+            };
+
+            use crate::{
+                color::RGBColor,
+                pixel::color_pixel::ColorOctPixel,
+            };
+
+            let (width, height) = (64, 64);
+            let data = (0..width * height)
+                .map(|_| {
+                    RGBColor {
+                        r: rng().random_range(0..=255),
+                        g: rng().random_range(0..=255),
+                        b: rng().random_range(0..=255),
+                    }
+                    .into()
+                })
+                .collect::<Vec<_>>();
+            let display =
+                DynamicPixelDisplay::<ColorOctPixel>::build_from_data(
+                    width, height, &data,
+                )
+                .unwrap();
+            let sequential = display
+                .data()
+                .chunks(display.width_characters())
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .map(|x| Into::<DataCell>::into(*x))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>();
+            assert_eq!(display.string_data().data, sequential);
+        }
+
         #[test]
         fn build_from_data_success() {
             let dynamic_pixel_display =
@@ -437,6 +873,138 @@ mod tests {
             assert_eq!(pixel, Ok(true));
         }
 
+        #[test]
+        fn resize() {
+            let mut display =
+                DynamicPixelDisplay::<SinglePixel>::build_from_data(
+                    2,
+                    2,
+                    &[true, false, false, true],
+                )
+                .unwrap();
+            display.resize(4, 4, false).unwrap();
+            assert_eq!(display.width(), 4);
+            assert_eq!(display.height(), 4);
+            assert_eq!(
+                display.pixels(),
+                vec![
+                    true, false, false, false, //
+                    false, true, false, false, //
+                    false, false, false, false, //
+                    false, false, false, false,
+                ]
+            );
+            display.resize(1, 2, false).unwrap();
+            assert_eq!(display.pixels(), vec![true, false]);
+        }
+
+        #[test]
+        fn custom_chars() {
+            use crate::pixel::monochrome_pixel::CustomPixel;
+
+            const CHARS: [char; 16] = [
+                'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', //
+                'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p',
+            ];
+
+            let mut display =
+                DynamicPixelDisplay::<CustomPixel<2, 2>>::new(2, 2, true)
+                    .with_chars(&CHARS);
+            assert_eq!(display.string_data().to_string(), "p");
+            display.set_pixel(0, 0, false).unwrap();
+            assert_eq!(display.string_data().to_string(), "o");
+            display.resize(4, 2, false).unwrap();
+            assert_eq!(display.string_data().to_string(), "oa");
+        }
+
+        #[test]
+        fn resize_failure() {
+            let mut display =
+                DynamicPixelDisplay::<DualPixel>::new(2, 2, true);
+            assert_eq!(
+                display.resize(2, 3, false),
+                Err(
+                    DisplayError::DisplayDimensionsNotMultipleOfPixelDimensions(
+                        2, 1, 3, 2
+                    )
+                )
+            );
+            assert_eq!(display.height(), 2);
+        }
+
+        #[test]
+        fn subregion() {
+            let mut display =
+                DynamicPixelDisplay::<SinglePixel>::new(4, 4, false);
+            display.set_pixel(2, 3, true).unwrap();
+            let region = display.subregion(1, 2, 2, 2).unwrap();
+            assert_eq!(region.width(), 2);
+            assert_eq!(region.height(), 2);
+            assert_eq!(region.pixels(), vec![false, false, false, true]);
+            assert!(display.subregion(3, 0, 2, 2).is_err());
+            assert!(
+                DynamicPixelDisplay::<DualPixel>::new(4, 4, false)
+                    .subregion(0, 0, 2, 1)
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn rotate_90() {
+            let mut display =
+                DynamicPixelDisplay::<SinglePixel>::new(2, 4, false);
+            display.set_pixel(0, 0, true).unwrap();
+            display.set_pixel(1, 3, true).unwrap();
+
+            let cw = display.rotate_90_cw().unwrap();
+            assert_eq!((cw.width(), cw.height()), (4, 2));
+            assert_eq!(cw.pixel(3, 0), Ok(true));
+            assert_eq!(cw.pixel(0, 1), Ok(true));
+
+            let ccw = display.rotate_90_ccw().unwrap();
+            assert_eq!(ccw.pixel(0, 1), Ok(true));
+            assert_eq!(ccw.pixel(3, 0), Ok(true));
+            assert_eq!(ccw.pixels().iter().filter(|&&x| x).count(), 2);
+            assert_eq!(ccw.rotate_90_cw().unwrap(), display);
+
+            assert!(
+                DynamicPixelDisplay::<DualPixel>::new(4, 2, false)
+                    .rotate_90_cw()
+                    .is_ok()
+            );
+            assert!(
+                DynamicPixelDisplay::<DualPixel>::new(1, 2, false)
+                    .rotate_90_cw()
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn convert() {
+            let mut display =
+                DynamicPixelDisplay::<SinglePixel>::new(2, 4, false);
+            display.set_pixel(1, 3, true).unwrap();
+            let converted = display.convert::<DualPixel>().unwrap();
+            assert_eq!(converted.width_characters(), 2);
+            assert_eq!(converted.height_characters(), 2);
+            assert_eq!(converted.pixel(1, 3), Ok(true));
+            assert_eq!(converted.pixels(), display.pixels());
+        }
+
+        #[test]
+        fn convert_failure() {
+            let display =
+                DynamicPixelDisplay::<SinglePixel>::new(3, 3, false);
+            assert_eq!(
+                display.convert::<DualPixel>(),
+                Err(
+                    DisplayError::DisplayDimensionsNotMultipleOfPixelDimensions(
+                        3, 1, 3, 2
+                    )
+                )
+            );
+        }
+
         #[test]
         fn set_pixel_failure() {
             let mut dynamic_pixel_display =
@@ -445,4 +1013,36 @@ mod tests {
             assert!(res.is_err());
         }
     }
+
+    mod static_pixel_display {
+        use super::*;
+
+        // Only compiles while `width_chars` and `height_chars` are const fns.
+        // Displays allocate their pixels, so they can not be built in a const item to call them directly.
+        const fn area_chars<T: Pixel, const W: usize, const H: usize>(
+            display: &StaticPixelDisplay<T, W, H>,
+        ) -> usize {
+            display.width_chars() * display.height_chars()
+        }
+
+        #[test]
+        fn dimensions_chars() {
+            let display =
+                StaticPixelDisplay::<DualPixel, 3, 4>::new(false);
+            assert_eq!(display.width_chars(), 3);
+            assert_eq!(display.height_chars(), 2);
+            assert_eq!(area_chars(&display), 6);
+        }
+
+        #[test]
+        fn display_macro() {
+            let display = crate::display!(DualPixel, 3 x 4, fill = true);
+            assert_eq!((display.width(), display.height()), (3, 4));
+            assert_eq!(display.pixels(), vec![true; 12]);
+
+            let display = crate::display!(DualPixel, 3, 4);
+            assert_eq!((display.width(), display.height()), (3, 4));
+            assert_eq!(display.pixels(), vec![false; 12]);
+        }
+    }
 }
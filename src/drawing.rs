@@ -1,24 +1,39 @@
 use core::f32;
 use std::marker::PhantomData;
 
-use num_traits::NumCast;
+use num_traits::{
+    NumCast,
+    ToPrimitive,
+};
 
 use crate::{
-    error::DrawingError,
+    color::{
+        ARGBColor,
+        Color,
+        TerminalColor,
+    },
+    error::{
+        DisplayError,
+        DrawingError,
+    },
     pixel::Pixel,
-    widget::DynamicWidget,
+    widget::{
+        DynamicWidget,
+        Region,
+        StringData,
+    },
 };
 
 /// Defines a fill for a drawable.
 pub const trait FillType {}
 
 /// Defines no fill on a drawable, e.g. only outline.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct NoFill;
 impl const FillType for NoFill {}
 
 /// Defines flat fill on a drawable.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Filled;
 impl const FillType for Filled {}
 
@@ -130,8 +145,132 @@ pub const trait DynamicDrawable<const N: usize> {
     ) -> Self;
 }
 
+/// Provides common affine transformations for drawables, built on `DynamicDrawable::transform`.
+pub trait AffineTransform<const N: usize>:
+    DynamicDrawable<N> + Sized
+{
+    /// Moves the drawable by the specified offset.
+    #[must_use]
+    fn translate(&self, dx: f32, dy: f32) -> Self {
+        self.transform(|(x, y)| (x + dx, y + dy))
+    }
+
+    /// Scales the drawable by the specified factors relative to the point `about`.
+    #[must_use]
+    fn scale(&self, sx: f32, sy: f32, about: (f32, f32)) -> Self {
+        self.transform(|(x, y)| {
+            (
+                (x - about.0).mul_add(sx, about.0),
+                (y - about.1).mul_add(sy, about.1),
+            )
+        })
+    }
+
+    /// Rotates the drawable by `angle` radians around the point `about`.
+    /// As the y axis of a canvas points down, positive angles rotate clockwise on screen.
+    #[must_use]
+    fn rotate(&self, angle: f32, about: (f32, f32)) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        self.transform(|(x, y)| {
+            let (dx, dy) = (x - about.0, y - about.1);
+            (
+                dx.mul_add(cos, -dy * sin) + about.0,
+                dx.mul_add(sin, dy * cos) + about.1,
+            )
+        })
+    }
+}
+
+impl<D: DynamicDrawable<N>, const N: usize> AffineTransform<N> for D {}
+
+/// Sets all pixels produced by a rasterization iterator on a canvas.
+/// Pixels with negative coordinates or outside the canvas are skipped.
+fn plot_pixels<T: DynamicCanvas<S>, S: Pixel>(
+    display: &mut T,
+    pixels: impl Iterator<Item = (i32, i32)>,
+    value: S::U,
+) where
+    [(); S::WIDTH * S::HEIGHT]:,
+{
+    for (x, y) in pixels {
+        if x < 0 || y < 0 {
+            continue;
+        }
+        if let Some(x) = NumCast::from(x) &&
+            let Some(y) = NumCast::from(y)
+        {
+            let _ = display.set_pixel(x, y, value);
+        }
+    }
+}
+
+/// Restricts drawing on a canvas to a region of it.
+/// Setting pixels outside the region fails as if they were out of bounds,
+/// so drawables only affect pixels inside the region.
+pub(crate) struct ClippedCanvas<'a, T> {
+    canvas: &'a mut T,
+    region: Region,
+}
+
+impl<'a, T> ClippedCanvas<'a, T> {
+    pub(crate) const fn new(canvas: &'a mut T, region: Region) -> Self {
+        Self { canvas, region }
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for ClippedCanvas<'_, T> {
+    fn width_characters(&self) -> usize {
+        self.canvas.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.canvas.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        self.canvas.string_data()
+    }
+}
+
+impl<T: DynamicCanvas<S>, S: Pixel> DynamicCanvas<S>
+    for ClippedCanvas<'_, T>
+{
+    type A = T::A;
+
+    fn pixel(&self, x: Self::A, y: Self::A) -> Result<S::U, DrawingError>
+    where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        self.canvas.pixel(x, y)
+    }
+
+    fn set_pixel(
+        &mut self,
+        x: Self::A,
+        y: Self::A,
+        value: S::U,
+    ) -> Result<(), DrawingError>
+    where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        let (Some(column), Some(row)) = (x.to_usize(), y.to_usize())
+        else {
+            return Err(DisplayError::CoordinatesToUsizeConversionFailed)?;
+        };
+        if !self.region.contains(column, row) {
+            return Err(DisplayError::CoordinatesOutOfBounds(
+                column,
+                self.region.x + self.region.width,
+                row,
+                self.region.y + self.region.height,
+            ))?;
+        }
+        self.canvas.set_pixel(x, y, value)
+    }
+}
+
 /// Defines a line primitive by two endpoints.
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Line {
     pub x1: f32,
     pub y1: f32,
@@ -139,6 +278,36 @@ pub struct Line {
     pub y2: f32,
 }
 
+impl Line {
+    /// Returns the coordinates of the pixels covered by the line, from the first to the second endpoint.
+    /// Coordinates are not clipped, so they may be negative.
+    pub fn pixels(&self) -> impl Iterator<Item = (i32, i32)> + use<> {
+        let dx = self.x2 - self.x1;
+        let dy = self.y2 - self.y1;
+
+        let steps: f32 = dx.abs().max(dy.abs());
+        let x_inc = dx / steps;
+        let y_inc = dy / steps;
+
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let step_count = steps.round() as usize;
+        (0..=step_count)
+            .scan((self.x1, self.y1), move |(x, y), _| {
+                let point = (*x, *y);
+                *x += x_inc;
+                *y += y_inc;
+                Some(point)
+            })
+            .filter_map(|(x, y)| {
+                Some((
+                    NumCast::from(x.round())?,
+                    NumCast::from(y.round())?,
+                ))
+            })
+    }
+}
+
 impl DynamicDrawable<2> for Line {
     fn draw<T: DynamicCanvas<S>, S: Pixel>(
         &self,
@@ -147,36 +316,184 @@ impl DynamicDrawable<2> for Line {
     ) where
         [(); S::WIDTH * S::HEIGHT]:,
     {
-        let dx = self.x2 - self.x1;
-        let dy = self.y2 - self.y1;
+        plot_pixels(display, self.pixels(), value);
+    }
 
-        let steps: f32 = dx.abs().max(dy.abs());
-        let x_inc = dx / steps;
-        let y_inc = dy / steps;
+    fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
+        &self,
+        transform: F,
+    ) -> Self {
+        let trans_p1 = transform((self.x1, self.y1));
+        let trans_p2 = transform((self.x2, self.y2));
+        Self {
+            x1: trans_p1.0,
+            y1: trans_p1.1,
+            x2: trans_p2.0,
+            y2: trans_p2.1,
+        }
+    }
+}
 
-        let mut x = self.x1;
-        let mut y = self.y1;
+/// Defines a point primitive drawn as a filled disc around its center.
+/// A radius of 0 draws a single pixel.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+}
 
-        #[allow(clippy::cast_possible_truncation)]
-        #[allow(clippy::cast_sign_loss)]
-        for _ in 0..=steps.round() as usize {
-            if x > -0.5 && y > -0.5 {
-                let x = NumCast::from(x.round());
-                let y = NumCast::from(y.round());
-                if let Some(x) = x &&
-                    let Some(y) = y
-                {
-                    let _ = display.set_pixel(x, y, value);
+impl DynamicDrawable<1> for Point {
+    #[allow(clippy::cast_possible_truncation)]
+    fn draw<T: DynamicCanvas<S>, S: Pixel>(
+        &self,
+        display: &mut T,
+        value: S::U,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        let center_x = self.x.round();
+        let center_y = self.y.round();
+        let extent = self.radius.max(0.).ceil() as i32;
+        for dy in -extent..=extent {
+            for dx in -extent..=extent {
+                let (dx, dy) = (dx as f32, dy as f32);
+                if dx.hypot(dy) > self.radius.max(0.) {
+                    continue;
+                }
+                let x = center_x + dx;
+                let y = center_y + dy;
+                if x > -0.5 && y > -0.5 {
+                    let x = NumCast::from(x);
+                    let y = NumCast::from(y);
+                    if let Some(x) = x &&
+                        let Some(y) = y
+                    {
+                        let _ = display.set_pixel(x, y, value);
+                    }
                 }
             }
-            x += x_inc;
-            y += y_inc;
         }
     }
 
+    /// Transforms the center of the point.
+    /// The radius is left unchanged.
     fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
         &self,
         transform: F,
+    ) -> Self {
+        let (x, y) = transform((self.x, self.y));
+        Self {
+            x,
+            y,
+            radius: self.radius,
+        }
+    }
+}
+
+/// Defines an anti-aliased line primitive by two endpoints.
+/// Uses Xiaolin Wu's algorithm, so it can only be drawn onto canvases with colored pixels.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AntialiasedLine {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl AntialiasedLine {
+    /// Draws the line onto a canvas with colored pixels.
+    /// Each pixel is covered partially depending on its distance to the ideal line.
+    /// The coverage scales the opacity of the color, which is then blended over the existing pixel.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn draw<T: DynamicCanvas<S>, S: Pixel<U = TerminalColor>>(
+        &self,
+        display: &mut T,
+        value: TerminalColor,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        let steep = (self.y2 - self.y1).abs() > (self.x2 - self.x1).abs();
+        let (mut x1, mut y1, mut x2, mut y2) = if steep {
+            (self.y1, self.x1, self.y2, self.x2)
+        }
+        else {
+            (self.x1, self.y1, self.x2, self.y2)
+        };
+        if x1 > x2 {
+            (x1, x2) = (x2, x1);
+            (y1, y2) = (y2, y1);
+        }
+
+        let dx = x2 - x1;
+        let gradient = if dx == 0. { 0. } else { (y2 - y1) / dx };
+
+        let mut plot = |major: f32, minor: f32, coverage: f32| {
+            let (x, y) = if steep {
+                (minor, major)
+            }
+            else {
+                (major, minor)
+            };
+            Self::plot(display, x, y, value, coverage);
+        };
+
+        for major in x1.round() as i32..=x2.round() as i32 {
+            let major = major as f32;
+            let minor = gradient.mul_add(major - x1, y1);
+            let coverage = minor - minor.floor();
+            plot(major, minor.floor(), 1. - coverage);
+            plot(major, minor.floor() + 1., coverage);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn plot<T: DynamicCanvas<S>, S: Pixel<U = TerminalColor>>(
+        display: &mut T,
+        x: f32,
+        y: f32,
+        value: TerminalColor,
+        coverage: f32,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        if coverage <= 0. {
+            return;
+        }
+        let color = match value {
+            TerminalColor::ARGBColor(color) => ARGBColor {
+                opacity: (Into::<f32>::into(color.opacity) * coverage)
+                    .round()
+                    .clamp(0., 255.) as u8,
+                color: color.color,
+            }
+            .into(),
+            TerminalColor::Default if coverage < 0.5 => return,
+            TerminalColor::Default => value,
+        };
+        // Coordinates are cast twice, since `T::A` is not necessarily `Copy`.
+        if let Some(x_read) = NumCast::from(x) &&
+            let Some(y_read) = NumCast::from(y) &&
+            let Ok(bottom) = display.pixel(x_read, y_read) &&
+            let Some(x) = NumCast::from(x) &&
+            let Some(y) = NumCast::from(y)
+        {
+            let _ = display.set_pixel(
+                x,
+                y,
+                TerminalColor::blend(&color, &bottom),
+            );
+        }
+    }
+
+    /// Transforms the line by applying a function to both endpoints.
+    /// Returns a new transformed line.
+    #[must_use]
+    pub fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
+        &self,
+        transform: F,
     ) -> Self {
         let trans_p1 = transform((self.x1, self.y1));
         let trans_p2 = transform((self.x2, self.y2));
@@ -189,8 +506,90 @@ impl DynamicDrawable<2> for Line {
     }
 }
 
+/// Defines a rectangular block of pixel values that can be stamped onto a canvas.
+/// Pixel values are stored in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sprite<S: Pixel> {
+    width: usize,
+    height: usize,
+    data: Vec<S::U>,
+}
+
+impl<S: Pixel> Sprite<S> {
+    /// Builds a sprite from row-major pixel values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the data does not match the dimensions.
+    pub fn build(
+        width: usize,
+        height: usize,
+        data: Vec<S::U>,
+    ) -> Result<Self, DisplayError> {
+        if data.len() != width * height {
+            return Err(DisplayError::MismatchedDimensions(
+                width * height,
+                data.len(),
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Returns the width of the sprite in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the sprite in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the pixel values in row-major order.
+    #[must_use]
+    pub fn data(&self) -> &[S::U] {
+        &self.data
+    }
+
+    /// Returns the pixel value at the specified coordinate or `None` if it is out of bounds.
+    #[must_use]
+    pub fn pixel(&self, x: usize, y: usize) -> Option<S::U> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.data[x + y * self.width])
+    }
+
+    /// Copies the sprite onto the canvas with its top left corner at the specified coordinate.
+    /// Pixels outside of the canvas are skipped.
+    pub fn stamp<T: DynamicCanvas<S>>(
+        &self,
+        canvas: &mut T,
+        x: usize,
+        y: usize,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        for (i, value) in self.data.iter().enumerate() {
+            let canvas_x = NumCast::from(x + i % self.width);
+            let canvas_y = NumCast::from(y + i / self.width);
+            if let Some(canvas_x) = canvas_x &&
+                let Some(canvas_y) = canvas_y
+            {
+                let _ = canvas.set_pixel(canvas_x, canvas_y, *value);
+            }
+        }
+    }
+}
+
 /// Defines a Rectangle by two corners.
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Rectangle<FILL: FillType> {
     pub x1: f32,
     pub y1: f32,
@@ -199,14 +598,10 @@ pub struct Rectangle<FILL: FillType> {
     pub fill: PhantomData<FILL>,
 }
 
-impl DynamicDrawable<2> for Rectangle<NoFill> {
-    fn draw<T: DynamicCanvas<S>, S: Pixel>(
-        &self,
-        display: &mut T,
-        value: S::U,
-    ) where
-        [(); S::WIDTH * S::HEIGHT]:,
-    {
+impl Rectangle<NoFill> {
+    /// Returns the coordinates of the pixels covered by the outline of the rectangle.
+    /// Corner pixels are produced more than once.
+    pub fn pixels(&self) -> impl Iterator<Item = (i32, i32)> + use<> {
         let lines = [
             Line {
                 x1: self.x1,
@@ -233,10 +628,19 @@ impl DynamicDrawable<2> for Rectangle<NoFill> {
                 y2: self.y2,
             },
         ];
+        lines.into_iter().flat_map(|line| line.pixels())
+    }
+}
 
-        for line in lines {
-            line.draw(display, value);
-        }
+impl DynamicDrawable<2> for Rectangle<NoFill> {
+    fn draw<T: DynamicCanvas<S>, S: Pixel>(
+        &self,
+        display: &mut T,
+        value: S::U,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        plot_pixels(display, self.pixels(), value);
     }
 
     fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
@@ -255,6 +659,25 @@ impl DynamicDrawable<2> for Rectangle<NoFill> {
     }
 }
 
+impl Rectangle<Filled> {
+    /// Returns the coordinates of the pixels covered by the rectangle, column by column.
+    pub fn pixels(&self) -> impl Iterator<Item = (i32, i32)> + use<> {
+        let (y1, y2) = (self.y1, self.y2);
+        #[allow(clippy::cast_possible_truncation)]
+        (self.x1.round() as i32..=self.x2.round() as i32).flat_map(
+            move |x| {
+                Line {
+                    x1: x as f32,
+                    y1,
+                    x2: x as f32,
+                    y2,
+                }
+                .pixels()
+            },
+        )
+    }
+}
+
 impl DynamicDrawable<2> for Rectangle<Filled> {
     fn draw<T: DynamicCanvas<S>, S: Pixel>(
         &self,
@@ -263,16 +686,7 @@ impl DynamicDrawable<2> for Rectangle<Filled> {
     ) where
         [(); S::WIDTH * S::HEIGHT]:,
     {
-        #[allow(clippy::cast_possible_truncation)]
-        for x in self.x1.round() as i32..=self.x2.round() as i32 {
-            let line = Line {
-                x1: x as f32,
-                y1: self.y1,
-                x2: x as f32,
-                y2: self.y2,
-            };
-            line.draw(display, value);
-        }
+        plot_pixels(display, self.pixels(), value);
     }
 
     fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
@@ -291,21 +705,57 @@ impl DynamicDrawable<2> for Rectangle<Filled> {
     }
 }
 
-// TODO: Check correctness of implementation
-/// Defines a Circle by its midpoint and two vectors from the midpoint to the circumference.
-#[derive(PartialEq, Debug)]
-pub struct Ellipse<FILL: FillType> {
-    pub midpoint_x: f32,
-    pub midpoint_y: f32,
+/// Defines a rectangle with rounded corners by two corners and a corner radius.
+/// The radius is limited to half the width and height of the rectangle.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RoundedRectangle<FILL: FillType> {
     pub x1: f32,
     pub y1: f32,
     pub x2: f32,
     pub y2: f32,
-    pub num_points: u32,
+    pub radius: f32,
     pub fill: PhantomData<FILL>,
 }
 
-impl DynamicDrawable<3> for Ellipse<NoFill> {
+impl<FILL: FillType> RoundedRectangle<FILL> {
+    /// Returns the corners ordered by ascending coordinates and the effective radius.
+    fn normalized(&self) -> (f32, f32, f32, f32, f32) {
+        let (x1, x2) = (self.x1.min(self.x2), self.x1.max(self.x2));
+        let (y1, y2) = (self.y1.min(self.y2), self.y1.max(self.y2));
+        let radius =
+            self.radius.max(0.).min((x2 - x1) / 2.).min((y2 - y1) / 2.);
+        (x1, y1, x2, y2, radius)
+    }
+
+    /// Maps both corners and scales the radius by the geometric mean of the scaling along both axes.
+    fn transformed<F: Fn((f32, f32)) -> (f32, f32)>(
+        &self,
+        transform: F,
+    ) -> Self {
+        let trans_p1 = transform((self.x1, self.y1));
+        let trans_p2 = transform((self.x2, self.y2));
+        let width = (self.x2 - self.x1).abs();
+        let height = (self.y2 - self.y1).abs();
+        let radius = if width > 0. && height > 0. {
+            let scale_x = (trans_p2.0 - trans_p1.0).abs() / width;
+            let scale_y = (trans_p2.1 - trans_p1.1).abs() / height;
+            self.radius * (scale_x * scale_y).sqrt()
+        }
+        else {
+            self.radius
+        };
+        Self {
+            x1: trans_p1.0,
+            y1: trans_p1.1,
+            x2: trans_p2.0,
+            y2: trans_p2.1,
+            radius,
+            fill: PhantomData::<FILL>,
+        }
+    }
+}
+
+impl DynamicDrawable<2> for RoundedRectangle<NoFill> {
     fn draw<T: DynamicCanvas<S>, S: Pixel>(
         &self,
         display: &mut T,
@@ -313,23 +763,135 @@ impl DynamicDrawable<3> for Ellipse<NoFill> {
     ) where
         [(); S::WIDTH * S::HEIGHT]:,
     {
-        let first_point;
-        let mut last_point;
-        let x = self.midpoint_x + self.x1;
-        let y = self.midpoint_y + self.y1;
-        let x = NumCast::from(x.round());
-        let y = NumCast::from(y.round());
-        if let Some(x) = x &&
-            let Some(y) = y
-        {
-            first_point = (x, y);
-            last_point = (x, y);
+        let (x1, y1, x2, y2, radius) = self.normalized();
+
+        let edges = [
+            Line {
+                x1: x1 + radius,
+                y1,
+                x2: x2 - radius,
+                y2: y1,
+            },
+            Line {
+                x1: x1 + radius,
+                y1: y2,
+                x2: x2 - radius,
+                y2,
+            },
+            Line {
+                x1,
+                y1: y1 + radius,
+                x2: x1,
+                y2: y2 - radius,
+            },
+            Line {
+                x1: x2,
+                y1: y1 + radius,
+                x2,
+                y2: y2 - radius,
+            },
+        ];
+        for edge in edges {
+            edge.draw(display, value);
         }
-        else {
+
+        if radius <= 0. {
             return;
         }
 
-        for i in 1..self.num_points {
+        // Each corner is given by the center of its arc and the angle the arc starts at.
+        let corners = [
+            (x1 + radius, y1 + radius, f32::consts::PI),
+            (x2 - radius, y1 + radius, f32::consts::PI * 1.5),
+            (x2 - radius, y2 - radius, 0.),
+            (x1 + radius, y2 - radius, f32::consts::FRAC_PI_2),
+        ];
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let segments =
+            (radius * f32::consts::FRAC_PI_2).ceil().max(1.) as u32;
+        for (center_x, center_y, start) in corners {
+            let point_at = |i: u32| {
+                let angle = (i as f32 / segments as f32)
+                    .mul_add(f32::consts::FRAC_PI_2, start);
+                (
+                    radius.mul_add(angle.cos(), center_x).round(),
+                    radius.mul_add(angle.sin(), center_y).round(),
+                )
+            };
+            for i in 0..segments {
+                let (x1, y1) = point_at(i);
+                let (x2, y2) = point_at(i + 1);
+                Line { x1, y1, x2, y2 }.draw(display, value);
+            }
+        }
+    }
+
+    fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
+        &self,
+        transform: F,
+    ) -> Self {
+        self.transformed(transform)
+    }
+}
+
+impl DynamicDrawable<2> for RoundedRectangle<Filled> {
+    fn draw<T: DynamicCanvas<S>, S: Pixel>(
+        &self,
+        display: &mut T,
+        value: S::U,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        let (x1, y1, x2, y2, radius) = self.normalized();
+
+        #[allow(clippy::cast_possible_truncation)]
+        for y in y1.round() as i32..=y2.round() as i32 {
+            let y = y as f32;
+            // Vertical distance into the rounded part of the corners.
+            let corner_y =
+                (y1 + radius - y).max(y - (y2 - radius)).max(0.);
+            let inset = radius -
+                radius.mul_add(radius, -corner_y.powi(2)).max(0.).sqrt();
+            Line {
+                x1: (x1 + inset).round(),
+                y1: y,
+                x2: (x2 - inset).round(),
+                y2: y,
+            }
+            .draw(display, value);
+        }
+    }
+
+    fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
+        &self,
+        transform: F,
+    ) -> Self {
+        self.transformed(transform)
+    }
+}
+
+// TODO: Check correctness of implementation
+/// Defines a Circle by its midpoint and two vectors from the midpoint to the circumference.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ellipse<FILL: FillType> {
+    pub midpoint_x: f32,
+    pub midpoint_y: f32,
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+    pub num_points: u32,
+    pub fill: PhantomData<FILL>,
+}
+
+impl Ellipse<NoFill> {
+    /// Returns the coordinates of the pixels covered by the outline of the ellipse.
+    /// The outline is approximated by lines between `num_points` points on the circumference.
+    pub fn pixels(&self) -> impl Iterator<Item = (i32, i32)> + use<> {
+        let mut points = Vec::with_capacity(self.num_points as usize);
+        let mut closed = true;
+        for i in 0..self.num_points {
             let angle =
                 f32::consts::TAU / self.num_points as f32 * i as f32;
             let x = self.x2.mul_add(
@@ -340,52 +902,69 @@ impl DynamicDrawable<3> for Ellipse<NoFill> {
                 f32::sin(angle),
                 self.y1.mul_add(f32::cos(angle), self.midpoint_y),
             );
-            let x = NumCast::from(x.round());
-            let y = NumCast::from(y.round());
+            let x: Option<f32> = NumCast::from(x.round());
+            let y: Option<f32> = NumCast::from(y.round());
             if let Some(x) = x &&
                 let Some(y) = y
             {
-                display.draw(
-                    &Line {
-                        x1: last_point.0,
-                        y1: last_point.1,
-                        x2: x,
-                        y2: y,
-                    },
-                    value,
-                );
-                last_point = (x, y);
+                points.push((x, y));
             }
             else {
-                return;
+                closed = false;
+                break;
             }
         }
+        if closed && let Some(&first) = points.first() {
+            points.push(first);
+        }
 
-        display.draw(
-            &Line {
-                x1: last_point.0,
-                y1: last_point.1,
-                x2: first_point.0,
-                y2: first_point.1,
-            },
-            value,
-        );
+        (1..points.len()).flat_map(move |i| {
+            Line {
+                x1: points[i - 1].0,
+                y1: points[i - 1].1,
+                x2: points[i].0,
+                y2: points[i].1,
+            }
+            .pixels()
+        })
     }
+}
 
+impl DynamicDrawable<3> for Ellipse<NoFill> {
+    fn draw<T: DynamicCanvas<S>, S: Pixel>(
+        &self,
+        display: &mut T,
+        value: S::U,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        plot_pixels(display, self.pixels(), value);
+    }
+
+    /// Moves the midpoint with the function.
+    /// As the axes are vectors relative to the midpoint,
+    /// they are replaced by the difference between their transformed end points and the transformed midpoint,
+    /// so translations keep them and rotations or scalings turn and stretch them.
     fn transform<F: Fn((f32, f32)) -> (f32, f32)>(
         &self,
         transform: F,
     ) -> Self {
         let trans_mid = transform((self.midpoint_x, self.midpoint_y));
-        let trans_p1 = transform((self.x1, self.y1));
-        let trans_p2 = transform((self.x2, self.y2));
+        let trans_p1 = transform((
+            self.midpoint_x + self.x1,
+            self.midpoint_y + self.y1,
+        ));
+        let trans_p2 = transform((
+            self.midpoint_x + self.x2,
+            self.midpoint_y + self.y2,
+        ));
         Self {
             midpoint_x: trans_mid.0,
             midpoint_y: trans_mid.1,
-            x1: trans_p1.0,
-            y1: trans_p1.1,
-            x2: trans_p2.0,
-            y2: trans_p2.1,
+            x1: trans_p1.0 - trans_mid.0,
+            y1: trans_p1.1 - trans_mid.1,
+            x2: trans_p2.0 - trans_mid.0,
+            y2: trans_p2.1 - trans_mid.1,
             num_points: self.num_points,
             fill: PhantomData::<NoFill>,
         }
@@ -396,10 +975,25 @@ impl DynamicDrawable<3> for Ellipse<NoFill> {
 mod tests {
     mod line {
         use crate::drawing::{
+            AffineTransform,
             DynamicDrawable,
             Line,
         };
 
+        #[test]
+        fn pixels() {
+            let line = Line {
+                x1: 0.,
+                y1: 0.,
+                x2: 3.,
+                y2: 1.,
+            };
+            assert_eq!(
+                line.pixels().collect::<Vec<_>>(),
+                vec![(0, 0), (1, 0), (2, 1), (3, 1)]
+            );
+        }
+
         #[test]
         fn transform() {
             let expected = Line {
@@ -417,6 +1011,334 @@ mod tests {
             let transform = line.transform(|(x, y)| (x + 1., y));
             assert_eq!(expected, transform);
         }
+
+        #[test]
+        fn translate() {
+            let line = Line {
+                x1: 0.,
+                y1: 1.,
+                x2: 2.,
+                y2: 3.,
+            };
+            assert_eq!(
+                line.translate(1., 1.),
+                line.transform(|(x, y)| (x + 1., y + 1.))
+            );
+        }
+
+        #[test]
+        fn scale() {
+            let line = Line {
+                x1: 0.,
+                y1: 1.,
+                x2: 2.,
+                y2: 3.,
+            };
+            let expected = Line {
+                x1: -2.,
+                y1: 1.,
+                x2: 2.,
+                y2: 7.,
+            };
+            assert_eq!(line.scale(2., 3., (2., 1.)), expected);
+        }
+    }
+
+    mod sprite {
+        use crate::{
+            console_display::DynamicConsoleDisplay,
+            drawing::{
+                DynamicCanvas,
+                Sprite,
+            },
+            error::DisplayError,
+            pixel::monochrome_pixel::SinglePixel,
+            pixel_display::StaticPixelDisplay,
+        };
+
+        #[test]
+        fn capture_and_stamp() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+            display.set_pixel(0, 0, true).unwrap();
+            display.set_pixel(1, 1, true).unwrap();
+
+            let sprite = display.capture_sprite(0, 0, 2, 2).unwrap();
+            assert_eq!(sprite.data(), [true, false, false, true]);
+
+            sprite.stamp(&mut display, 2, 2);
+            assert_eq!(display.capture_sprite(2, 2, 2, 2), Ok(sprite));
+            assert_eq!(display.pixel(3, 2), Ok(false));
+            assert_eq!(display.pixel(2, 3), Ok(false));
+        }
+
+        #[test]
+        fn stamp_clips() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+            let sprite =
+                Sprite::<SinglePixel>::build(2, 2, vec![true; 4]).unwrap();
+            sprite.stamp(&mut display, 3, 3);
+            assert_eq!(display.pixels().iter().filter(|&&x| x).count(), 1);
+            assert_eq!(display.pixel(3, 3), Ok(true));
+        }
+
+        #[test]
+        fn capture_out_of_bounds() {
+            let display =
+                StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+            assert_eq!(
+                display.capture_sprite(3, 0, 2, 2),
+                Err(DisplayError::CoordinatesOutOfBounds(4, 4, 1, 4))
+            );
+        }
+    }
+
+    mod rounded_rectangle {
+        use std::marker::PhantomData;
+
+        use crate::{
+            drawing::{
+                DynamicCanvas,
+                DynamicDrawable,
+                Filled,
+                NoFill,
+                RoundedRectangle,
+            },
+            pixel::monochrome_pixel::SinglePixel,
+            pixel_display::StaticPixelDisplay,
+        };
+
+        #[test]
+        fn outline() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 7, 7>::new(false);
+            display.draw(
+                &RoundedRectangle {
+                    x1: 0.,
+                    y1: 0.,
+                    x2: 6.,
+                    y2: 6.,
+                    radius: 2.,
+                    fill: PhantomData::<NoFill>,
+                },
+                true,
+            );
+            for (x, y) in [(0, 0), (6, 0), (0, 6), (6, 6), (3, 3)] {
+                assert_eq!(display.pixel(x, y), Ok(false));
+            }
+            for i in 2..=4 {
+                assert_eq!(display.pixel(i, 0), Ok(true));
+                assert_eq!(display.pixel(i, 6), Ok(true));
+                assert_eq!(display.pixel(0, i), Ok(true));
+                assert_eq!(display.pixel(6, i), Ok(true));
+            }
+            assert_eq!(display.pixel(1, 1), Ok(true));
+        }
+
+        #[test]
+        fn filled() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 7, 7>::new(false);
+            display.draw(
+                &RoundedRectangle {
+                    x1: 0.,
+                    y1: 0.,
+                    x2: 6.,
+                    y2: 6.,
+                    radius: 2.,
+                    fill: PhantomData::<Filled>,
+                },
+                true,
+            );
+            for (x, y) in [(0, 0), (6, 0), (0, 6), (6, 6)] {
+                assert_eq!(display.pixel(x, y), Ok(false));
+            }
+            for (x, y) in [(3, 0), (0, 3), (3, 3), (6, 3), (3, 6)] {
+                assert_eq!(display.pixel(x, y), Ok(true));
+            }
+        }
+
+        #[test]
+        fn transform() {
+            let rectangle = RoundedRectangle {
+                x1: 0.,
+                y1: 0.,
+                x2: 4.,
+                y2: 2.,
+                radius: 1.,
+                fill: PhantomData::<NoFill>,
+            };
+            let expected = RoundedRectangle {
+                x1: 1.,
+                y1: 2.,
+                x2: 9.,
+                y2: 6.,
+                radius: 2.,
+                fill: PhantomData::<NoFill>,
+            };
+            let transform = rectangle.transform(|(x, y)| {
+                (2f32.mul_add(x, 1.), 2f32.mul_add(y, 2.))
+            });
+            assert_eq!(expected, transform);
+        }
+    }
+
+    mod point {
+        use core::f32;
+
+        use crate::{
+            drawing::{
+                AffineTransform,
+                DynamicCanvas,
+                Point,
+            },
+            pixel::monochrome_pixel::SinglePixel,
+            pixel_display::StaticPixelDisplay,
+            widget::single_widget::{
+                SingleWidget,
+                UvWidget,
+            },
+        };
+
+        #[test]
+        fn rotate() {
+            let point = Point {
+                x: 1.,
+                y: 0.,
+                radius: 2.,
+            };
+            let rotated = point.rotate(f32::consts::FRAC_PI_2, (0., 0.));
+            assert!(rotated.x.abs() < 1e-6);
+            assert!((rotated.y - 1.).abs() < 1e-6);
+            assert!((rotated.radius - 2.).abs() < f32::EPSILON);
+
+            let rotated = point.rotate(f32::consts::PI, (2., 0.));
+            assert!((rotated.x - 3.).abs() < 1e-6);
+            assert!(rotated.y.abs() < 1e-6);
+        }
+
+        #[test]
+        fn radius() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 5, 5>::new(false);
+            display.draw(
+                &Point {
+                    x: 2.,
+                    y: 2.,
+                    radius: 1.,
+                },
+                true,
+            );
+            for y in 0..5usize {
+                for x in 0..5usize {
+                    let expected = x.abs_diff(2) + y.abs_diff(2) <= 1;
+                    assert_eq!(display.pixel(x, y), Ok(expected));
+                }
+            }
+        }
+
+        #[test]
+        fn uv_mapped() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 4, 4>::new(false),
+                );
+            widget.set_uv_x_min(-1.);
+            widget.set_uv_x_max(1.);
+            widget.set_uv_y_min(-1.);
+            widget.set_uv_y_max(1.);
+            widget.draw(
+                &Point {
+                    x: 0.6,
+                    y: -0.6,
+                    radius: 0.,
+                },
+                true,
+            );
+            let (texture_x, texture_y) = (
+                widget.uv_x_to_texture(0.6),
+                widget.uv_y_to_texture(-0.6),
+            );
+            assert_eq!((texture_x, texture_y), (3, 0));
+            for y in 0..4 {
+                for x in 0..4 {
+                    assert_eq!(
+                        widget.child().pixel(x, y),
+                        Ok(x == texture_x && y == texture_y)
+                    );
+                }
+            }
+        }
+    }
+
+    mod antialiased_line {
+        use crate::{
+            color::{
+                RGBColor,
+                TerminalColor,
+            },
+            console_display::DynamicConsoleDisplay,
+            drawing::{
+                AntialiasedLine,
+                DynamicCanvas,
+                Line,
+            },
+            pixel::color_pixel::ColorSinglePixel,
+            pixel_display::StaticPixelDisplay,
+        };
+
+        #[test]
+        fn horizontal_matches_line() {
+            let mut expected =
+                StaticPixelDisplay::<ColorSinglePixel, 6, 3>::new(
+                    RGBColor::BLACK.into(),
+                );
+            let mut actual =
+                StaticPixelDisplay::<ColorSinglePixel, 6, 3>::new(
+                    RGBColor::BLACK.into(),
+                );
+            expected.draw(
+                &Line {
+                    x1: 0.,
+                    y1: 1.,
+                    x2: 5.,
+                    y2: 1.,
+                },
+                RGBColor::WHITE.into(),
+            );
+            AntialiasedLine {
+                x1: 0.,
+                y1: 1.,
+                x2: 5.,
+                y2: 1.,
+            }
+            .draw(&mut actual, RGBColor::WHITE.into());
+            assert_eq!(expected.pixels(), actual.pixels());
+        }
+
+        #[test]
+        fn partial_coverage() {
+            let mut display =
+                StaticPixelDisplay::<ColorSinglePixel, 3, 3>::new(
+                    RGBColor::BLACK.into(),
+                );
+            AntialiasedLine {
+                x1: 0.,
+                y1: 0.5,
+                x2: 2.,
+                y2: 0.5,
+            }
+            .draw(&mut display, RGBColor::WHITE.into());
+            let pixel = display.pixel(1, 0).unwrap();
+            assert_ne!(pixel, RGBColor::WHITE.into());
+            assert_ne!(pixel, RGBColor::BLACK.into());
+            assert_eq!(pixel, display.pixel(1, 1).unwrap());
+            assert_eq!(
+                display.pixel(1, 2).unwrap(),
+                TerminalColor::from(RGBColor::BLACK)
+            );
+        }
     }
 
     mod rectagle {
@@ -455,24 +1377,14 @@ mod tests {
         use std::marker::PhantomData;
 
         use crate::drawing::{
+            AffineTransform,
             DynamicDrawable,
             Ellipse,
             NoFill,
         };
 
-        #[test]
-        fn transform() {
-            let expected = Ellipse {
-                x1: -8.,
-                y1: -20.,
-                x2: 12.,
-                y2: 0.,
-                midpoint_x: 1.,
-                midpoint_y: -9.,
-                num_points: 10,
-                fill: PhantomData::<NoFill>,
-            };
-            let ellipse = Ellipse {
+        fn ellipse() -> Ellipse<NoFill> {
+            Ellipse {
                 x1: -10.,
                 y1: -10.,
                 x2: 10.,
@@ -481,9 +1393,47 @@ mod tests {
                 midpoint_y: 1.,
                 num_points: 10,
                 fill: PhantomData::<NoFill>,
+            }
+        }
+
+        #[test]
+        fn transform() {
+            let expected = Ellipse {
+                midpoint_x: 1.,
+                midpoint_y: -9.,
+                ..ellipse()
             };
-            let transform = ellipse.transform(|(x, y)| (x + 2., y - 10.));
+            let transform =
+                ellipse().transform(|(x, y)| (x + 2., y - 10.));
             assert_eq!(expected, transform);
         }
+
+        #[test]
+        fn translate_keeps_radii() {
+            let translated = ellipse().translate(5., -3.);
+            assert_eq!(
+                (translated.midpoint_x, translated.midpoint_y),
+                (4., -2.)
+            );
+            assert_eq!((translated.x1, translated.y1), (-10., -10.));
+            assert_eq!((translated.x2, translated.y2), (10., 10.));
+        }
+
+        #[test]
+        fn scale_and_rotate_axes() {
+            let scaled = ellipse().scale(2., 0.5, (-1., 1.));
+            assert_eq!((scaled.midpoint_x, scaled.midpoint_y), (-1., 1.));
+            assert_eq!((scaled.x1, scaled.y1), (-20., -5.));
+            assert_eq!((scaled.x2, scaled.y2), (20., 5.));
+
+            let rotated =
+                ellipse().rotate(std::f32::consts::FRAC_PI_2, (0., 0.));
+            assert!((rotated.midpoint_x + 1.).abs() < 1e-5);
+            assert!((rotated.midpoint_y + 1.).abs() < 1e-5);
+            assert!((rotated.x1 - 10.).abs() < 1e-5);
+            assert!((rotated.y1 + 10.).abs() < 1e-5);
+            assert!((rotated.x2 + 10.).abs() < 1e-5);
+            assert!((rotated.y2 - 10.).abs() < 1e-5);
+        }
     }
 }
@@ -22,6 +22,14 @@ pub(crate) const COULD_NOT_CAST_X_COORD: &str =
     "Could not cast x coordinate.";
 pub(crate) const COULD_NOT_CAST_Y_COORD: &str =
     "Could not cast y coordinate.";
+#[cfg(feature = "image")]
+pub(crate) const IMAGE_TOO_LARGE: &str =
+    "Size of the image does not fit into a u32.";
+pub(crate) const WRITING_TO_STRING_FAILED: &str =
+    konst::string::str_concat!(&[
+        INVARIANT_VIOLATED,
+        " Writing to a string failed."
+    ]);
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum PixelError {
@@ -82,12 +90,59 @@ pub enum WidgetError {
         "Height in characters of arguments does not match. Got {0} and {1}"
     )]
     HeightMismatch(usize, usize),
+    #[error(
+        "Number of children must be a multiple of the column count. Got {0} children and {1} columns"
+    )]
+    ChildCountNotMultipleOfColumns(usize, usize),
+    #[error(
+        "Character spans multiple columns and cannot be rotated. At '{0}'"
+    )]
+    WideCharacter(char),
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum DrawingError {
+    #[error("Pixel error: {0}")]
+    PixelError(#[from] PixelError),
     #[error("Display error: {0}")]
     DisplayError(#[from] DisplayError),
     #[error("Widget error: {0}")]
     WidgetError(#[from] WidgetError),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    fn pixel() -> Result<(), PixelError> {
+        Err(PixelError::ControlCharacter('\n'))
+    }
+
+    fn drawing() -> Result<(), DrawingError> {
+        pixel()?;
+        Ok(())
+    }
+
+    #[test]
+    fn propagate_pixel_error_to_drawing_error() {
+        assert_eq!(
+            drawing(),
+            Err(DrawingError::PixelError(PixelError::ControlCharacter(
+                '\n'
+            )))
+        );
+    }
+
+    #[test]
+    fn drawing_error_source_is_pixel_error() {
+        let error =
+            drawing().expect_err("should propagate the pixel error");
+        let source = error
+            .source()
+            .and_then(|source| source.downcast_ref::<PixelError>());
+        assert_eq!(source, Some(&PixelError::ControlCharacter('\n')));
+        assert!(source.and_then(Error::source).is_none());
+    }
+}
@@ -2,7 +2,6 @@
 #![feature(generic_const_exprs)]
 #![feature(const_trait_impl)]
 #![feature(const_convert)]
-#![feature(const_ref_cell)]
 #![feature(adt_const_params)]
 #![feature(lazy_type_alias)]
 #![feature(specialization)]
@@ -16,8 +15,10 @@ pub mod drawing;
 pub mod error;
 mod macros;
 pub mod optional_const_generics;
+pub mod particle;
 pub mod pixel;
 mod public_api;
+pub mod style;
 pub mod widget;
 
 pub use display::*;
@@ -156,6 +156,59 @@ macro_rules! impl_new {
     };
 }
 
+/// Implements `HitTest` and `Describe` for a widget with a single child in the field `child`.
+/// The generic parameters of the implementations are given in brackets.
+///
+/// By default, the child covers the whole widget.
+/// With `offset`, the child keeps its size and its top left corner is placed
+/// at the position returned by the given closure taking the widget.
+/// Widgets mapping cells to their child differently implement `HitTest` themselves
+/// and only implement `Describe` with `describe`.
+#[macro_export]
+macro_rules! impl_single_child {
+    (describe [$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> $crate::widget::Describe for $type {
+            fn describe(&self) -> String {
+                $crate::widget::describe_node(
+                    self,
+                    &[$crate::widget::Describe::describe(&self.child)],
+                )
+            }
+        }
+    };
+    ([$($generics:tt)*] $type:ty, offset: $offset:expr) => {
+        impl<$($generics)*> $crate::widget::HitTest for $type {
+            fn child_regions(&self) -> Vec<$crate::widget::Region> {
+                let (left, top) = ($offset)(self);
+                vec![$crate::widget::Region::new(
+                    left,
+                    top,
+                    $crate::widget::DynamicWidget::width_characters(&self.child),
+                    $crate::widget::DynamicWidget::height_characters(&self.child),
+                )]
+            }
+
+            fn widget_at(
+                &self,
+                column: usize,
+                row: usize,
+            ) -> Option<$crate::widget::WidgetId> {
+                $crate::widget::resolve_hit(self, column, row, |_, x, y| {
+                    $crate::widget::HitTest::widget_at(&self.child, x, y)
+                })
+            }
+        }
+
+        $crate::impl_single_child!(describe [$($generics)*] $type);
+    };
+    ([$($generics:tt)*] $type:ty) => {
+        $crate::impl_single_child!(
+            [$($generics)*] $type,
+            offset: |_: &Self| (0, 0)
+        );
+    };
+}
+
 /// Constrains a constant generic parameter with the given constraint.
 /// Should be used in conjunction with the `or` and `and` macros.
 ///
@@ -207,6 +260,115 @@ macro_rules! and {
     }
 }
 
+/// Sets several pixels of a static display at once.
+/// Expands to one `StaticConsoleDisplay::set_pixel_static` call per pixel,
+/// so all coordinates are checked at compile time.
+///
+/// # Examples
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     console_display::StaticConsoleDisplay,
+///     pixel::monochrome_pixel::QuadPixel,
+///     pixel_display::StaticPixelDisplay,
+///     set_pixels_static,
+/// };
+///
+/// let mut disp = StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+///
+/// set_pixels_static!(disp, (0, 0) => true, (3, 3) => true);
+///
+/// assert!(disp.pixel_static::<3, 3>());
+/// ```
+///
+/// Coordinates outside of the display do not compile.
+///
+/// ```compile_fail
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     console_display::StaticConsoleDisplay,
+///     pixel::monochrome_pixel::QuadPixel,
+///     pixel_display::StaticPixelDisplay,
+///     set_pixels_static,
+/// };
+///
+/// let mut disp = StaticPixelDisplay::<QuadPixel, 4, 4>::new(false);
+///
+/// set_pixels_static!(disp, (0, 0) => true, (4, 3) => true);
+/// ```
+#[macro_export]
+macro_rules! set_pixels_static {
+    ($display:expr, $( ($x:expr, $y:expr) => $value:expr ),* $(,)?) => {
+        $( $display.set_pixel_static::<{ $x }, { $y }>($value); )*
+    };
+}
+
+/// Constructs a pixel display of the given pixel type.
+///
+/// Supported syntaxes:
+/// - `display!(Pixel, WIDTH x HEIGHT, fill = value)` creates a `StaticPixelDisplay`.
+///   The dimensions must be literals or blocks, as they are passed as const generics.
+/// - `display!(Pixel, width, height, fill = value)` creates a `DynamicPixelDisplay`.
+///
+/// The fill may be omitted, in which case the default value of the pixel data is used.
+///
+/// # Examples
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     color::RGBColor,
+///     console_display::DynamicConsoleDisplay,
+///     display,
+///     pixel::{
+///         color_pixel::ColorOctPixel,
+///         monochrome_pixel::SinglePixel,
+///     },
+/// };
+///
+/// let disp = display!(ColorOctPixel, 200 x 100, fill = RGBColor::BLACK.into());
+/// assert_eq!((disp.width(), disp.height()), (200, 100));
+///
+/// let (width, height) = (4, 3);
+/// let disp = display!(SinglePixel, width, height);
+/// assert_eq!(disp.pixels(), vec![false; 12]);
+/// ```
+#[macro_export]
+macro_rules! display {
+    ($pixel:ty, $width:tt x $height:tt $(,)?) => {
+        $crate::display!(
+            $pixel,
+            $width x $height,
+            fill = ::core::default::Default::default()
+        )
+    };
+    ($pixel:ty, $width:tt x $height:tt, fill = $fill:expr $(,)?) => {
+        $crate::pixel_display::StaticPixelDisplay::<
+            $pixel,
+            $width,
+            $height,
+        >::new($fill)
+    };
+    ($pixel:ty, $width:expr, $height:expr $(,)?) => {
+        $crate::display!(
+            $pixel,
+            $width,
+            $height,
+            fill = ::core::default::Default::default()
+        )
+    };
+    ($pixel:ty, $width:expr, $height:expr, fill = $fill:expr $(,)?) => {
+        $crate::pixel_display::DynamicPixelDisplay::<$pixel>::new(
+            $width, $height, $fill,
+        )
+    };
+}
+
 #[macro_export]
 macro_rules! for_all {
     ( for $v:ident in [ $( $vals:ident ),* ] => $expr:expr ) => {
@@ -230,6 +392,8 @@ macro_rules! impl_from_mono_chrome_pixel_for_datacell {
                     character: val.character(),
                     foreground: TerminalColor::Default,
                     background: TerminalColor::Default,
+                    style: $crate::style::TextStyle::NONE,
+                    link: None,
                 }
             }
         }
@@ -242,7 +406,7 @@ macro_rules! impl_from_color_pixel_for_datacell {
         impl From<$type> for DataCell {
             fn from(val: $type) -> Self {
                 let colors = val.pixels();
-                let grouping = TerminalColor::group(&colors);
+                let grouping = TerminalColor::group_best(&colors);
                 let symb = <$base>::new(grouping).character();
 
                 let mut col1 = vec![];
@@ -262,6 +426,8 @@ macro_rules! impl_from_color_pixel_for_datacell {
                     character: symb,
                     foreground: col1,
                     background: col2,
+                    style: $crate::style::TextStyle::NONE,
+                    link: None,
                 }
             }
         }
@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use crate::{
+    drawing::{
+        DynamicCanvas,
+        Point,
+    },
+    impl_new,
+    impl_setters,
+    pixel::Pixel,
+};
+
+/// Defines a single particle of a `ParticleSystem`.
+/// Positions are in canvas coordinates and velocities in canvas units per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Particle<U: Copy> {
+    pub x: f32,
+    pub y: f32,
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    pub value: U,
+    pub lifetime: Duration,
+}
+
+impl<U: Copy> Particle<U> {
+    impl_new!(
+        pub Particle<U>,
+        x: f32,
+        y: f32,
+        velocity_x: f32,
+        velocity_y: f32,
+        value: U,
+        lifetime: Duration
+    );
+}
+
+/// Manages a pool of particles that move with a constant acceleration and
+/// disappear once their lifetime has expired.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use std::time::Duration;
+///
+/// use console_display::{
+///     drawing::DynamicCanvas,
+///     particle::{
+///         Particle,
+///         ParticleSystem,
+///     },
+///     pixel::monochrome_pixel::SinglePixel,
+///     pixel_display::StaticPixelDisplay,
+/// };
+///
+/// let mut disp = StaticPixelDisplay::<SinglePixel, 8, 8>::new(false);
+/// let mut particles = ParticleSystem::new();
+///
+/// particles.spawn(Particle::new(
+///     0.,
+///     0.,
+///     2.,
+///     1.,
+///     true,
+///     Duration::from_secs(2),
+/// ));
+/// particles.update(Duration::from_secs(1));
+/// particles.render(&mut disp);
+///
+/// assert_eq!(disp.pixel(2, 1), Ok(true));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParticleSystem<U: Copy> {
+    particles: Vec<Particle<U>>,
+    acceleration_x: f32,
+    acceleration_y: f32,
+}
+
+impl<U: Copy> ParticleSystem<U> {
+    impl_setters!(
+        pub acceleration_x: f32,
+        pub acceleration_y: f32
+    );
+
+    /// Creates an empty particle system without acceleration.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            particles: Vec::new(),
+            acceleration_x: 0.,
+            acceleration_y: 0.,
+        }
+    }
+
+    /// Returns the horizontal acceleration applied to all particles.
+    #[must_use]
+    pub const fn acceleration_x(&self) -> f32 {
+        self.acceleration_x
+    }
+
+    /// Returns the vertical acceleration applied to all particles.
+    #[must_use]
+    pub const fn acceleration_y(&self) -> f32 {
+        self.acceleration_y
+    }
+
+    /// Returns the particles that are currently alive.
+    #[must_use]
+    pub fn particles(&self) -> &[Particle<U>] {
+        &self.particles
+    }
+
+    /// Adds a particle to the system.
+    /// Particles with a zero lifetime are removed on the next update.
+    pub fn spawn(&mut self, particle: Particle<U>) {
+        self.particles.push(particle);
+    }
+
+    /// Removes all particles from the system.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    /// Advances all particles by the time step `dt`.
+    /// Velocities are updated first, then positions, and particles whose
+    /// lifetime has run out are removed.
+    pub fn update(&mut self, dt: Duration) {
+        let seconds = dt.as_secs_f32();
+        for particle in &mut self.particles {
+            particle.velocity_x =
+                self.acceleration_x.mul_add(seconds, particle.velocity_x);
+            particle.velocity_y =
+                self.acceleration_y.mul_add(seconds, particle.velocity_y);
+            particle.x = particle.velocity_x.mul_add(seconds, particle.x);
+            particle.y = particle.velocity_y.mul_add(seconds, particle.y);
+            particle.lifetime = particle.lifetime.saturating_sub(dt);
+        }
+        self.particles
+            .retain(|particle| !particle.lifetime.is_zero());
+    }
+
+    /// Plots every particle at its rounded position onto the canvas.
+    /// Particles outside of the canvas are skipped.
+    pub fn render<T: DynamicCanvas<S>, S: Pixel<U = U>>(
+        &self,
+        canvas: &mut T,
+    ) where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        for particle in &self.particles {
+            canvas.draw(
+                &Point {
+                    x: particle.x,
+                    y: particle.y,
+                    radius: 0.,
+                },
+                particle.value,
+            );
+        }
+    }
+}
+
+impl<U: Copy> Default for ParticleSystem<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod particle_system {
+        use std::time::Duration;
+
+        use crate::{
+            drawing::DynamicCanvas,
+            particle::{
+                Particle,
+                ParticleSystem,
+            },
+            pixel::monochrome_pixel::SinglePixel,
+            pixel_display::StaticPixelDisplay,
+        };
+
+        #[test]
+        fn update_moves_particle() {
+            let mut system = ParticleSystem::new();
+            system.spawn(Particle::new(
+                1.,
+                2.,
+                3.,
+                -1.,
+                true,
+                Duration::from_secs(1),
+            ));
+            system.update(Duration::from_millis(500));
+            let particle = system.particles()[0];
+            assert!((particle.x - 2.5).abs() < f32::EPSILON);
+            assert!((particle.y - 1.5).abs() < f32::EPSILON);
+            assert_eq!(particle.lifetime, Duration::from_millis(500));
+        }
+
+        #[test]
+        fn update_removes_expired() {
+            let mut system = ParticleSystem::new();
+            system.spawn(Particle::new(
+                0.,
+                0.,
+                0.,
+                0.,
+                true,
+                Duration::from_millis(300),
+            ));
+            system.update(Duration::from_millis(200));
+            assert_eq!(system.particles().len(), 1);
+            system.update(Duration::from_millis(200));
+            assert!(system.particles().is_empty());
+        }
+
+        #[test]
+        fn acceleration() {
+            let mut system = ParticleSystem::new();
+            system.set_acceleration_y(2.);
+            system.spawn(Particle::new(
+                0.,
+                0.,
+                0.,
+                0.,
+                true,
+                Duration::from_secs(5),
+            ));
+            system.update(Duration::from_secs(1));
+            let particle = system.particles()[0];
+            assert!((particle.velocity_y - 2.).abs() < f32::EPSILON);
+            assert!((particle.y - 2.).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn render_clips() {
+            let mut display =
+                StaticPixelDisplay::<SinglePixel, 4, 4>::new(false);
+            let mut system = ParticleSystem::new();
+            for (x, y) in [(1., 1.), (-3., 0.), (10., 2.)] {
+                system.spawn(Particle::new(
+                    x,
+                    y,
+                    0.,
+                    0.,
+                    true,
+                    Duration::from_secs(1),
+                ));
+            }
+            system.render(&mut display);
+            for y in 0..4 {
+                for x in 0..4 {
+                    assert_eq!(display.pixel(x, y), Ok(x == 1 && y == 1));
+                }
+            }
+        }
+    }
+}
@@ -33,7 +33,7 @@ where
     where
         [(); Self::WIDTH * Self::HEIGHT]:,
     {
-        if x < Self::WIDTH || y < Self::HEIGHT {
+        if x < Self::WIDTH && y < Self::HEIGHT {
             Ok(self.pixels()[x + y * Self::WIDTH])
         }
         else {
@@ -60,7 +60,7 @@ where
     where
         [(); Self::WIDTH * Self::HEIGHT]:,
     {
-        if x < Self::WIDTH || y < Self::HEIGHT {
+        if x < Self::WIDTH && y < Self::HEIGHT {
             self.pixels_mut()[x + y * Self::WIDTH] = value;
             Ok(())
         }
@@ -11,6 +11,7 @@ use crate::{
     },
     or,
     pixel::Pixel,
+    style::TextStyle,
     widget::DataCell,
 };
 
@@ -30,6 +31,7 @@ pub struct CharacterPixelData {
     character: char,
     foreground: TerminalColor,
     background: TerminalColor,
+    style: TextStyle,
     copy: bool,
     width: usize,
 }
@@ -68,6 +70,8 @@ impl const From<CharacterPixel> for DataCell {
             character: val.character(),
             foreground: val.foreground(),
             background: val.background(),
+            style: val.style(),
+            link: None,
         }
     }
 }
@@ -94,6 +98,7 @@ impl CharacterPixel {
                 character: CHARACTER,
                 foreground,
                 background,
+                style: TextStyle::NONE,
                 copy: false,
                 width: UnicodeWidthChar::width(CHARACTER)
                     .expect(FOUND_CONTROL_CHAR),
@@ -101,6 +106,28 @@ impl CharacterPixel {
         }
     }
 
+    /// Constructs a character pixel from a char, a foreground and a background color and a text style.
+    ///
+    /// # Panics
+    ///
+    /// This function checks for control characters at compile time.
+    /// Panics when the compile time checks miss a control character.
+    /// This should not happen and is a implementation detail that is subject to change.
+    #[must_use]
+    pub fn new_styled<const CHARACTER: char>(
+        foreground: TerminalColor,
+        background: TerminalColor,
+        style: TextStyle,
+    ) -> Self
+    where
+        constraint!(CHARACTER >= '\u{20}'):, // Exclude C0 control chars
+        constraint!(or!(CHARACTER < '\u{7F}', CHARACTER >= '\u{A0}')):, // Exclude C1 control chars
+    {
+        let mut pixel = Self::new::<CHARACTER>(foreground, background);
+        pixel.data[0].style = style;
+        pixel
+    }
+
     /// Constructs a character pixel from a char, a foreground and a background color.
     ///
     /// # Errors
@@ -116,6 +143,7 @@ impl CharacterPixel {
                 character,
                 foreground,
                 background,
+                style: TextStyle::NONE,
                 copy: false,
                 width: match UnicodeWidthChar::width(character) {
                     Some(val) => val,
@@ -129,6 +157,22 @@ impl CharacterPixel {
         })
     }
 
+    /// Constructs a character pixel from a char, a foreground and a background color and a text style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the character is a control character.
+    pub fn build_styled(
+        character: char,
+        foreground: TerminalColor,
+        background: TerminalColor,
+        style: TextStyle,
+    ) -> Result<Self, PixelError> {
+        let mut pixel = Self::build(character, foreground, background)?;
+        pixel.data[0].style = style;
+        Ok(pixel)
+    }
+
     #[must_use]
     pub const fn make_copy(&self) -> Self {
         let mut clone = *self;
@@ -151,6 +195,11 @@ impl CharacterPixel {
         self.data[0].background
     }
 
+    #[must_use]
+    pub const fn style(&self) -> TextStyle {
+        self.data[0].style
+    }
+
     #[must_use]
     pub const fn is_copy(&self) -> bool {
         self.data[0].copy
@@ -205,6 +254,7 @@ impl const Default for CharacterPixelData {
             character: ' ',
             foreground: TerminalColor::default(),
             background: TerminalColor::default(),
+            style: TextStyle::NONE,
             copy: false,
             width: 1,
         }
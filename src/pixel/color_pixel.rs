@@ -10,11 +10,13 @@ use crate::{
     pixel::{
         Pixel,
         monochrome_pixel::{
+            BrailleOctPixel,
             HexPixel,
             OctPixel,
             QuadPixel,
         },
     },
+    style::TextStyle,
     widget::DataCell,
 };
 
@@ -44,6 +46,8 @@ impl const From<ColorSinglePixel> for DataCell {
             character: '█',
             foreground: val.pixels[0],
             background: val.pixels[0],
+            style: TextStyle::NONE,
+            link: None,
         }
     }
 }
@@ -73,6 +77,8 @@ impl const From<ColorDualPixel> for DataCell {
             character: '▀',
             foreground: val.pixels[0],
             background: val.pixels[1],
+            style: TextStyle::NONE,
+            link: None,
         }
     }
 }
@@ -139,3 +145,131 @@ impl Pixel for ColorOctPixel {
 }
 
 impl_from_color_pixel_for_datacell!(ColorOctPixel, OctPixel);
+
+/// Specifies a block of colored pixels with dimensions 2 (width) by 4 (height) displayed as braille dots.
+///
+/// The pixels are split into two groups of similar colors.
+/// The smaller group is drawn as dots in its mixed color,
+/// while the other group determines the background color.
+#[derive(Clone, Copy)]
+pub struct ColorBraillePixel {
+    pixels: [TerminalColor; 8],
+}
+
+impl Pixel for ColorBraillePixel {
+    type U = TerminalColor;
+
+    const WIDTH: usize = 2;
+
+    const HEIGHT: usize = 4;
+
+    impl_getters!(pixels: [Self::U; Self::WIDTH * Self::HEIGHT]);
+
+    impl_getters_mut!(pixels: [Self::U; Self::WIDTH * Self::HEIGHT]);
+
+    impl_new!(Self, pixels: [Self::U; 8]);
+}
+
+impl From<ColorBraillePixel> for DataCell {
+    fn from(val: ColorBraillePixel) -> Self {
+        let colors = val.pixels();
+        let mut grouping = TerminalColor::group_best(colors);
+        // Braille dots only cover a small part of the cell,
+        // so the larger group is used as the background.
+        if grouping.iter().filter(|&&dot| dot).count() > grouping.len() / 2
+        {
+            grouping = grouping.map(|dot| !dot);
+        }
+        let symb = BrailleOctPixel::new(grouping).character();
+
+        let mut col1 = vec![];
+        let mut col2 = vec![];
+        for (color, dot) in colors.iter().zip(grouping) {
+            if dot {
+                col1.push(*color);
+            }
+            else {
+                col2.push(*color);
+            }
+        }
+
+        Self {
+            character: symb,
+            foreground: TerminalColor::mix(&col1),
+            background: TerminalColor::mix(&col2),
+            style: TextStyle::NONE,
+            link: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod color_quad_pixel {
+        use crate::{
+            color::{
+                Color,
+                RGBColor,
+                TerminalColor,
+            },
+            pixel::{
+                Pixel,
+                color_pixel::ColorQuadPixel,
+            },
+            widget::DataCell,
+        };
+
+        #[test]
+        fn three_colors() {
+            let pixels = [
+                RGBColor::BLACK.into(),
+                RGBColor::BLACK.into(),
+                RGBColor::GRAY.into(),
+                RGBColor::RED.into(),
+            ];
+            let best = TerminalColor::group_best(&pixels);
+            assert!(
+                TerminalColor::grouping_error(&pixels, &best) <
+                    TerminalColor::grouping_error(
+                        &pixels,
+                        &TerminalColor::group(&pixels)
+                    )
+            );
+            let cell: DataCell = ColorQuadPixel::new(pixels).into();
+            assert_eq!(cell.character, '▀');
+            assert_eq!(cell.foreground, RGBColor::BLACK.into());
+        }
+    }
+
+    mod color_braille_pixel {
+        use crate::{
+            color::{
+                RGBColor,
+                TerminalColor,
+            },
+            pixel::{
+                Pixel,
+                color_pixel::ColorBraillePixel,
+            },
+            widget::DataCell,
+        };
+
+        #[test]
+        fn single_dot() {
+            let mut pixels = [TerminalColor::from(RGBColor::BLACK); 8];
+            pixels[3] = RGBColor::RED.into();
+            let cell: DataCell = ColorBraillePixel::new(pixels).into();
+            assert_eq!(cell.character, '⠐');
+            assert_eq!(cell.foreground, RGBColor::RED.into());
+            assert_eq!(cell.background, RGBColor::BLACK.into());
+        }
+
+        #[test]
+        fn uniform() {
+            let pixels = [TerminalColor::from(RGBColor::BLUE); 8];
+            let cell: DataCell = ColorBraillePixel::new(pixels).into();
+            assert_eq!(cell.character, '⠀');
+            assert_eq!(cell.background, RGBColor::BLUE.into());
+        }
+    }
+}
@@ -68,6 +68,65 @@ impl Pixel for SinglePixel {
 
 impl_from_mono_chrome_pixel_for_datacell!(SinglePixel);
 
+/// Represents a singular pixel with an intensity from 0 to 255,
+/// which is displayed using the shade characters ` ░▒▓█`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ShadePixel {
+    pixels: [u8; 1],
+}
+
+// Needed because rustfmt panics when skipping elements with some
+// special characters directly.
+// https://github.com/rust-lang/rustfmt/issues/6523
+#[rustfmt::skip]
+impl ShadePixel {
+    #[rustfmt::skip]
+    const CHARS: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+    /// Maps the intensity to one of five equally sized buckets.
+    const fn index(self) -> usize {
+        self.pixels[0] as usize * Self::CHARS.len() / (u8::MAX as usize + 1)
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use console_display::pixel::{
+    ///     Pixel,
+    ///     monochrome_pixel::ShadePixel
+    /// };
+    ///
+    /// let pixel = ShadePixel::new([128]);
+    ///
+    /// let symbol = pixel.character();
+    ///
+    /// assert_eq!(symbol, '▒');
+    /// ```
+    #[must_use]
+    pub const fn character(self) -> char {
+        Self::CHARS[self.index()]
+    }
+}
+
+impl Pixel for ShadePixel {
+    type U = u8;
+
+    const WIDTH: usize = 1;
+
+    const HEIGHT: usize = 1;
+
+    impl_getters!(pixels: [u8; 1]);
+
+    impl_getters_mut!(pixels: [u8; 1]);
+
+    impl_new!(ShadePixel, pixels: [u8; 1]);
+}
+
+impl_from_mono_chrome_pixel_for_datacell!(ShadePixel);
+
 /// Specifies a block of pixels with dimensions 1 (width) by 2 (height).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub struct DualPixel {
@@ -453,6 +512,126 @@ impl Pixel for BrailleOctPixel {
 
 impl_from_mono_chrome_pixel_for_datacell!(BrailleOctPixel);
 
+/// Specifies a block of pixels with dimensions W (width) by H (height),
+/// whose characters are taken from a user provided table.
+///
+/// The character at index `i` of the table is chosen, if the subpixels whose bits are set in `i` are filled.
+/// Subpixel `x + y * W` corresponds to bit `x + y * W`, like for the predefined pixel types.
+/// This allows falling back to other characters, e.g. ASCII approximations,
+/// on terminals or fonts lacking the block characters of the predefined pixel types.
+///
+/// Pixels created through [`Pixel::new`], e.g. by displays, use an ASCII table based on the number of filled subpixels.
+/// Displays switch all their pixels to another table through `PixelDisplay::with_chars`.
+/// `Pixel` is implemented for the dimensions of the predefined pixel types,
+/// i.e. 1x1, 1x2, 2x2, 2x3 and 2x4.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::pixel::monochrome_pixel::CustomPixel;
+///
+/// const CHARS: [char; 4] = [' ', '\'', '.', ':'];
+///
+/// let pixel = CustomPixel::<1, 2>::with_chars([false, true], &CHARS);
+///
+/// assert_eq!(pixel.character(), '.');
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomPixel<const W: usize, const H: usize>
+where
+    [(); W * H]:,
+    [(); 1 << (W * H)]:,
+{
+    pixels: [bool; W * H],
+    chars: &'static [char; 1 << (W * H)],
+}
+
+impl<const W: usize, const H: usize> CustomPixel<W, H>
+where
+    [(); W * H]:,
+    [(); 1 << (W * H)]:,
+{
+    /// The table used by `Pixel::new`.
+    /// Maps the share of filled subpixels to ` .:+#`.
+    pub const DEFAULT_CHARS: [char; 1 << (W * H)] = {
+        const SHADES: [char; 5] = [' ', '.', ':', '+', '#'];
+        let mut chars = [' '; 1 << (W * H)];
+        konst::for_range! { i in 0..chars.len() =>
+            chars[i] = SHADES[
+                i.count_ones() as usize * (SHADES.len() - 1) / (W * H)
+            ];
+        }
+        chars
+    };
+
+    /// Creates a block of pixels displayed using the provided table.
+    #[must_use]
+    pub const fn with_chars(
+        pixels: [bool; W * H],
+        chars: &'static [char; 1 << (W * H)],
+    ) -> Self {
+        Self { pixels, chars }
+    }
+
+    /// Returns the table the characters are chosen from.
+    #[must_use]
+    pub const fn chars(&self) -> &'static [char; 1 << (W * H)] {
+        self.chars
+    }
+
+    /// Displays the pixels using the provided table.
+    pub const fn set_chars(
+        &mut self,
+        chars: &'static [char; 1 << (W * H)],
+    ) {
+        self.chars = chars;
+    }
+
+    const fn index(self) -> usize {
+        let mut index = 0;
+        konst::for_range! { i in 0..W * H =>
+            index |= (self.pixels[i] as usize) << i;
+        }
+        index
+    }
+
+    #[must_use]
+    pub const fn character(self) -> char {
+        self.chars[self.index()]
+    }
+}
+
+macro_rules! impl_pixel_for_custom_pixel {
+    ($($width:literal x $height:literal),*) => {
+        $(
+            impl Pixel for CustomPixel<$width, $height> {
+                type U = bool;
+
+                const WIDTH: usize = $width;
+
+                const HEIGHT: usize = $height;
+
+                impl_getters!(pixels: [bool; $width * $height]);
+
+                impl_getters_mut!(pixels: [bool; $width * $height]);
+
+                fn new(pixels: [bool; $width * $height]) -> Self {
+                    Self::with_chars(pixels, &Self::DEFAULT_CHARS)
+                }
+            }
+
+            impl_from_mono_chrome_pixel_for_datacell!(
+                CustomPixel<$width, $height>
+            );
+        )*
+    };
+}
+
+impl_pixel_for_custom_pixel!(1 x 1, 1 x 2, 2 x 2, 2 x 3, 2 x 4);
+
 #[cfg(test)]
 mod tests {
     mod single_pixel {
@@ -467,6 +646,21 @@ mod tests {
         }
     }
 
+    mod shade_pixel {
+        use crate::pixel::{
+            Pixel,
+            monochrome_pixel::ShadePixel,
+        };
+
+        #[test]
+        fn character() {
+            assert_eq!(' ', ShadePixel::new([0]).character());
+            assert_eq!('░', ShadePixel::new([64]).character());
+            assert_eq!('▓', ShadePixel::new([200]).character());
+            assert_eq!('█', ShadePixel::new([255]).character());
+        }
+    }
+
     mod dual_pixel {
         use crate::pixel::{
             Pixel,
@@ -480,9 +674,12 @@ mod tests {
     }
 
     mod quad_pixel {
-        use crate::pixel::{
-            Pixel,
-            monochrome_pixel::QuadPixel,
+        use crate::{
+            error::PixelError,
+            pixel::{
+                Pixel,
+                monochrome_pixel::QuadPixel,
+            },
         };
 
         #[test]
@@ -492,6 +689,30 @@ mod tests {
                 QuadPixel::new([true, false, true, false]).character()
             );
         }
+
+        #[test]
+        fn subpixel_out_of_bounds() {
+            let pixel = QuadPixel::new([true, false, true, false]);
+            assert_eq!(pixel.subpixel(1, 1), Ok(false));
+            assert_eq!(
+                pixel.subpixel(2, 0),
+                Err(PixelError::CoordinatesOutOfBounds(2, 2, 0, 2))
+            );
+            assert_eq!(
+                pixel.subpixel(0, 2),
+                Err(PixelError::CoordinatesOutOfBounds(0, 2, 2, 2))
+            );
+        }
+
+        #[test]
+        fn set_subpixel_out_of_bounds() {
+            let mut pixel = QuadPixel::new([false; 4]);
+            assert_eq!(
+                pixel.set_subpixel(2, 0, true),
+                Err(PixelError::CoordinatesOutOfBounds(2, 2, 0, 2))
+            );
+            assert_eq!(pixel, QuadPixel::new([false; 4]));
+        }
     }
 
     mod hex_pixel {
@@ -545,4 +766,47 @@ mod tests {
             );
         }
     }
+
+    mod custom_pixel {
+        use crate::pixel::{
+            Pixel,
+            monochrome_pixel::CustomPixel,
+        };
+
+        #[rustfmt::skip]
+        const CHARS: [char; 16] = [
+            ' ', '\'', '\'', '"',
+            '.', '|', '/', 'P',
+            '.', '\\', '|', '9',
+            '_', 'L', 'J', '#',
+        ];
+
+        #[test]
+        fn character() {
+            let pixel = CustomPixel::<2, 2>::with_chars(
+                [false, true, true, false],
+                &CHARS,
+            );
+            assert_eq!('/', pixel.character());
+            let pixel = CustomPixel::<2, 2>::with_chars([true; 4], &CHARS);
+            assert_eq!('#', pixel.character());
+        }
+
+        #[test]
+        fn default_chars() {
+            assert_eq!(
+                ' ',
+                CustomPixel::<2, 2>::new([false; 4]).character()
+            );
+            assert_eq!(
+                ':',
+                CustomPixel::<2, 2>::new([true, false, false, true])
+                    .character()
+            );
+            assert_eq!(
+                '#',
+                CustomPixel::<2, 4>::new([true; 8]).character()
+            );
+        }
+    }
 }
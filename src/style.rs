@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    fmt::{
+        self,
+        Write,
+    },
+    ops::{
+        BitOr,
+        BitOrAssign,
+    },
+    sync::{
+        Arc,
+        LazyLock,
+        Mutex,
+        PoisonError,
+    },
+};
+
+use crate::error::WRITING_TO_STRING_FAILED;
+
+/// Defines text attributes supported by most terminals, like bold or underlined text.
+/// Attributes can be combined with `|`.
+///
+/// # Examples
+///
+/// ```
+/// use console_display::style::TextStyle;
+///
+/// let style = TextStyle::BOLD | TextStyle::UNDERLINE;
+///
+/// assert!(style.contains(TextStyle::BOLD));
+/// assert!(!style.contains(TextStyle::ITALIC));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive_const(Default)]
+pub struct TextStyle(u8);
+
+impl TextStyle {
+    pub const NONE: Self = Self(0);
+    pub const BOLD: Self = Self(1);
+    pub const ITALIC: Self = Self(1 << 1);
+    pub const UNDERLINE: Self = Self(1 << 2);
+    pub const STRIKETHROUGH: Self = Self(1 << 3);
+    pub const REVERSE: Self = Self(1 << 4);
+
+    const CODES: [(Self, &str); 5] = [
+        (Self::BOLD, "\x1b[1m"),
+        (Self::ITALIC, "\x1b[3m"),
+        (Self::UNDERLINE, "\x1b[4m"),
+        (Self::STRIKETHROUGH, "\x1b[9m"),
+        (Self::REVERSE, "\x1b[7m"),
+    ];
+
+    /// Returns whether all attributes of `other` are set in `self`.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether no attributes are set.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the attributes set in either `self` or `other`.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the escape sequences enabling the attributes,
+    /// or an empty string if no attributes are set.
+    pub(crate) fn codes(self) -> String {
+        Self::CODES
+            .iter()
+            .filter(|(style, _)| self.contains(*style))
+            .map(|(_, code)| *code)
+            .collect()
+    }
+
+    /// Writes the escape sequences enabling the attributes,
+    /// or nothing if no attributes are set.
+    pub(crate) fn write_codes<W: fmt::Write>(
+        self,
+        writer: &mut W,
+    ) -> fmt::Result {
+        for (style, code) in Self::CODES {
+            if self.contains(style) {
+                writer.write_str(code)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BitOr for TextStyle {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for TextStyle {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// Identifies the target of a hyperlink, e.g. of the cells of a `DataCell`.
+/// Urls are interned, so links are `Copy` and equal links have equal urls.
+/// Interned urls are kept until the end of the program.
+///
+/// Control characters, which would end the escape sequence of the hyperlink early
+/// and let the url inject other escape sequences, are percent-encoded.
+///
+/// # Examples
+///
+/// ```
+/// use console_display::style::Link;
+///
+/// let link = Link::new("https://example.com/\x1b]8;;evil\x07");
+///
+/// assert_eq!(&*link.url(), "https://example.com/%1B]8;;evil%07");
+/// assert_eq!(link, Link::new("https://example.com/\x1b]8;;evil\x07"));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Link(usize);
+
+#[derive(Default)]
+struct Links {
+    ids: HashMap<Arc<str>, usize>,
+    urls: Vec<Arc<str>>,
+}
+
+static LINKS: LazyLock<Mutex<Links>> = LazyLock::new(Mutex::default);
+
+impl Link {
+    /// Interns the url, percent-encoding control characters.
+    #[must_use]
+    pub fn new(url: &str) -> Self {
+        let mut escaped = String::with_capacity(url.len());
+        for character in url.chars() {
+            if character.is_control() {
+                for byte in character.encode_utf8(&mut [0; 4]).bytes() {
+                    write!(escaped, "%{byte:02X}")
+                        .expect(WRITING_TO_STRING_FAILED);
+                }
+            }
+            else {
+                escaped.push(character);
+            }
+        }
+        let mut links =
+            LINKS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(id) = links.ids.get(escaped.as_str()) {
+            return Self(*id);
+        }
+        let url: Arc<str> = escaped.into();
+        let id = links.urls.len();
+        links.urls.push(Arc::clone(&url));
+        links.ids.insert(url, id);
+        Self(id)
+    }
+
+    /// Returns the url of the link, with control characters percent-encoded.
+    #[must_use]
+    pub fn url(self) -> Arc<str> {
+        Arc::clone(
+            &LINKS.lock().unwrap_or_else(PoisonError::into_inner).urls
+                [self.0],
+        )
+    }
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Link").field(&self.url()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes() {
+        assert_eq!(TextStyle::NONE.codes(), "");
+        assert_eq!(
+            (TextStyle::REVERSE | TextStyle::BOLD).codes(),
+            "\x1b[1m\x1b[7m"
+        );
+    }
+
+    #[test]
+    fn link() {
+        let link = Link::new("https://example.com");
+        assert_eq!(link, Link::new("https://example.com"));
+        assert_ne!(link, Link::new("https://example.org"));
+        assert_eq!(&*link.url(), "https://example.com");
+        assert_eq!(
+            &*Link::new("a\x1b\\b\x07c\u{9b}").url(),
+            "a%1B\\b%07c%C2%9B"
+        );
+    }
+}
@@ -1,16 +1,43 @@
 use std::{
-    fmt::Display,
+    any::type_name,
+    fmt::{
+        self,
+        Display,
+    },
+    fs,
+    io,
     ops::{
         Deref,
         DerefMut,
     },
+    path::Path,
+    sync::{
+        Mutex,
+        MutexGuard,
+        PoisonError,
+    },
 };
 
-use crate::color::{
-    Color,
-    TerminalColor,
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    color::{
+        Color,
+        ColorDepth,
+        TerminalColor,
+    },
+    error::{
+        WRITING_TO_STRING_FAILED,
+        WidgetError,
+    },
+    impl_new,
+    style::{
+        Link,
+        TextStyle,
+    },
 };
 
+pub mod multi_widget;
 pub mod single_widget;
 pub mod two_widget;
 
@@ -35,21 +62,591 @@ pub const trait DynamicWidget {
     fn string_data(&self) -> StringData;
 }
 
+/// Defines a rectangular region of character cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Region {
+    impl_new!(pub const Region, x: usize, y: usize, width: usize, height: usize);
+
+    /// Returns whether the cell at the specified column and row lies inside the region.
+    #[must_use]
+    pub const fn contains(&self, column: usize, row: usize) -> bool {
+        column >= self.x &&
+            row >= self.y &&
+            column < self.x + self.width &&
+            row < self.y + self.height
+    }
+}
+
+/// Identifies a widget in a composition tree by the indices of the children
+/// leading from the root widget to it.
+/// The root widget itself is identified by an empty path.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WidgetId {
+    path: Vec<usize>,
+}
+
+impl WidgetId {
+    impl_new!(pub const WidgetId, path: Vec<usize>);
+
+    /// Returns the identifier of the root widget.
+    #[must_use]
+    pub const fn root() -> Self {
+        Self { path: Vec::new() }
+    }
+
+    /// Returns the indices of the children leading from the root to the widget.
+    #[must_use]
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Turns an identifier relative to a child into one relative to its parent.
+    #[must_use]
+    pub fn within(mut self, child_index: usize) -> Self {
+        self.path.insert(0, child_index);
+        self
+    }
+}
+
+/// Maps character cells to the widgets of a composition tree, e.g. to dispatch mouse clicks.
+///
+/// Widgets without children are treated as leaves.
+/// Widgets composing other widgets expose the regions of their children,
+/// which are searched for the innermost widget containing a cell.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     pixel::monochrome_pixel::SinglePixel,
+///     pixel_display::StaticPixelDisplay,
+///     widget::{
+///         HitTest,
+///         WidgetId,
+///         two_widget::HorizontalTilingWidget,
+///     },
+/// };
+///
+/// let widget = HorizontalTilingWidget::new(
+///     StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+///     StaticPixelDisplay::<SinglePixel, 3, 2>::new(false),
+/// );
+///
+/// assert_eq!(widget.widget_at(3, 1), Some(WidgetId::new(vec![1])));
+/// assert_eq!(widget.widget_at(5, 0), None);
+/// ```
+pub trait HitTest: DynamicWidget {
+    /// Returns the regions occupied by the direct children in the order of their indices.
+    /// The regions are relative to the top left corner of this widget.
+    fn child_regions(&self) -> Vec<Region>;
+
+    /// Returns the innermost widget containing the cell at the specified column and row,
+    /// or `None` if the cell lies outside of this widget.
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId>;
+}
+
+impl<T: DynamicWidget> HitTest for T {
+    default fn child_regions(&self) -> Vec<Region> {
+        Vec::new()
+    }
+
+    default fn widget_at(
+        &self,
+        column: usize,
+        row: usize,
+    ) -> Option<WidgetId> {
+        Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )
+        .contains(column, row)
+        .then(WidgetId::root)
+    }
+}
+
+/// Describes the structure of a composition tree, e.g. to verify it in tests or debug output.
+///
+/// A widget is described by its type name and its dimensions in characters,
+/// followed by the descriptions of its children in parentheses.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     pixel::monochrome_pixel::SinglePixel,
+///     pixel_display::StaticPixelDisplay,
+///     widget::{
+///         Describe,
+///         two_widget::HorizontalTilingWidget,
+///     },
+/// };
+///
+/// let widget = HorizontalTilingWidget::new(
+///     StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+///     StaticPixelDisplay::<SinglePixel, 3, 2>::new(false),
+/// );
+///
+/// assert_eq!(
+///     widget.describe(),
+///     "HorizontalTilingWidget[5x2](PixelDisplay[2x2], PixelDisplay[3x2])"
+/// );
+/// ```
+pub trait Describe: DynamicWidget {
+    /// Returns the description of the widget and its children.
+    fn describe(&self) -> String;
+}
+
+impl<T: DynamicWidget> Describe for T {
+    default fn describe(&self) -> String {
+        describe_node(self, &[])
+    }
+}
+
+/// Renders a widget into a reusable buffer, e.g. to avoid allocating new rows every frame.
+///
+/// Widgets without a dedicated implementation replace the contents of the buffer with their `string_data`.
+/// Composite widgets render their children into the buffer and lay them out in place.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+///
+/// use console_display::{
+///     pixel::monochrome_pixel::SinglePixel,
+///     pixel_display::StaticPixelDisplay,
+///     widget::{
+///         DynamicWidget,
+///         Render,
+///         StringData,
+///     },
+/// };
+///
+/// let display = StaticPixelDisplay::<SinglePixel, 2, 2>::new(false);
+/// let mut buffer = StringData::default();
+///
+/// display.render_into(&mut buffer);
+///
+/// assert_eq!(buffer, display.string_data());
+/// ```
+pub trait Render: DynamicWidget {
+    /// Renders the widget into the buffer, replacing its contents.
+    /// The result is equal to `string_data`.
+    fn render_into(&self, buf: &mut StringData);
+}
+
+impl<T: DynamicWidget> Render for T {
+    default fn render_into(&self, buf: &mut StringData) {
+        *buf = self.string_data();
+    }
+}
+
+/// Describes a widget given the descriptions of its children.
+/// Generic parameters and the module path are stripped from the type name.
+pub(crate) fn describe_node<W: DynamicWidget>(
+    widget: &W,
+    children: &[String],
+) -> String {
+    let name = type_name::<W>();
+    let name = name.split('<').next().unwrap_or(name);
+    let name = name.rsplit("::").next().unwrap_or(name);
+    let mut description = format!(
+        "{name}[{}x{}]",
+        widget.width_characters(),
+        widget.height_characters()
+    );
+    if !children.is_empty() {
+        description = format!("{description}({})", children.join(", "));
+    }
+    description
+}
+
+/// Resolves a hit on a widget with children.
+/// The first child whose region contains the cell is queried through `hit_child`,
+/// which receives the index of the child and the cell relative to the child.
+/// Cells inside the widget but outside of all child regions hit the widget itself.
+pub(crate) fn resolve_hit<
+    W: DynamicWidget,
+    F: Fn(usize, usize, usize) -> Option<WidgetId>,
+>(
+    widget: &W,
+    column: usize,
+    row: usize,
+    hit_child: F,
+) -> Option<WidgetId> {
+    for (index, region) in widget.child_regions().iter().enumerate() {
+        if region.contains(column, row) {
+            return hit_child(index, column - region.x, row - region.y)
+                .map(|id| id.within(index));
+        }
+    }
+    Region::new(
+        0,
+        0,
+        widget.width_characters(),
+        widget.height_characters(),
+    )
+    .contains(column, row)
+    .then(WidgetId::root)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct StringData {
     pub data: Vec<Vec<DataCell>>,
 }
 
-impl Display for StringData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl StringData {
+    /// Returns the width of the data in characters.
+    /// This is the display width of the first row, so double-width characters count twice.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.data.first().map_or(0, |row| {
+            row.iter()
+                .map(|cell| {
+                    UnicodeWidthChar::width(cell.character).unwrap_or(0)
+                })
+                .sum()
+        })
+    }
+
+    /// Returns the height of the data in characters, i.e. the number of rows.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Renders the data using escape sequences of the given color depth.
+    /// Rows are separated by carriage return and line feed.
+    /// Colors are only emitted when they differ from the previous cell of the row,
+    /// and reset at the end of every row.
+    #[must_use]
+    pub fn render(&self, color_depth: ColorDepth) -> String {
+        self.render_rows(color_depth, "\r\n")
+    }
+
+    /// Renders every row and joins the rows with the separator.
+    fn render_rows(
+        &self,
+        color_depth: ColorDepth,
+        separator: &str,
+    ) -> String {
+        self.data
+            .iter()
+            .map(|row| Self::render_row(row, color_depth))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn render_row(row: &[DataCell], color_depth: ColorDepth) -> String {
         let mut str_repr = String::new();
-        for row in &self.data {
-            for cell in row {
-                str_repr.push_str(&cell.to_string());
+        let mut current_codes = String::new();
+        let mut current_link = None;
+        for cell in row {
+            if cell.link != current_link {
+                if current_link.is_some() {
+                    str_repr.push_str(HYPERLINK_CLOSER);
+                }
+                if let Some(link) = cell.link {
+                    write_hyperlink_opener(&mut str_repr, &link.url())
+                        .expect(WRITING_TO_STRING_FAILED);
+                }
+                current_link = cell.link;
+            }
+            let codes = cell.codes(color_depth);
+            if codes != current_codes {
+                if !current_codes.is_empty() {
+                    str_repr.push_str("\x1b[0m");
+                }
+                str_repr.push_str(&codes);
+                current_codes = codes;
+            }
+            str_repr.push(cell.character);
+        }
+        if !current_codes.is_empty() {
+            str_repr.push_str("\x1b[0m");
+        }
+        if current_link.is_some() {
+            str_repr.push_str(HYPERLINK_CLOSER);
+        }
+        str_repr
+    }
+
+    /// Renders the data as true color escape sequences, with rows separated by line feeds.
+    /// This is the output of `print_display` without cursor positioning,
+    /// so it can be printed to a terminal as is, e.g. with `cat`.
+    #[must_use]
+    pub fn to_ansi_string(&self) -> String {
+        self.to_ansi_string_with_depth(ColorDepth::TrueColor)
+    }
+
+    /// Renders the data like [`StringData::to_ansi_string`],
+    /// with colors approximated to the given color depth.
+    #[must_use]
+    pub fn to_ansi_string_with_depth(
+        &self,
+        color_depth: ColorDepth,
+    ) -> String {
+        self.render_rows(color_depth, "\n")
+    }
+
+    /// Returns the characters of the data without any colors, with rows separated by line feeds.
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        self.data
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.character).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Writes the result of [`StringData::to_ansi_string`] to a file, followed by a line feed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be written.
+    pub fn save_ansi<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_ansi_string() + "\n")
+    }
+
+    /// Applies the function to the foreground and background color of every cell.
+    #[must_use]
+    pub fn map_colors<F: Fn(TerminalColor) -> TerminalColor>(
+        mut self,
+        function: F,
+    ) -> Self {
+        self.map_colors_in_place(function);
+        self
+    }
+
+    /// Applies the function to the foreground and background color of every cell in place.
+    /// See [`StringData::map_colors`].
+    pub fn map_colors_in_place<F: Fn(TerminalColor) -> TerminalColor>(
+        &mut self,
+        function: F,
+    ) {
+        for cell in self.data.iter_mut().flatten() {
+            cell.foreground = function(cell.foreground);
+            cell.background = function(cell.background);
+        }
+    }
+
+    /// Resizes the data to the given number of rows and empties every row,
+    /// keeping the allocated capacity for the next contents.
+    pub fn clear_rows(&mut self, height: usize) {
+        self.data.resize_with(height, Vec::new);
+        for row in &mut self.data {
+            row.clear();
+        }
+    }
+
+    /// Pads or truncates the data to the given width and height in characters,
+    /// filling missing cells and rows with blank cells.
+    /// A double-width character crossing the right edge is replaced by a blank cell.
+    ///
+    /// Composite widgets use this to lay out children whose dimensions changed after building,
+    /// e.g. through `DerefMut`, instead of failing to render.
+    pub fn fit(&mut self, width: usize, height: usize) {
+        self.data.resize_with(height, Vec::new);
+        for row in &mut self.data {
+            let mut columns = 0;
+            let mut cells = row.len();
+            for (index, cell) in row.iter().enumerate() {
+                let cell_width =
+                    UnicodeWidthChar::width(cell.character).unwrap_or(0);
+                if columns + cell_width > width {
+                    cells = index;
+                    break;
+                }
+                columns += cell_width;
             }
-            str_repr.push_str("\r\n");
+            row.truncate(cells);
+            row.resize(cells + (width - columns), DataCell::default());
+        }
+    }
+
+    /// Stacks `other` below `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width of both operands does not match.
+    /// Empty data can be stacked with data of any width.
+    pub fn stack_vertical(
+        mut self,
+        mut other: Self,
+    ) -> Result<Self, WidgetError> {
+        self.append_vertical(&mut other)?;
+        Ok(self)
+    }
+
+    /// Stacks `other` below `self` in place, moving the rows out of `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width of both operands does not match.
+    /// Empty data can be stacked with data of any width.
+    pub fn append_vertical(
+        &mut self,
+        other: &mut Self,
+    ) -> Result<(), WidgetError> {
+        if !self.is_empty() &&
+            !other.is_empty() &&
+            self.width() != other.width()
+        {
+            return Err(WidgetError::WidthMismatch(
+                self.width(),
+                other.width(),
+            ));
         }
-        str_repr = str_repr.trim_end_matches("\r\n").to_string();
-        write!(f, "{str_repr}")
+        self.data.append(&mut other.data);
+        Ok(())
+    }
+
+    /// Stacks `other` to the right of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the height of both operands does not match.
+    pub fn stack_horizontal(
+        mut self,
+        other: Self,
+    ) -> Result<Self, WidgetError> {
+        self.append_horizontal(&other)?;
+        Ok(self)
+    }
+
+    /// Stacks `other` to the right of `self` in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the height of both operands does not match.
+    pub fn append_horizontal(
+        &mut self,
+        other: &Self,
+    ) -> Result<(), WidgetError> {
+        if self.height() != other.height() {
+            return Err(WidgetError::HeightMismatch(
+                self.height(),
+                other.height(),
+            ));
+        }
+        for (row, other_row) in self.data.iter_mut().zip(&other.data) {
+            row.extend_from_slice(other_row);
+        }
+        Ok(())
+    }
+
+    /// Places `self` on top of `base`.
+    /// Cells that are (partially) transparent let the cells of `base` show through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of both operands don't match.
+    pub fn overlay(self, base: Self) -> Result<Self, WidgetError> {
+        self.overlay_with(base, TerminalColor::blend)
+    }
+
+    /// Places `self` on top of `base`, blending colors with the provided function.
+    /// The function receives the top color first and the bottom color second.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of both operands don't match.
+    pub fn overlay_with<
+        F: Fn(&TerminalColor, &TerminalColor) -> TerminalColor,
+    >(
+        mut self,
+        base: Self,
+        blend: F,
+    ) -> Result<Self, WidgetError> {
+        self.overlay_in_place(&base, blend)?;
+        Ok(self)
+    }
+
+    /// Places `self` on top of `base` in place, blending colors with the provided function.
+    /// See [`StringData::overlay_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of both operands don't match.
+    pub fn overlay_in_place<
+        F: Fn(&TerminalColor, &TerminalColor) -> TerminalColor,
+    >(
+        &mut self,
+        base: &Self,
+        blend: F,
+    ) -> Result<(), WidgetError> {
+        if self.width() != base.width() || self.height() != base.height() {
+            return Err(WidgetError::WidthAndOrHeightMismatch(
+                self.width(),
+                base.width(),
+                self.height(),
+                base.height(),
+            ));
+        }
+        for (overlay_row, base_row) in self.data.iter_mut().zip(&base.data)
+        {
+            for (cell_top, cell_bottom) in
+                overlay_row.iter_mut().zip(base_row)
+            {
+                *cell_top = cell_top.blend_over_with(cell_bottom, &blend);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Buffer a composite widget renders a child into before laying it out,
+/// kept to reuse its rows across frames.
+/// It is not part of the state of the widget,
+/// so all scratch buffers are equal and clones start out empty.
+/// A mutex instead of a `RefCell` keeps the widgets `Sync`.
+#[derive(Debug, Default)]
+pub(crate) struct ScratchBuffer(Mutex<StringData>);
+
+impl ScratchBuffer {
+    pub(crate) const fn new() -> Self {
+        Self(Mutex::new(StringData { data: Vec::new() }))
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, StringData> {
+        // The buffer is overwritten before every use, so a poisoned one is still usable.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for ScratchBuffer {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for ScratchBuffer {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ScratchBuffer {}
+
+impl Display for StringData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(ColorDepth::TrueColor))
     }
 }
 
@@ -67,23 +664,460 @@ impl const DerefMut for StringData {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct DataCell {
     pub character: char,
     pub foreground: TerminalColor,
     pub background: TerminalColor,
+    pub style: TextStyle,
+    /// Target of the hyperlink the cell belongs to, if any.
+    /// Adjacent cells with the same link are rendered as a single link.
+    pub link: Option<Link>,
 }
 
-impl Display for DataCell {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            TerminalColor::color(
-                &self.character.to_string(),
+/// Escape sequence ending a hyperlink.
+const HYPERLINK_CLOSER: &str = "\x1b]8;;\x1b\\";
+
+/// Writes the escape sequence starting a hyperlink to the url.
+fn write_hyperlink_opener<W: fmt::Write>(
+    writer: &mut W,
+    url: &str,
+) -> fmt::Result {
+    write!(writer, "\x1b]8;;{url}\x1b\\")
+}
+
+/// A blank cell in the default colors.
+impl const Default for DataCell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            foreground: TerminalColor::Default,
+            background: TerminalColor::Default,
+            style: TextStyle::NONE,
+            link: None,
+        }
+    }
+}
+
+impl DataCell {
+    /// Places `self` on top of `base` and returns the composited cell.
+    /// Colors are blended with [`TerminalColor::blend`].
+    ///
+    /// A cell is see-through if either of its colors is the terminal default,
+    /// or if both of its colors are less than half opaque.
+    /// In that case the character of `base` shows through and the colors of both cells are blended.
+    /// Otherwise `self` is returned unchanged.
+    #[must_use]
+    pub fn blend_over(&self, base: &Self) -> Self {
+        self.blend_over_with(base, TerminalColor::blend)
+    }
+
+    /// Places `self` on top of `base`, blending colors with the provided function.
+    /// The function receives the top color first and the bottom color second.
+    /// See [`DataCell::blend_over`] for the compositing rules.
+    #[must_use]
+    pub fn blend_over_with<
+        F: Fn(&TerminalColor, &TerminalColor) -> TerminalColor,
+    >(
+        &self,
+        base: &Self,
+        blend: F,
+    ) -> Self {
+        if !self.is_see_through() {
+            return *self;
+        }
+        Self {
+            character: base.character,
+            foreground: blend(&self.foreground, &base.foreground),
+            background: blend(&self.background, &base.background),
+            link: base.link,
+            style: base.style,
+        }
+    }
+
+    fn is_see_through(&self) -> bool {
+        let translucent = |color: &TerminalColor| {
+            matches!(
+                color,
+                TerminalColor::ARGBColor(color)
+                    if color.opacity < u8::MAX / 2
+            )
+        };
+        self.foreground == TerminalColor::Default ||
+            self.background == TerminalColor::Default ||
+            translucent(&self.foreground) &&
+                translucent(&self.background)
+    }
+
+    /// Renders the cell using escape sequences of the given color depth.
+    /// Text style attributes are emitted before the colors.
+    /// Linked cells are wrapped in a hyperlink.
+    #[must_use]
+    pub fn render(&self, color_depth: ColorDepth) -> String {
+        let mut rendered = String::new();
+        self.write_rendered(&mut rendered, color_depth)
+            .expect(WRITING_TO_STRING_FAILED);
+        rendered
+    }
+
+    /// Writes the cell like [`DataCell::render`], without allocating intermediate strings.
+    fn write_rendered<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        color_depth: ColorDepth,
+    ) -> fmt::Result {
+        if let Some(link) = self.link {
+            write_hyperlink_opener(writer, &link.url())?;
+        }
+        self.style.write_codes(writer)?;
+        TerminalColor::write_color_with_depth(
+            writer,
+            self.character.encode_utf8(&mut [0; 4]),
+            &self.foreground,
+            &self.background,
+            color_depth,
+        )?;
+        // Without colors, the style is not reset by the colored text.
+        if !self.style.is_empty() &&
+            self.foreground == TerminalColor::Default &&
+            self.background == TerminalColor::Default
+        {
+            writer.write_str("\x1b[0m")?;
+        }
+        if self.link.is_some() {
+            writer.write_str(HYPERLINK_CLOSER)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the escape sequences selecting the style and colors of the cell,
+    /// or an empty string if the cell has no style and default colors.
+    fn codes(&self, color_depth: ColorDepth) -> String {
+        self.style.codes() +
+            &TerminalColor::color_codes(
                 &self.foreground,
-                &self.background
+                &self.background,
+                color_depth,
             )
-        )
+    }
+}
+
+impl Display for DataCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_rendered(f, ColorDepth::TrueColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::{
+            ARGBColor,
+            ColorDepth,
+            RGBColor,
+            TerminalColor,
+        },
+        error::WidgetError,
+        style::TextStyle,
+        widget::{
+            DataCell,
+            DynamicWidget,
+            StringData,
+        },
+    };
+
+    fn string_data(
+        character: char,
+        width: usize,
+        height: usize,
+    ) -> StringData {
+        StringData {
+            data: vec![
+                vec![
+                    DataCell {
+                        character,
+                        foreground: TerminalColor::Default,
+                        background: TerminalColor::Default,
+                        style: TextStyle::NONE,
+                        link: None,
+                    };
+                    width
+                ];
+                height
+            ],
+        }
+    }
+
+    #[test]
+    fn to_ansi_string() {
+        let mut data = string_data('a', 3, 2);
+        for cell in data.iter_mut().flatten() {
+            cell.foreground = RGBColor::WHITE.into();
+            cell.background = RGBColor::BLACK.into();
+        }
+        let ansi = data.to_ansi_string();
+        assert_eq!(ansi.lines().count(), 2);
+        assert_eq!(ansi.matches("\x1b[0m").count(), 2);
+        assert!(!ansi.contains('\r'));
+    }
+
+    #[test]
+    fn to_ansi_string_with_depth() {
+        let mut data = string_data('a', 3, 2);
+        for cell in data.iter_mut().flatten() {
+            cell.foreground = RGBColor::RED.into();
+        }
+        assert_eq!(
+            data.to_ansi_string_with_depth(ColorDepth::TrueColor),
+            data.to_ansi_string()
+        );
+        let ansi = data.to_ansi_string_with_depth(ColorDepth::Ansi256);
+        assert_eq!(ansi.lines().count(), 2);
+        assert!(ansi.contains("\x1b[38;5;"));
+        assert!(!ansi.contains("\x1b[38;2;"));
+    }
+
+    #[test]
+    fn render_uniform_row() {
+        let mut data = string_data('a', 200, 1);
+        for cell in data.iter_mut().flatten() {
+            cell.foreground = RGBColor::RED.into();
+        }
+        let rendered = data.render(ColorDepth::TrueColor);
+        assert_eq!(rendered.matches("\x1b[38;2;").count(), 1);
+        assert_eq!(rendered.matches("\x1b[0m").count(), 1);
+        assert_eq!(rendered.matches('a').count(), 200);
+    }
+
+    #[test]
+    fn to_plain_string() {
+        let mut data = string_data('a', 2, 2);
+        data[1][0].character = 'b';
+        for cell in data.iter_mut().flatten() {
+            cell.foreground = RGBColor::RED.into();
+            cell.background = RGBColor::BLUE.into();
+        }
+        assert_eq!(data.to_plain_string(), "aa\nba");
+    }
+
+    #[test]
+    fn stack_vertical() {
+        let stacked = string_data('a', 3, 2)
+            .stack_vertical(string_data('b', 3, 1))
+            .unwrap();
+        assert_eq!(stacked.width(), 3);
+        assert_eq!(stacked.height(), 3);
+        assert_eq!(stacked.to_string(), "aaa\r\naaa\r\nbbb");
+    }
+
+    #[test]
+    fn stack_horizontal() {
+        let stacked = string_data('a', 1, 2)
+            .stack_horizontal(string_data('b', 2, 2))
+            .unwrap();
+        assert_eq!(stacked.width(), 3);
+        assert_eq!(stacked.height(), 2);
+        assert_eq!(stacked.to_string(), "abb\r\nabb");
+    }
+
+    #[test]
+    fn stack_mismatch() {
+        assert_eq!(
+            string_data('a', 3, 2).stack_vertical(string_data('b', 2, 2)),
+            Err(WidgetError::WidthMismatch(3, 2))
+        );
+        assert_eq!(
+            string_data('a', 3, 2)
+                .stack_horizontal(string_data('b', 3, 1)),
+            Err(WidgetError::HeightMismatch(2, 1))
+        );
+    }
+
+    #[test]
+    fn fit() {
+        let mut data = string_data('a', 3, 2);
+        data.fit(2, 3);
+        assert_eq!(data.to_string(), "aa\r\naa\r\n  ");
+
+        let mut wide = string_data('あ', 2, 1);
+        wide.fit(3, 1);
+        assert_eq!(wide.to_plain_string(), "あ ");
+        wide.fit(5, 1);
+        assert_eq!(wide.to_plain_string(), "あ   ");
+    }
+
+    #[test]
+    fn blend_over() {
+        let base = DataCell {
+            character: 'b',
+            foreground: TerminalColor::ARGBColor(ARGBColor {
+                opacity: u8::MAX,
+                color: RGBColor::WHITE,
+            }),
+            background: TerminalColor::ARGBColor(ARGBColor {
+                opacity: u8::MAX,
+                color: RGBColor::BLACK,
+            }),
+            style: TextStyle::NONE,
+            link: None,
+        };
+        let top = DataCell {
+            character: 'a',
+            foreground: TerminalColor::ARGBColor(ARGBColor::TRANSPARENT),
+            background: TerminalColor::ARGBColor(ARGBColor::TRANSPARENT),
+            style: TextStyle::BOLD,
+            link: None,
+        };
+        let blended = top.blend_over(&base);
+        assert_eq!(blended.character, 'b');
+        assert_eq!(blended.foreground, base.foreground);
+        assert_eq!(blended.background, base.background);
+        assert_eq!(blended.style, base.style);
+
+        let opaque = DataCell {
+            character: 'a',
+            ..base
+        };
+        assert_eq!(opaque.blend_over(&top), opaque);
+    }
+
+    #[test]
+    fn render_style() {
+        let cell = DataCell {
+            style: TextStyle::BOLD,
+            ..DataCell::default()
+        };
+        assert_eq!(cell.to_string(), "\x1b[1m \x1b[0m");
+        assert!(
+            string_data('a', 2, 1).data[0]
+                .iter()
+                .all(|cell| cell.to_string() == "a")
+        );
+    }
+
+    #[test]
+    fn render_link() {
+        let link = DataCell {
+            link: Some(Link::new("https://example.com")),
+            ..DataCell::default()
+        };
+        assert_eq!(
+            link.to_string(),
+            "\x1b]8;;https://example.com\x1b\\ \x1b]8;;\x1b\\"
+        );
+
+        let data = StringData {
+            data: vec![vec![
+                DataCell::default(),
+                link,
+                link,
+                DataCell::default(),
+            ]],
+        };
+        assert_eq!(
+            data.to_string(),
+            " \x1b]8;;https://example.com\x1b\\  \x1b]8;;\x1b\\ "
+        );
+    }
+
+    #[test]
+    fn display_cell() {
+        let cell = DataCell {
+            character: 'x',
+            style: TextStyle::BOLD,
+            link: Some(Link::new("https://example.com")),
+            ..DataCell::default()
+        };
+        assert_eq!(
+            cell.to_string(),
+            "\x1b]8;;https://example.com\x1b\\\x1b[1mx\x1b[0m\x1b]8;;\x1b\\"
+        );
+        assert_eq!(cell.to_string(), cell.render(ColorDepth::TrueColor));
+    }
+
+    #[test]
+    fn overlay() {
+        let overlay = string_data('a', 2, 2)
+            .overlay(string_data('b', 2, 2))
+            .unwrap();
+        assert_eq!(overlay.to_string(), "bb\r\nbb");
+        assert!(
+            string_data('a', 2, 2)
+                .overlay(string_data('b', 2, 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn render_into_matches_string_data() {
+        use crate::{
+            character_display::DynamicCharacterDisplay,
+            pixel::character_pixel::CharacterPixel,
+            widget::multi_widget::VerticalStackWidget,
+        };
+
+        let widget = VerticalStackWidget::build(vec![
+            DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "aあb\ncd",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap(),
+            DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "efgh",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap(),
+        ])
+        .unwrap();
+        let mut buffer = string_data('x', 5, 7);
+        widget.render_into(&mut buffer);
+        assert_eq!(buffer, widget.string_data());
+    }
+
+    #[test]
+    fn render_into_reuses_capacity() {
+        use crate::{
+            pixel::monochrome_pixel::SinglePixel,
+            pixel_display::StaticPixelDisplay,
+            widget::{
+                single_widget::PaddingWidget,
+                two_widget::{
+                    HorizontalTilingWidget,
+                    VerticalTilingWidget,
+                },
+            },
+        };
+
+        let widget = PaddingWidget::new(
+            VerticalTilingWidget::new(
+                HorizontalTilingWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 2, 2>::new(true),
+                    StaticPixelDisplay::<SinglePixel, 3, 2>::new(false),
+                ),
+                StaticPixelDisplay::<SinglePixel, 5, 1>::new(true),
+            ),
+            1,
+            2,
+            1,
+            1,
+        );
+        let capacities = |buffer: &StringData| {
+            (
+                buffer.data.capacity(),
+                buffer.data.iter().map(Vec::capacity).collect::<Vec<_>>(),
+            )
+        };
+
+        let mut buffer = StringData::default();
+        widget.render_into(&mut buffer);
+        assert_eq!(buffer, widget.string_data());
+        let expected = capacities(&buffer);
+        for _ in 0..10 {
+            widget.render_into(&mut buffer);
+            assert_eq!(capacities(&buffer), expected);
+        }
+        assert_eq!(buffer, widget.string_data());
     }
 }
@@ -0,0 +1,767 @@
+use std::{
+    mem,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+use crate::{
+    error::WidgetError,
+    impl_getters,
+    widget::{
+        Describe,
+        DynamicWidget,
+        HitTest,
+        Region,
+        Render,
+        ScratchBuffer,
+        StringData,
+        WidgetId,
+        describe_node,
+        resolve_hit,
+    },
+};
+
+/// Stacks any number of widgets of the same type from top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerticalStackWidget<T: DynamicWidget> {
+    children: Vec<T>,
+}
+
+impl<T: DynamicWidget> VerticalStackWidget<T> {
+    /// Builds a vertical stack widget.
+    /// The first child will be displayed at the top.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width of the children does not match.
+    pub fn build(children: Vec<T>) -> Result<Self, WidgetError> {
+        if let Some(first) = children.first() &&
+            let Some(mismatch) = children.iter().find(|child| {
+                child.width_characters() != first.width_characters()
+            })
+        {
+            return Err(WidgetError::WidthMismatch(
+                first.width_characters(),
+                mismatch.width_characters(),
+            ));
+        }
+        Ok(Self { children })
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for VerticalStackWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, DynamicWidget::width_characters)
+    }
+
+    fn height_characters(&self) -> usize {
+        self.children
+            .iter()
+            .map(DynamicWidget::height_characters)
+            .sum()
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for VerticalStackWidget<T> {
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the height they report and the width of the first child.
+    fn render_into(&self, buf: &mut StringData) {
+        let width = self.width_characters();
+        // Each child reuses as many rows of the previous frame as it occupies.
+        let mut rows = buf.data.split_off(0);
+        for child in &self.children {
+            let height = child.height_characters();
+            let rest = rows.split_off(height.min(rows.len()));
+            let mut data = StringData {
+                data: mem::replace(&mut rows, rest),
+            };
+            child.render_into(&mut data);
+            data.fit(width, height);
+            buf.data.append(&mut data.data);
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for VerticalStackWidget<T> {
+    fn child_regions(&self) -> Vec<Region> {
+        let mut y = 0;
+        self.children
+            .iter()
+            .map(|child| {
+                let region = Region::new(
+                    0,
+                    y,
+                    child.width_characters(),
+                    child.height_characters(),
+                );
+                y += child.height_characters();
+                region
+            })
+            .collect()
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| {
+            self.children[index].widget_at(x, y)
+        })
+    }
+}
+
+impl<T: DynamicWidget> Describe for VerticalStackWidget<T> {
+    fn describe(&self) -> String {
+        let children = self
+            .children
+            .iter()
+            .map(Describe::describe)
+            .collect::<Vec<_>>();
+        describe_node(self, &children)
+    }
+}
+
+impl<T: DynamicWidget> Deref for VerticalStackWidget<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+impl<T: DynamicWidget> DerefMut for VerticalStackWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.children
+    }
+}
+
+/// Stacks any number of widgets of the same type from left to right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HorizontalStackWidget<T: DynamicWidget> {
+    children: Vec<T>,
+    scratch: ScratchBuffer,
+}
+
+impl<T: DynamicWidget> HorizontalStackWidget<T> {
+    /// Builds a horizontal stack widget.
+    /// The first child will be displayed on the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the height of the children does not match.
+    pub fn build(children: Vec<T>) -> Result<Self, WidgetError> {
+        if let Some(first) = children.first() &&
+            let Some(mismatch) = children.iter().find(|child| {
+                child.height_characters() != first.height_characters()
+            })
+        {
+            return Err(WidgetError::HeightMismatch(
+                first.height_characters(),
+                mismatch.height_characters(),
+            ));
+        }
+        Ok(Self {
+            children,
+            scratch: ScratchBuffer::new(),
+        })
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for HorizontalStackWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.children
+            .iter()
+            .map(DynamicWidget::width_characters)
+            .sum()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, DynamicWidget::height_characters)
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for HorizontalStackWidget<T> {
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the width they report and the height of the first child.
+    fn render_into(&self, buf: &mut StringData) {
+        let mut children = self.children.iter();
+        let Some(first) = children.next()
+        else {
+            buf.data.clear();
+            return;
+        };
+        let height = first.height_characters();
+        first.render_into(buf);
+        buf.fit(first.width_characters(), height);
+        let mut data = self.scratch.lock();
+        for child in children {
+            child.render_into(&mut data);
+            data.fit(child.width_characters(), height);
+            for (row, child_row) in buf.data.iter_mut().zip(&data.data) {
+                row.extend_from_slice(child_row);
+            }
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for HorizontalStackWidget<T> {
+    fn child_regions(&self) -> Vec<Region> {
+        let mut x = 0;
+        self.children
+            .iter()
+            .map(|child| {
+                let region = Region::new(
+                    x,
+                    0,
+                    child.width_characters(),
+                    child.height_characters(),
+                );
+                x += child.width_characters();
+                region
+            })
+            .collect()
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| {
+            self.children[index].widget_at(x, y)
+        })
+    }
+}
+
+impl<T: DynamicWidget> Describe for HorizontalStackWidget<T> {
+    fn describe(&self) -> String {
+        let children = self
+            .children
+            .iter()
+            .map(Describe::describe)
+            .collect::<Vec<_>>();
+        describe_node(self, &children)
+    }
+}
+
+impl<T: DynamicWidget> Deref for HorizontalStackWidget<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+impl<T: DynamicWidget> DerefMut for HorizontalStackWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.children
+    }
+}
+
+/// Lays out widgets of the same type in a grid with `COLS` columns.
+/// Children are placed in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridWidget<T: DynamicWidget, const COLS: usize> {
+    children: Vec<T>,
+    scratch: ScratchBuffer,
+}
+
+impl<T: DynamicWidget, const COLS: usize> GridWidget<T, COLS> {
+    /// Builds a grid widget.
+    /// The first `COLS` children make up the top row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of children is not a multiple of
+    /// `COLS` or if the dimensions of the children do not match.
+    pub fn build(children: Vec<T>) -> Result<Self, WidgetError> {
+        if children.len().checked_rem(COLS) != Some(0) {
+            return Err(WidgetError::ChildCountNotMultipleOfColumns(
+                children.len(),
+                COLS,
+            ));
+        }
+        if let Some(first) = children.first() &&
+            let Some(mismatch) = children.iter().find(|child| {
+                child.width_characters() != first.width_characters() ||
+                    child.height_characters() !=
+                        first.height_characters()
+            })
+        {
+            return Err(WidgetError::WidthAndOrHeightMismatch(
+                first.width_characters(),
+                mismatch.width_characters(),
+                first.height_characters(),
+                mismatch.height_characters(),
+            ));
+        }
+        Ok(Self {
+            children,
+            scratch: ScratchBuffer::new(),
+        })
+    }
+
+    pub const fn rows(&self) -> usize {
+        self.children.len() / COLS
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> DynamicWidget
+    for GridWidget<T, COLS>
+{
+    fn width_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, |child| child.width_characters() * COLS)
+    }
+
+    fn height_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, |child| child.height_characters() * self.rows())
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> Render for GridWidget<T, COLS> {
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the dimensions of the first child.
+    fn render_into(&self, buf: &mut StringData) {
+        let Some(first) = self.children.first()
+        else {
+            buf.data.clear();
+            return;
+        };
+        let (width, height) =
+            (first.width_characters(), first.height_characters());
+        buf.clear_rows(height * self.rows());
+        let mut data = self.scratch.lock();
+        for (index, child) in self.children.iter().enumerate() {
+            child.render_into(&mut data);
+            data.fit(width, height);
+            for (row, child_row) in buf.data[index / COLS * height..]
+                .iter_mut()
+                .zip(&data.data)
+            {
+                row.extend_from_slice(child_row);
+            }
+        }
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> HitTest for GridWidget<T, COLS> {
+    fn child_regions(&self) -> Vec<Region> {
+        self.children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| {
+                let width = child.width_characters();
+                let height = child.height_characters();
+                Region::new(
+                    index % COLS * width,
+                    index / COLS * height,
+                    width,
+                    height,
+                )
+            })
+            .collect()
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| {
+            self.children[index].widget_at(x, y)
+        })
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> Describe
+    for GridWidget<T, COLS>
+{
+    fn describe(&self) -> String {
+        let children = self
+            .children
+            .iter()
+            .map(Describe::describe)
+            .collect::<Vec<_>>();
+        describe_node(self, &children)
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> Deref for GridWidget<T, COLS> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+impl<T: DynamicWidget, const COLS: usize> DerefMut
+    for GridWidget<T, COLS>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.children
+    }
+}
+
+/// Displays one of any number of widgets of the same type at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabWidget<T: DynamicWidget> {
+    children: Vec<T>,
+    selected: usize,
+}
+
+impl<T: DynamicWidget> TabWidget<T> {
+    /// Builds a tab widget displaying the first child.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of the children don't match.
+    pub fn build(children: Vec<T>) -> Result<Self, WidgetError> {
+        if let Some(first) = children.first() &&
+            let Some(mismatch) = children.iter().find(|child| {
+                child.width_characters() != first.width_characters() ||
+                    child.height_characters() !=
+                        first.height_characters()
+            })
+        {
+            return Err(WidgetError::WidthAndOrHeightMismatch(
+                first.width_characters(),
+                mismatch.width_characters(),
+                first.height_characters(),
+                mismatch.height_characters(),
+            ));
+        }
+        Ok(Self {
+            children,
+            selected: 0,
+        })
+    }
+
+    impl_getters!(pub const selected: usize);
+
+    /// Selects the child to display.
+    /// The index is clamped to the last child.
+    pub const fn select(&mut self, index: usize) {
+        let last = self.children.len().saturating_sub(1);
+        self.selected = if index < last { index } else { last };
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for TabWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, DynamicWidget::width_characters)
+    }
+
+    fn height_characters(&self) -> usize {
+        self.children
+            .first()
+            .map_or(0, DynamicWidget::height_characters)
+    }
+
+    fn string_data(&self) -> StringData {
+        self.children
+            .get(self.selected)
+            .map_or(StringData { data: Vec::new() }, |child| {
+                child.string_data()
+            })
+    }
+}
+
+impl<T: DynamicWidget> Render for TabWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        match self.children.get(self.selected) {
+            Some(child) => child.render_into(buf),
+            None => buf.data.clear(),
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for TabWidget<T> {
+    /// Only the child that is currently displayed occupies the widget.
+    fn child_regions(&self) -> Vec<Region> {
+        (0..self.children.len())
+            .map(|index| {
+                if index == self.selected {
+                    Region::new(
+                        0,
+                        0,
+                        self.width_characters(),
+                        self.height_characters(),
+                    )
+                }
+                else {
+                    Region::new(0, 0, 0, 0)
+                }
+            })
+            .collect()
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| {
+            self.children[index].widget_at(x, y)
+        })
+    }
+}
+
+impl<T: DynamicWidget> Describe for TabWidget<T> {
+    fn describe(&self) -> String {
+        let children = self
+            .children
+            .iter()
+            .map(Describe::describe)
+            .collect::<Vec<_>>();
+        describe_node(self, &children)
+    }
+}
+
+impl<T: DynamicWidget> Deref for TabWidget<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.children
+    }
+}
+
+impl<T: DynamicWidget> DerefMut for TabWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        drawing::DynamicCanvas,
+        pixel::monochrome_pixel::SinglePixel,
+        pixel_display::DynamicPixelDisplay,
+    };
+
+    use super::*;
+
+    fn display(
+        width: usize,
+        height: usize,
+    ) -> DynamicPixelDisplay<SinglePixel> {
+        DynamicPixelDisplay::<SinglePixel>::new(width, height, false)
+    }
+
+    mod vertical_stack {
+        use super::*;
+
+        #[test]
+        fn build_success() {
+            let stack = VerticalStackWidget::build(vec![
+                display(4, 1),
+                display(4, 2),
+                display(4, 3),
+            ]);
+            assert!(stack.is_ok());
+        }
+
+        #[test]
+        fn build_failure() {
+            let stack = VerticalStackWidget::build(vec![
+                display(4, 1),
+                display(4, 2),
+                display(5, 3),
+            ]);
+            assert!(stack.is_err());
+        }
+
+        #[test]
+        fn dimensions() {
+            let stack = VerticalStackWidget::build(vec![
+                display(4, 1),
+                display(4, 2),
+                display(4, 3),
+            ])
+            .unwrap();
+            assert_eq!(stack.width_characters(), 4);
+            assert_eq!(stack.height_characters(), 6);
+            assert_eq!(stack.string_data().data.len(), 6);
+        }
+
+        #[test]
+        fn resized_child() {
+            let mut stack = VerticalStackWidget::build(vec![
+                display(2, 1),
+                display(2, 1),
+            ])
+            .unwrap();
+            stack[1].resize(3, 2, true).unwrap();
+            assert_eq!(
+                stack.string_data().to_plain_string(),
+                "  \n  \n██"
+            );
+        }
+    }
+
+    mod horizontal_stack {
+        use super::*;
+
+        #[test]
+        fn build_success() {
+            let stack = HorizontalStackWidget::build(vec![
+                display(1, 4),
+                display(2, 4),
+                display(3, 4),
+            ]);
+            assert!(stack.is_ok());
+        }
+
+        #[test]
+        fn build_failure() {
+            let stack = HorizontalStackWidget::build(vec![
+                display(1, 4),
+                display(2, 5),
+            ]);
+            assert!(stack.is_err());
+        }
+
+        #[test]
+        fn dimensions() {
+            let stack = HorizontalStackWidget::build(vec![
+                display(1, 4),
+                display(2, 4),
+                display(3, 4),
+            ])
+            .unwrap();
+            assert_eq!(stack.width_characters(), 6);
+            assert_eq!(stack.height_characters(), 4);
+            assert_eq!(stack.string_data().data[0].len(), 6);
+        }
+
+        #[test]
+        fn resized_child() {
+            let mut stack = HorizontalStackWidget::build(vec![
+                display(1, 2),
+                display(1, 2),
+            ])
+            .unwrap();
+            stack[1].resize(2, 3, true).unwrap();
+            assert_eq!(stack.string_data().to_plain_string(), "  █\n ██");
+        }
+    }
+
+    mod grid {
+        use super::*;
+
+        #[test]
+        fn build_success() {
+            let grid = GridWidget::<_, 2>::build(vec![
+                display(3, 2),
+                display(3, 2),
+                display(3, 2),
+                display(3, 2),
+            ]);
+            assert!(grid.is_ok());
+        }
+
+        #[test]
+        fn build_failure() {
+            let grid = GridWidget::<_, 2>::build(vec![
+                display(3, 2),
+                display(3, 2),
+                display(3, 2),
+            ]);
+            assert_eq!(
+                grid,
+                Err(WidgetError::ChildCountNotMultipleOfColumns(3, 2))
+            );
+
+            let grid = GridWidget::<_, 2>::build(vec![
+                display(3, 2),
+                display(3, 3),
+            ]);
+            assert!(grid.is_err());
+        }
+
+        #[test]
+        fn dimensions() {
+            let grid = GridWidget::<_, 2>::build(vec![
+                display(3, 2),
+                display(3, 2),
+                display(3, 2),
+                display(3, 2),
+            ])
+            .unwrap();
+            assert_eq!(grid.rows(), 2);
+            assert_eq!(grid.width_characters(), 6);
+            assert_eq!(grid.height_characters(), 4);
+
+            let data = grid.string_data().data;
+            assert_eq!(data.len(), 4);
+            assert!(data.iter().all(|row| row.len() == 6));
+        }
+
+        #[test]
+        fn resized_child() {
+            let mut grid = GridWidget::<_, 2>::build(vec![
+                DynamicPixelDisplay::<SinglePixel>::new(1, 1, true);
+                4
+            ])
+            .unwrap();
+            grid[0].resize(2, 2, false).unwrap();
+            assert_eq!(
+                grid.string_data().to_plain_string(),
+                "█ █ \n    \n█ █ \n    "
+            );
+        }
+    }
+
+    mod tab {
+        use super::*;
+
+        #[test]
+        fn build_failure() {
+            let tab = TabWidget::build(vec![
+                display(3, 2),
+                display(3, 2),
+                display(2, 3),
+            ]);
+            assert_eq!(
+                tab,
+                Err(WidgetError::WidthAndOrHeightMismatch(3, 2, 2, 3))
+            );
+        }
+
+        #[test]
+        fn select() {
+            let mut first = display(2, 2);
+            first.set_pixel(0, 0, true).unwrap();
+            let mut tab = TabWidget::build(vec![
+                first,
+                display(2, 2),
+                display(2, 2),
+            ])
+            .unwrap();
+            assert_eq!(tab.string_data(), tab[0].string_data());
+
+            tab.select(1);
+            assert_eq!(*tab.selected(), 1);
+            assert_eq!(tab.string_data(), tab[1].string_data());
+            assert_ne!(tab.string_data(), tab[0].string_data());
+
+            tab.select(10);
+            assert_eq!(*tab.selected(), 2);
+        }
+    }
+}
@@ -1,12 +1,10 @@
 use std::{
     cell::{
         Cell,
-        Ref,
         RefCell,
-        RefMut,
     },
+    iter,
     marker::PhantomData,
-    mem,
     ops::{
         Deref,
         DerefMut,
@@ -19,29 +17,46 @@ use console_display_macros::{
     StaticWidget,
 };
 use num_traits::NumCast;
+use unicode_width::UnicodeWidthChar;
 
 use crate::{
-    color::TerminalColor,
+    color::{
+        ARGBColor,
+        TerminalColor,
+    },
     console_display::DynamicConsoleDisplay,
     constraint,
-    drawing::DynamicCanvas,
+    drawing::{
+        ClippedCanvas,
+        DynamicCanvas,
+    },
     error::{
         COULD_NOT_CAST_X_COORD,
         COULD_NOT_CAST_Y_COORD,
+        DATA_DOES_NOT_MATCH_DIMENSIONS,
         DrawingError,
         WidgetError,
     },
     impl_getters,
     impl_new,
     impl_setters,
+    impl_single_child,
     or,
     pixel::{
         Pixel,
         character_pixel::CharacterPixel,
     },
     widget::{
+        DataCell,
+        Describe,
         DynamicWidget,
+        HitTest,
+        Region,
+        Render,
         StringData,
+        WidgetId,
+        describe_node,
+        resolve_hit,
     },
 };
 
@@ -63,6 +78,89 @@ pub const trait SingleWidget<T: DynamicWidget>:
     fn child_mut(&mut self) -> Self::BorrowedMut<'_>;
 }
 
+/// Defines how a [`UvWidget`] treats coordinates outside its uv mapping.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum UvWrapMode {
+    /// Uses the nearest pixel on the edge of the texture.
+    #[default]
+    Clamp,
+    /// Repeats the texture, wrapping coordinates modulo the texture size.
+    Wrap,
+    /// Returns an error.
+    Error,
+}
+
+/// Defines how a [`UvWidget`] reads pixels between the centers of texture pixels.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum Sampling {
+    /// Uses the nearest texture pixel.
+    #[default]
+    Nearest,
+    /// Mixes the four surrounding texture pixels, weighted by their distance.
+    /// Values that cannot be mixed fall back to the nearest texture pixel.
+    Bilinear,
+}
+
+/// Defines how a [`UvWidget`] distributes uv coordinates along an axis.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive_const(Default)]
+pub enum AxisScale {
+    /// Spaces uv coordinates evenly.
+    #[default]
+    Linear,
+    /// Spaces the decimal logarithms of uv coordinates evenly.
+    /// Non-positive coordinates are clamped to the smallest positive `f32`.
+    Log10,
+}
+
+impl AxisScale {
+    /// Maps a uv coordinate to the evenly spaced value of the axis.
+    #[must_use]
+    pub fn apply(self, uv: f32) -> f32 {
+        match self {
+            Self::Linear => uv,
+            Self::Log10 => uv.max(f32::MIN_POSITIVE).log10(),
+        }
+    }
+
+    /// Maps an evenly spaced value of the axis back to a uv coordinate.
+    #[must_use]
+    pub fn invert(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10f32.powf(value),
+        }
+    }
+}
+
+/// Mixes texture pixels for bilinear sampling.
+trait Interpolate: Sized {
+    fn interpolate(values: &[Self; 4], weights: &[f32; 4]) -> Self;
+}
+
+impl<T: Copy> Interpolate for T {
+    default fn interpolate(
+        values: &[Self; 4],
+        weights: &[f32; 4],
+    ) -> Self {
+        let mut nearest = 0;
+        for (index, weight) in weights.iter().enumerate() {
+            if *weight > weights[nearest] {
+                nearest = index;
+            }
+        }
+        values[nearest]
+    }
+}
+
+impl Interpolate for TerminalColor {
+    fn interpolate(values: &[Self; 4], weights: &[f32; 4]) -> Self {
+        Self::mix_weighted(values, weights)
+    }
+}
+
 #[derive(StaticWidget, DynamicWidget, Debug, Clone, PartialEq)]
 pub struct UvWidget<T: DynamicConsoleDisplay<S>, S: Pixel> {
     pixel_type: PhantomData<S>,
@@ -71,6 +169,10 @@ pub struct UvWidget<T: DynamicConsoleDisplay<S>, S: Pixel> {
     uv_x_max: f32,
     uv_y_min: f32,
     uv_y_max: f32,
+    wrap_mode: UvWrapMode,
+    sampling: Sampling,
+    axis_x_scale: AxisScale,
+    axis_y_scale: AxisScale,
 }
 
 impl<T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel> DynamicCanvas<S>
@@ -78,13 +180,12 @@ impl<T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel> DynamicCanvas<S>
 {
     type A = f32;
 
-    /// Gets the pixel at the _uv_ coordinate (x, y).
-    /// Using coordinates outside the uv mapping is considered
-    /// undefined behaviour at the moment and is subject to change.
+    /// Gets the pixel at the _uv_ coordinate (x, y), using the widget's [`Sampling`].
+    /// Coordinates outside the uv mapping are treated according to the [`UvWrapMode`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the pixel coordinates calculated by the UV mapping are out of bounds.
+    /// Returns an error if the coordinates are outside the uv mapping and the wrap mode is [`UvWrapMode::Error`].
     fn pixel(
         &self,
         x: Self::A,
@@ -94,31 +195,49 @@ impl<T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel> DynamicCanvas<S>
         [(); <S as Pixel>::WIDTH * <S as Pixel>::HEIGHT]:,
     {
         let display = self.child();
-        let uv = (
-            Self::uv_to_texture(
-                x,
-                self.uv_x_min,
-                self.uv_x_max,
-                display.width(),
-            ),
-            Self::uv_to_texture(
-                y,
-                self.uv_y_min,
-                self.uv_y_max,
-                display.width(),
-            ),
+        let texel = |x: usize, y: usize| {
+            display.pixel(
+                NumCast::from(x).expect(COULD_NOT_CAST_X_COORD),
+                NumCast::from(y).expect(COULD_NOT_CAST_Y_COORD),
+            )
+        };
+        if self.sampling == Sampling::Nearest {
+            let (x, y) = self.texture_coordinates(x, y)?;
+            return texel(x, y);
+        }
+        let (x, y) = self.texture_position(x, y)?;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x1, y1) = (
+            self.texture_index(x0 + 1., display.width()),
+            self.texture_index(y0 + 1., display.height()),
         );
-        display.pixel(
-            NumCast::from(uv.0).expect(COULD_NOT_CAST_X_COORD),
-            NumCast::from(uv.1).expect(COULD_NOT_CAST_Y_COORD),
-        )
+        let (x0, y0) = (
+            self.texture_index(x0, display.width()),
+            self.texture_index(y0, display.height()),
+        );
+        Ok(Interpolate::interpolate(
+            &[
+                texel(x0, y0)?,
+                texel(x1, y0)?,
+                texel(x0, y1)?,
+                texel(x1, y1)?,
+            ],
+            &[
+                (1. - fx) * (1. - fy),
+                fx * (1. - fy),
+                (1. - fx) * fy,
+                fx * fy,
+            ],
+        ))
     }
 
     /// Sets the pixel at the _uv_ coordinate (x, y).
+    /// Coordinates outside the uv mapping are treated according to the [`UvWrapMode`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the coordinates are outside the uv mapping.
+    /// Returns an error if the coordinates are outside the uv mapping and the wrap mode is [`UvWrapMode::Error`].
     fn set_pixel(
         &mut self,
         x: Self::A,
@@ -128,40 +247,16 @@ impl<T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel> DynamicCanvas<S>
     where
         [(); <S as Pixel>::WIDTH * <S as Pixel>::HEIGHT]:,
     {
-        let display = self.child();
-        // Note: Checks need to consider that uv_max < uv_min.
-        // While unintuitive, this is used to flip the uv mapping. (Especially with the y coordinate.)
-        if x < self.uv_x_min.min(self.uv_x_max) ||
-            x > self.uv_x_max.max(self.uv_x_min)
-        {
-            return Err(WidgetError::UvCoordinateOutOfBounds('x'))?;
-        }
-        if y < self.uv_y_min.min(self.uv_y_max) ||
-            y > self.uv_y_max.max(self.uv_y_min)
-        {
-            return Err(WidgetError::UvCoordinateOutOfBounds('y'))?;
-        }
-        let uv = (
-            Self::uv_to_texture(
-                x,
-                self.uv_x_min,
-                self.uv_x_max,
-                display.width(),
-            ),
-            Self::uv_to_texture(
-                y,
-                self.uv_y_min,
-                self.uv_y_max,
-                display.height(),
-            ),
-        );
+        let (x, y) = self.texture_coordinates(x, y)?;
         self.child_mut().set_pixel(
-            NumCast::from(uv.0).expect(COULD_NOT_CAST_X_COORD),
-            NumCast::from(uv.1).expect(COULD_NOT_CAST_Y_COORD),
+            NumCast::from(x).expect(COULD_NOT_CAST_X_COORD),
+            NumCast::from(y).expect(COULD_NOT_CAST_Y_COORD),
             value,
         )
     }
 
+    /// Draws the drawable given in _uv_ coordinates,
+    /// clipped to the texture pixels whose centers lie within the uv bounds.
     fn draw<D: crate::drawing::DynamicDrawable<N>, const N: usize>(
         &mut self,
         drawable: &D,
@@ -170,26 +265,36 @@ impl<T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel> DynamicCanvas<S>
         Self: Sized,
         [(); <S as Pixel>::WIDTH * <S as Pixel>::HEIGHT]:,
     {
+        let region = self.texture_region();
+        let (x_min, x_max) = self.scaled_x_bounds();
+        let (y_min, y_max) = self.scaled_y_bounds();
         let drawable = drawable.transform(|(x, y)| {
             (
                 Self::uv_to_texture_f32(
-                    x,
-                    self.uv_x_min,
-                    self.uv_x_max,
+                    self.axis_x_scale.apply(x),
+                    x_min,
+                    x_max,
                     self.width() as f32,
                 ),
                 Self::uv_to_texture_f32(
-                    y,
-                    self.uv_y_min,
-                    self.uv_y_max,
+                    self.axis_y_scale.apply(y),
+                    y_min,
+                    y_max,
                     self.height() as f32,
                 ),
             )
         });
-        drawable.draw(self.child_mut(), value);
+        drawable.draw::<_, S>(
+            &mut ClippedCanvas::new(self.child_mut(), region),
+            value,
+        );
     }
 }
 
+impl_single_child!(
+    describe [T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel] UvWidget<T, S>
+);
+
 impl<T: DynamicConsoleDisplay<S>, S: Pixel> UvWidget<T, S> {
     pub fn new(child: T) -> Self {
         let (width, height) = (child.width(), child.height());
@@ -200,50 +305,96 @@ impl<T: DynamicConsoleDisplay<S>, S: Pixel> UvWidget<T, S> {
             uv_x_max: width as f32,
             uv_y_min: 0.0,
             uv_y_max: height as f32,
+            wrap_mode: UvWrapMode::default(),
+            sampling: Sampling::default(),
+            axis_x_scale: AxisScale::default(),
+            axis_y_scale: AxisScale::default(),
         }
     }
 }
 
 impl<S: Pixel, T: DynamicConsoleDisplay<S> + StaticWidget> UvWidget<T, S> {
-    impl_setters!(pub const uv_x_min: f32, pub const uv_x_max: f32, pub const uv_y_min: f32, pub const uv_y_max: f32);
+    impl_setters!(pub const uv_x_min: f32, pub const uv_x_max: f32, pub const uv_y_min: f32, pub const uv_y_max: f32, pub const wrap_mode: UvWrapMode, pub const sampling: Sampling, pub const axis_x_scale: AxisScale, pub const axis_y_scale: AxisScale);
+    impl_getters!(pub const wrap_mode: UvWrapMode, pub const sampling: Sampling, pub const axis_x_scale: AxisScale, pub const axis_y_scale: AxisScale);
 
     #[must_use]
     pub fn uv_x_to_texture(&self, x: f32) -> usize {
+        let (x_min, x_max) = self.scaled_x_bounds();
         Self::uv_to_texture(
-            x,
-            self.uv_x_min,
-            self.uv_x_max,
+            self.axis_x_scale.apply(x),
+            x_min,
+            x_max,
             self.child().width(),
         )
     }
 
     #[must_use]
     pub fn uv_y_to_texture(&self, y: f32) -> usize {
+        let (y_min, y_max) = self.scaled_y_bounds();
         Self::uv_to_texture(
-            y,
-            self.uv_y_min,
-            self.uv_y_max,
+            self.axis_y_scale.apply(y),
+            y_min,
+            y_max,
             self.child().height(),
         )
     }
 
     #[must_use]
     pub fn texture_to_uv_x(&self, x: usize) -> f32 {
-        Self::texture_to_uv(
+        let (x_min, x_max) = self.scaled_x_bounds();
+        self.axis_x_scale.invert(Self::texture_to_uv(
             x,
             self.child().width(),
-            self.uv_x_min,
-            self.uv_x_max,
-        )
+            x_min,
+            x_max,
+        ))
     }
 
     #[must_use]
     pub fn texture_to_uv_y(&self, y: usize) -> f32 {
-        Self::texture_to_uv(
+        let (y_min, y_max) = self.scaled_y_bounds();
+        self.axis_y_scale.invert(Self::texture_to_uv(
             y,
             self.child().height(),
-            self.uv_y_min,
-            self.uv_y_max,
+            y_min,
+            y_max,
+        ))
+    }
+
+    /// Returns the rectangle of texture pixels whose centers lie within the uv bounds.
+    fn texture_region(&self) -> Region {
+        let within = |uv: f32, min: f32, max: f32| {
+            (min.min(max)..=max.max(min)).contains(&uv)
+        };
+        let span = |size: usize, in_bounds: &dyn Fn(usize) -> bool| {
+            let start = (0..size).position(in_bounds).unwrap_or(size);
+            let end = (0..size)
+                .rposition(in_bounds)
+                .map_or(start, |end| end + 1);
+            (start, end - start)
+        };
+        let (x, width) = span(self.child().width(), &|x| {
+            within(self.texture_to_uv_x(x), self.uv_x_min, self.uv_x_max)
+        });
+        let (y, height) = span(self.child().height(), &|y| {
+            within(self.texture_to_uv_y(y), self.uv_y_min, self.uv_y_max)
+        });
+        Region::new(x, y, width, height)
+    }
+
+    /// Returns the uv bounds of the x axis mapped by its scale.
+    fn scaled_x_bounds(&self) -> (f32, f32) {
+        (
+            self.axis_x_scale.apply(self.uv_x_min),
+            self.axis_x_scale.apply(self.uv_x_max),
+        )
+    }
+
+    /// Returns the uv bounds of the y axis mapped by its scale.
+    fn scaled_y_bounds(&self) -> (f32, f32) {
+        (
+            self.axis_y_scale.apply(self.uv_y_min),
+            self.axis_y_scale.apply(self.uv_y_max),
         )
     }
 
@@ -271,6 +422,94 @@ impl<S: Pixel, T: DynamicConsoleDisplay<S> + StaticWidget> UvWidget<T, S> {
             .round()
     }
 
+    /// Maps the uv coordinate (x, y) to fractional texture coordinates,
+    /// with pixel centers at whole numbers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coordinates are outside the uv mapping and the wrap mode is [`UvWrapMode::Error`].
+    fn texture_position(
+        &self,
+        x: f32,
+        y: f32,
+    ) -> Result<(f32, f32), WidgetError> {
+        let display = self.child();
+        Ok((
+            self.axis_position(
+                x,
+                self.uv_x_min,
+                self.uv_x_max,
+                self.axis_x_scale,
+                display.width(),
+                'x',
+            )?,
+            self.axis_position(
+                y,
+                self.uv_y_min,
+                self.uv_y_max,
+                self.axis_y_scale,
+                display.height(),
+                'y',
+            )?,
+        ))
+    }
+
+    /// Maps the uv coordinate (x, y) to the nearest texture coordinates,
+    /// applying the wrap mode to coordinates outside the uv mapping.
+    fn texture_coordinates(
+        &self,
+        x: f32,
+        y: f32,
+    ) -> Result<(usize, usize), WidgetError> {
+        let (x, y) = self.texture_position(x, y)?;
+        let display = self.child();
+        Ok((
+            self.texture_index(x.round(), display.width()),
+            self.texture_index(y.round(), display.height()),
+        ))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn axis_position(
+        &self,
+        uv: f32,
+        uv_min: f32,
+        uv_max: f32,
+        scale: AxisScale,
+        texture_coordinate_max: usize,
+        axis: char,
+    ) -> Result<f32, WidgetError> {
+        // Note: Checks need to consider that uv_max < uv_min.
+        // While unintuitive, this is used to flip the uv mapping. (Especially with the y coordinate.)
+        if self.wrap_mode == UvWrapMode::Error &&
+            (uv < uv_min.min(uv_max) || uv > uv_max.max(uv_min))
+        {
+            return Err(WidgetError::UvCoordinateOutOfBounds(axis));
+        }
+        let (uv, uv_min, uv_max) =
+            (scale.apply(uv), scale.apply(uv_min), scale.apply(uv_max));
+        Ok(((uv - uv_min) / (uv_max - uv_min))
+            .mul_add(texture_coordinate_max as f32, -0.5))
+    }
+
+    /// Applies the wrap mode to a whole texture coordinate.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    fn texture_index(
+        &self,
+        texture: f32,
+        texture_coordinate_max: usize,
+    ) -> usize {
+        let size = texture_coordinate_max as f32;
+        let texture = match self.wrap_mode {
+            UvWrapMode::Wrap => texture.rem_euclid(size),
+            UvWrapMode::Clamp | UvWrapMode::Error => texture,
+        };
+        // Rounding can place the upper edge of the mapping one pixel past the texture.
+        texture.clamp(0., (size - 1.).max(0.)) as usize
+    }
+
     fn texture_to_uv(
         texture_coordinate: usize,
         texture_coordinate_max: usize,
@@ -384,34 +623,48 @@ impl<T: DynamicConsoleDisplay<S>, S: Pixel> const DerefMut
     }
 }
 
+/// Holds two copies of a display.
+/// Reading (through `Deref`) uses the front buffer,
+/// while writing (through `DerefMut`) uses the back buffer.
+/// Rendering presents the back buffer if it was written to since the last swap,
+/// so the front buffer stays unchanged until the next frame.
 #[derive(StaticWidget, Debug, Clone, PartialEq, Eq)]
 pub struct DoubleBufferWidget<T: DynamicConsoleDisplay<S>, S: Pixel> {
     pixel_type: PhantomData<S>,
-    child: RefCell<T>,
-    backbuffer: RefCell<Box<[S]>>,
+    buffers: [T; 2],
+    is_swapped: Cell<bool>,
     is_write: Cell<bool>,
 }
 
 impl<T: DynamicConsoleDisplay<S>, S: Pixel> DoubleBufferWidget<T, S> {
     pub fn new(child: T) -> Self
     where
-        [(); S::WIDTH * S::HEIGHT]:,
+        T: Clone,
     {
-        let pixels = child.data().to_vec().into_boxed_slice();
         Self {
             pixel_type: PhantomData::<S>,
-            child: RefCell::new(child),
-            backbuffer: RefCell::new(pixels),
+            buffers: [child.clone(), child],
+            is_swapped: false.into(),
             is_write: false.into(),
         }
     }
 
-    #[allow(clippy::swap_with_temporary)]
+    /// Exchanges the front and back buffer.
     pub fn swap_buffers(&self) {
-        mem::swap(
-            self.child.borrow_mut().data_mut(),
-            &mut self.backbuffer.borrow_mut(),
-        );
+        self.is_swapped.set(!self.is_swapped.get());
+        self.is_write.set(false);
+    }
+
+    const fn front_index(&self) -> usize {
+        self.is_swapped.get() as usize
+    }
+
+    /// Swaps the buffers if the back buffer was written to and returns the front buffer.
+    fn present(&self) -> &T {
+        if self.is_write.get() {
+            self.swap_buffers();
+        }
+        &self.buffers[self.front_index()]
     }
 }
 
@@ -419,19 +672,31 @@ impl<T: DynamicConsoleDisplay<S>, S: Pixel> DynamicWidget
     for DoubleBufferWidget<T, S>
 {
     fn width_characters(&self) -> usize {
-        self.child.borrow().width_characters()
+        self.buffers[0].width_characters()
     }
 
     fn height_characters(&self) -> usize {
-        self.child.borrow().height_characters()
+        self.buffers[0].height_characters()
     }
 
     fn string_data(&self) -> StringData {
-        if self.is_write.get() {
-            self.swap_buffers();
-            self.is_write.set(false);
-        }
-        self.child.borrow().string_data()
+        self.present().string_data()
+    }
+}
+
+impl<T: DynamicConsoleDisplay<S>, S: Pixel> Render
+    for DoubleBufferWidget<T, S>
+{
+    fn render_into(&self, buf: &mut StringData) {
+        self.present().render_into(buf);
+    }
+}
+
+impl<T: DynamicConsoleDisplay<S>, S: Pixel> Describe
+    for DoubleBufferWidget<T, S>
+{
+    fn describe(&self) -> String {
+        describe_node(self, &[self.child().describe()])
     }
 }
 
@@ -439,50 +704,44 @@ impl<T: DynamicConsoleDisplay<S>, S: Pixel> const SingleWidget<T>
     for DoubleBufferWidget<T, S>
 {
     type Borrowed<'a>
-        = Ref<'a, T>
+        = &'a T
     where
         T: 'a,
         Self: 'a;
 
     type BorrowedMut<'a>
-        = RefMut<'a, T>
+        = &'a mut T
     where
         T: 'a,
         Self: 'a;
 
-    fn child(&self) -> Ref<'_, T> {
-        self.child.borrow()
+    /// Returns the front buffer.
+    fn child(&self) -> &T {
+        &self.buffers[self.front_index()]
     }
 
-    fn child_mut(&mut self) -> RefMut<'_, T> {
-        self.child.borrow_mut()
+    /// Returns the back buffer.
+    fn child_mut(&mut self) -> &mut T {
+        *self.is_write.get_mut() = true;
+        &mut self.buffers[1 - self.front_index()]
     }
 }
 
-impl<T: DynamicConsoleDisplay<S>, S: Pixel> Deref
+impl<T: DynamicConsoleDisplay<S>, S: Pixel> const Deref
     for DoubleBufferWidget<T, S>
 {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        if self.is_write.get() {
-            self.swap_buffers();
-            self.is_write.set(false);
-        }
-        // TODO: Make this implementation safe
-        unsafe { &*self.child.as_ptr() }
+        self.child()
     }
 }
 
-impl<T: DynamicConsoleDisplay<S>, S: Pixel> DerefMut
+impl<T: DynamicConsoleDisplay<S>, S: Pixel> const DerefMut
     for DoubleBufferWidget<T, S>
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        if !self.is_write.get() {
-            self.swap_buffers();
-            self.is_write.set(true);
-        }
-        self.child.get_mut()
+        self.child_mut()
     }
 }
 
@@ -493,12 +752,30 @@ pub struct PaddingWidget<T: DynamicWidget> {
     padding_right: usize,
     padding_top: usize,
     padding_bottom: usize,
+    fill: CharacterPixel,
 }
 
 impl<T: DynamicWidget> PaddingWidget<T> {
-    impl_new!(pub const PaddingWidget<T>, child: T, padding_left: usize, padding_right: usize, padding_top: usize, padding_bottom: usize);
+    /// Creates a widget surrounding the child with the given padding.
+    /// The padding is filled with blank characters in the default colors.
+    pub const fn new(
+        child: T,
+        padding_left: usize,
+        padding_right: usize,
+        padding_top: usize,
+        padding_bottom: usize,
+    ) -> Self {
+        Self {
+            child,
+            padding_left,
+            padding_right,
+            padding_top,
+            padding_bottom,
+            fill: CharacterPixel::default(),
+        }
+    }
 
-    impl_setters!(pub const padding_left: usize, pub const padding_right: usize, pub const padding_top: usize, pub const padding_bottom: usize);
+    impl_setters!(pub const padding_left: usize, pub const padding_right: usize, pub const padding_top: usize, pub const padding_bottom: usize, pub const fill: CharacterPixel);
 }
 
 impl<T: DynamicWidget> DynamicWidget for PaddingWidget<T> {
@@ -516,33 +793,23 @@ impl<T: DynamicWidget> DynamicWidget for PaddingWidget<T> {
 
     fn string_data(&self) -> StringData {
         let mut data = self.child.string_data().data;
-        let padding_top = vec![
+        let padding_top =
             vec![
-                CharacterPixel::default().into();
-                self.width_characters()
+                vec![self.fill.into(); self.width_characters()];
+                self.padding_top
             ];
-            self.padding_top
-        ];
-        let padding_bottom = vec![
+        let padding_bottom =
             vec![
-                CharacterPixel::default().into();
-                self.width_characters()
+                vec![self.fill.into(); self.width_characters()];
+                self.padding_bottom
             ];
-            self.padding_bottom
-        ];
         data = data
             .into_iter()
             .map(|line| {
                 [
-                    vec![
-                        CharacterPixel::default().into();
-                        self.padding_left
-                    ],
+                    vec![self.fill.into(); self.padding_left],
                     line,
-                    vec![
-                        CharacterPixel::default().into();
-                        self.padding_right
-                    ],
+                    vec![self.fill.into(); self.padding_right],
                 ]
                 .concat()
             })
@@ -553,6 +820,40 @@ impl<T: DynamicWidget> DynamicWidget for PaddingWidget<T> {
     }
 }
 
+impl<T: DynamicWidget> Render for PaddingWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        let fill: DataCell = self.fill.into();
+        let width = self.width_characters();
+        // The rows of the previous frame are split up, so each part reuses its rows.
+        let mut child = StringData {
+            data: buf.data.split_off(self.padding_top.min(buf.data.len())),
+        };
+        let mut bottom = StringData {
+            data: child.data.split_off(
+                self.child.height_characters().min(child.data.len()),
+            ),
+        };
+        self.child.render_into(&mut child);
+        for row in &mut child.data {
+            row.splice(0..0, iter::repeat_n(fill, self.padding_left));
+            row.extend(iter::repeat_n(fill, self.padding_right));
+        }
+        buf.data.resize_with(self.padding_top, Vec::new);
+        bottom.data.resize_with(self.padding_bottom, Vec::new);
+        for row in buf.data.iter_mut().chain(&mut bottom.data) {
+            row.clear();
+            row.resize(width, fill);
+        }
+        buf.data.append(&mut child.data);
+        buf.data.append(&mut bottom.data);
+    }
+}
+
+impl_single_child!(
+    [T: DynamicWidget] PaddingWidget<T>,
+    offset: |widget: &Self| (widget.padding_left, widget.padding_top)
+);
+
 impl<T: DynamicWidget> const Deref for PaddingWidget<T> {
     type Target = T;
 
@@ -941,6 +1242,11 @@ impl<T: DynamicWidget, S: Border> DynamicWidget for BorderWidget<T, S> {
     }
 }
 
+impl_single_child!(
+    [T: DynamicWidget, S: Border] BorderWidget<T, S>,
+    offset: |widget: &Self| (widget.border.width_left(), widget.border.width_top())
+);
+
 impl<T: DynamicWidget, S: Border> const Deref for BorderWidget<T, S> {
     type Target = T;
 
@@ -1007,6 +1313,27 @@ impl<T: DynamicWidget> DynamicWidget for InsetWidget<T> {
     }
 }
 
+impl<T: DynamicWidget> HitTest for InsetWidget<T> {
+    /// The child covers the whole widget, with its cropped parts hidden.
+    fn child_regions(&self) -> Vec<Region> {
+        vec![Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |_, x, y| {
+            self.child
+                .widget_at(x + self.inset_left, y + self.inset_top)
+        })
+    }
+}
+
+impl_single_child!(describe [T: DynamicWidget] InsetWidget<T>);
+
 impl<T: DynamicWidget> const Deref for InsetWidget<T> {
     type Target = T;
 
@@ -1021,77 +1348,1961 @@ impl<T: DynamicWidget> const DerefMut for InsetWidget<T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        pixel::monochrome_pixel::SinglePixel,
-        pixel_display::StaticPixelDisplay,
-    };
+/// Wraps a color display and fades its pixels toward a background color on every `step`,
+/// so that anything drawn leaves a fading motion trail.
+#[derive(StaticWidget, DynamicWidget, Debug, Clone, PartialEq)]
+pub struct TrailWidget<
+    T: DynamicConsoleDisplay<S>,
+    S: Pixel<U = TerminalColor>,
+> {
+    pixel_type: PhantomData<S>,
+    child: T,
+    background: TerminalColor,
+    fade: f32,
+}
 
-    use super::*;
+impl<T: DynamicConsoleDisplay<S>, S: Pixel<U = TerminalColor>>
+    TrailWidget<T, S>
+{
+    /// Creates a trail widget that fades by the given fraction per step.
+    /// The fraction is clamped to the range from 0 to 1.
+    /// A fade of 0 keeps the pixels unchanged, a fade of 1 clears them to the background in one step.
+    pub const fn new(
+        child: T,
+        background: TerminalColor,
+        fade: f32,
+    ) -> Self {
+        Self {
+            pixel_type: PhantomData::<S>,
+            child,
+            background,
+            fade: fade.clamp(0., 1.),
+        }
+    }
 
-    mod uv_widget {
-        use super::*;
+    impl_getters!(pub const background: TerminalColor, pub const fade: f32);
 
-        #[test]
-        fn texture_to_uv() {
-            let expected = 0.0005;
-            let actual = UvWidget::<
-                StaticPixelDisplay<SinglePixel, 1, 1>,
-                SinglePixel,
-            >::texture_to_uv(500, 1000, -0.5, 0.5);
-            let error = expected * 0.0001;
-            assert!((actual - 0.0005).abs() < error);
-        }
+    impl_setters!(pub const background: TerminalColor);
 
-        #[test]
-        fn uv_to_texture() {
-            let expected = 1500;
-            let actual = UvWidget::<
-                StaticPixelDisplay<SinglePixel, 1, 1>,
-                SinglePixel,
-            >::uv_to_texture(0.5, -1.0, 1.0, 2000);
-            assert_eq!(actual, expected);
-        }
+    /// Sets the fraction by which pixels fade per step.
+    /// The fraction is clamped to the range from 0 to 1.
+    pub const fn set_fade(&mut self, fade: f32) {
+        self.fade = fade.clamp(0., 1.);
     }
 
-    mod double_buffer_widget {
-        use crate::console_display::StaticConsoleDisplay;
+    /// Fades all pixels toward the background color.
+    /// Should be called once per frame before drawing new content.
+    /// If the background is the terminal default, pixels fade toward transparency instead.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index of a pixel is out of bounds.
+    /// This should not happen and is subject to change in the future.
+    pub fn step(&mut self)
+    where
+        [(); S::WIDTH * S::HEIGHT]:,
+    {
+        let pixels = self
+            .child
+            .pixels()
+            .into_iter()
+            .map(|color| {
+                Self::fade_toward(color, self.background, self.fade)
+            })
+            .collect::<Vec<_>>();
+        self.child
+            .set_pixels(&pixels)
+            .expect(DATA_DOES_NOT_MATCH_DIMENSIONS);
+    }
 
-        use super::*;
+    fn fade_toward(
+        color: TerminalColor,
+        target: TerminalColor,
+        fade: f32,
+    ) -> TerminalColor {
+        let TerminalColor::ARGBColor(color) = color
+        else {
+            return color;
+        };
+        let target = match target {
+            TerminalColor::Default => ARGBColor {
+                opacity: 0,
+                color: color.color,
+            },
+            TerminalColor::ARGBColor(target) => target,
+        };
+        ARGBColor::lerp(&color, &target, fade).into()
+    }
+}
 
-        #[test]
-        fn buffer_swap() {
-            let mut widget =
-                DoubleBufferWidget::new(StaticPixelDisplay::<
-                    SinglePixel,
-                    1,
-                    1,
-                >::new(false));
-            widget.set_pixel_static::<0, 0>(true);
-            let buffer1 = widget.backbuffer.clone();
-            widget.swap_buffers();
-            let buffer2 = widget.backbuffer;
-            assert_ne!(
-                buffer1.borrow()[0].character().to_string(),
-                buffer2.borrow()[0].character().to_string()
-            );
-        }
+impl_single_child!(
+    describe [T: DynamicConsoleDisplay<S> + StaticWidget, S: Pixel<U = TerminalColor>] TrailWidget<T, S>
+);
+
+impl<T: DynamicConsoleDisplay<S>, S: Pixel<U = TerminalColor>> const Deref
+    for TrailWidget<T, S>
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
     }
-    mod padding_widget {
-        use super::*;
+}
 
-        #[test]
-        fn dimensions() {
-            let widget = PaddingWidget::new(
-                StaticPixelDisplay::<SinglePixel, 1, 1>::new(false),
-                10,
-                20,
-                30,
-                40,
-            );
-            assert_eq!(widget.width_characters(), 31);
+impl<T: DynamicConsoleDisplay<S>, S: Pixel<U = TerminalColor>> const
+    DerefMut for TrailWidget<T, S>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Renders the child in grayscale by mapping all colors to grays of the same luma.
+#[derive(SingleWidget, StaticWidget, Debug, Clone, PartialEq, Eq)]
+pub struct GrayscaleWidget<T: DynamicWidget> {
+    child: T,
+}
+
+impl<T: DynamicWidget> GrayscaleWidget<T> {
+    impl_new!(pub const GrayscaleWidget<T>, child: T);
+}
+
+impl<T: DynamicWidget> DynamicWidget for GrayscaleWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for GrayscaleWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        self.child.render_into(buf);
+        buf.map_colors_in_place(|color| color.to_grayscale());
+    }
+}
+
+impl_single_child!([T: DynamicWidget] GrayscaleWidget<T>);
+
+impl<T: DynamicWidget> const Deref for GrayscaleWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for GrayscaleWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Shows a window of fixed size into a possibly larger child.
+/// The window can be moved with `scroll_by` and `scroll_to`.
+/// If the child is smaller than the viewport, the widget shrinks to the size of the child.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollWidget<T: DynamicWidget> {
+    child: T,
+    offset_x: usize,
+    offset_y: usize,
+    viewport_width: usize,
+    viewport_height: usize,
+}
+
+impl<T: DynamicWidget> ScrollWidget<T> {
+    /// Creates a scroll widget showing the top left corner of the child.
+    pub const fn new(
+        child: T,
+        viewport_width: usize,
+        viewport_height: usize,
+    ) -> Self {
+        Self {
+            child,
+            offset_x: 0,
+            offset_y: 0,
+            viewport_width,
+            viewport_height,
+        }
+    }
+
+    impl_getters!(
+        pub const child: T,
+        pub const offset_x: usize,
+        pub const offset_y: usize,
+        pub const viewport_width: usize,
+        pub const viewport_height: usize
+    );
+
+    impl_setters!(
+        pub const viewport_width: usize,
+        pub const viewport_height: usize
+    );
+
+    /// Moves the viewport by the given number of characters.
+    /// The offset is clamped so the viewport stays within the child.
+    pub fn scroll_by(&mut self, dx: isize, dy: isize) {
+        self.scroll_to(
+            self.offset_x.saturating_add_signed(dx),
+            self.offset_y.saturating_add_signed(dy),
+        );
+    }
+
+    /// Moves the top left corner of the viewport to the given position in the child.
+    /// The offset is clamped so the viewport stays within the child.
+    pub fn scroll_to(&mut self, x: usize, y: usize) {
+        self.offset_x = x.min(self.max_offset_x());
+        self.offset_y = y.min(self.max_offset_y());
+    }
+
+    fn max_offset_x(&self) -> usize {
+        self.child
+            .width_characters()
+            .saturating_sub(self.viewport_width)
+    }
+
+    fn max_offset_y(&self) -> usize {
+        self.child
+            .height_characters()
+            .saturating_sub(self.viewport_height)
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for ScrollWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.viewport_width.min(self.child.width_characters())
+    }
+
+    fn height_characters(&self) -> usize {
+        self.viewport_height.min(self.child.height_characters())
+    }
+
+    fn string_data(&self) -> StringData {
+        // The child may have shrunk since the last scroll.
+        let offset_x = self.offset_x.min(self.max_offset_x());
+        let offset_y = self.offset_y.min(self.max_offset_y());
+
+        let mut data = StringData {
+            data: self
+                .child
+                .string_data()
+                .data
+                .into_iter()
+                .skip(offset_y)
+                .map(|line| skip_columns(line, offset_x))
+                .collect(),
+        };
+        data.fit(self.width_characters(), self.height_characters());
+        data
+    }
+}
+
+/// Drops the first `columns` columns of the row.
+/// Wide characters cut by the left edge are replaced by spaces in their colors.
+fn skip_columns(line: Vec<DataCell>, columns: usize) -> Vec<DataCell> {
+    let mut start = 0;
+    let mut cells = Vec::with_capacity(line.len());
+    for cell in line {
+        let width = UnicodeWidthChar::width(cell.character).unwrap_or(0);
+        if start >= columns {
+            cells.push(cell);
+        }
+        else if start + width > columns {
+            cells.extend(iter::repeat_n(
+                DataCell {
+                    character: ' ',
+                    ..cell
+                },
+                start + width - columns,
+            ));
+        }
+        start += width;
+    }
+    cells
+}
+
+impl<T: DynamicWidget> HitTest for ScrollWidget<T> {
+    /// The child covers the whole widget, with the parts outside the viewport hidden.
+    fn child_regions(&self) -> Vec<Region> {
+        vec![Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        let offset_x = self.offset_x.min(self.max_offset_x());
+        let offset_y = self.offset_y.min(self.max_offset_y());
+        resolve_hit(self, column, row, |_, x, y| {
+            self.child.widget_at(x + offset_x, y + offset_y)
+        })
+    }
+}
+
+impl_single_child!(describe [T: DynamicWidget] ScrollWidget<T>);
+
+impl<T: DynamicWidget> const Deref for ScrollWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for ScrollWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Places `data` at the given offset in an area of the given size.
+/// The surrounding space is filled with [`DataCell::default`].
+fn place(
+    data: StringData,
+    left: usize,
+    top: usize,
+    width: usize,
+    height: usize,
+) -> StringData {
+    let data_height = data.data.len();
+    let fill = DataCell::default();
+    let lines = data.data.into_iter().map(|line| {
+        let columns: usize = line
+            .iter()
+            .map(|cell| {
+                UnicodeWidthChar::width(cell.character).unwrap_or(0)
+            })
+            .sum();
+        let right = width.saturating_sub(left + columns);
+        [vec![fill; left], line, vec![fill; right]].concat()
+    });
+    StringData {
+        data: vec![vec![fill; width]; top]
+            .into_iter()
+            .chain(lines)
+            .chain(vec![vec![fill; width]; height - data_height - top])
+            .collect(),
+    }
+}
+
+/// Centers the child in an area of the given size.
+/// The surrounding space is filled with [`DataCell::default`].
+/// If the space cannot be split evenly, the child is placed one character closer to the top left.
+/// If the child is larger than the area, the widget grows to the size of the child.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CenterWidget<T: DynamicWidget> {
+    child: T,
+    width: usize,
+    height: usize,
+}
+
+impl<T: DynamicWidget> CenterWidget<T> {
+    impl_new!(pub const CenterWidget<T>, child: T, width: usize, height: usize);
+
+    impl_getters!(pub const child: T, pub const width: usize, pub const height: usize);
+
+    impl_setters!(pub const width: usize, pub const height: usize);
+
+    /// Returns the offset of the child as (left, top).
+    fn offset(&self) -> (usize, usize) {
+        (
+            (self.width_characters() - self.child.width_characters()) / 2,
+            (self.height_characters() - self.child.height_characters()) /
+                2,
+        )
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for CenterWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.width.max(self.child.width_characters())
+    }
+
+    fn height_characters(&self) -> usize {
+        self.height.max(self.child.height_characters())
+    }
+
+    fn string_data(&self) -> StringData {
+        let (left, top) = self.offset();
+        place(
+            self.child.string_data(),
+            left,
+            top,
+            self.width_characters(),
+            self.height_characters(),
+        )
+    }
+}
+
+impl_single_child!([T: DynamicWidget] CenterWidget<T>, offset: Self::offset);
+
+impl<T: DynamicWidget> const Deref for CenterWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for CenterWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Horizontal position of a child within a larger area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HorizontalAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl HorizontalAlign {
+    /// Returns the offset of the child given the free space around it.
+    /// Centering puts the extra column of uneven space on the right.
+    pub(crate) const fn offset(self, space: usize) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Center => space / 2,
+            Self::Right => space,
+        }
+    }
+}
+
+/// Vertical position of a child within a larger area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VerticalAlign {
+    /// Returns the offset of the child given the free space around it.
+    /// Centering puts the extra row of uneven space at the bottom.
+    pub(crate) const fn offset(self, space: usize) -> usize {
+        match self {
+            Self::Top => 0,
+            Self::Center => space / 2,
+            Self::Bottom => space,
+        }
+    }
+}
+
+/// Positions the child in an area of the given size according to the alignments.
+/// The surrounding space is filled with [`DataCell::default`].
+/// If the child is larger than the area, the widget grows to the size of the child.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlignWidget<T: DynamicWidget> {
+    child: T,
+    width: usize,
+    height: usize,
+    horizontal_align: HorizontalAlign,
+    vertical_align: VerticalAlign,
+}
+
+impl<T: DynamicWidget> AlignWidget<T> {
+    impl_new!(pub const AlignWidget<T>, child: T, width: usize, height: usize, horizontal_align: HorizontalAlign, vertical_align: VerticalAlign);
+
+    impl_getters!(pub const child: T, pub const width: usize, pub const height: usize, pub const horizontal_align: HorizontalAlign, pub const vertical_align: VerticalAlign);
+
+    impl_setters!(pub const width: usize, pub const height: usize, pub const horizontal_align: HorizontalAlign, pub const vertical_align: VerticalAlign);
+
+    /// Returns the offset of the child as (left, top).
+    fn offset(&self) -> (usize, usize) {
+        (
+            self.horizontal_align.offset(
+                self.width_characters() - self.child.width_characters(),
+            ),
+            self.vertical_align.offset(
+                self.height_characters() - self.child.height_characters(),
+            ),
+        )
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for AlignWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.width.max(self.child.width_characters())
+    }
+
+    fn height_characters(&self) -> usize {
+        self.height.max(self.child.height_characters())
+    }
+
+    fn string_data(&self) -> StringData {
+        let (left, top) = self.offset();
+        place(
+            self.child.string_data(),
+            left,
+            top,
+            self.width_characters(),
+            self.height_characters(),
+        )
+    }
+}
+
+impl_single_child!([T: DynamicWidget] AlignWidget<T>, offset: Self::offset);
+
+impl<T: DynamicWidget> const Deref for AlignWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for AlignWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Scales the opacity of all colors of the child, which fades it out over whatever is below.
+/// Default colors are left untouched.
+#[derive(SingleWidget, StaticWidget, Debug, Clone, PartialEq, Eq)]
+pub struct OpacityWidget<T: DynamicWidget> {
+    child: T,
+    opacity: u8,
+}
+
+impl<T: DynamicWidget> OpacityWidget<T> {
+    /// Creates an opacity widget.
+    /// An opacity of 255 leaves the child unchanged, 0 makes it fully transparent.
+    pub const fn new(child: T, opacity: u8) -> Self {
+        Self { child, opacity }
+    }
+
+    impl_getters!(pub const opacity: u8);
+
+    impl_setters!(pub const opacity: u8);
+}
+
+impl<T: DynamicWidget> DynamicWidget for OpacityWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for OpacityWidget<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn render_into(&self, buf: &mut StringData) {
+        self.child.render_into(buf);
+        buf.map_colors_in_place(|color| match color {
+            TerminalColor::Default => TerminalColor::Default,
+            TerminalColor::ARGBColor(color) => {
+                TerminalColor::ARGBColor(ARGBColor {
+                    // The product divided by 255 always fits into a u8.
+                    opacity: ((u16::from(color.opacity) *
+                        u16::from(self.opacity) +
+                        127) /
+                        255) as u8,
+                    color: color.color,
+                })
+            }
+        });
+    }
+}
+
+impl_single_child!([T: DynamicWidget] OpacityWidget<T>);
+
+impl<T: DynamicWidget> const Deref for OpacityWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for OpacityWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Tints all colors of the child toward a single color.
+/// Default colors are left untouched, as is everything if the tint is the default color.
+#[derive(SingleWidget, StaticWidget, Debug, Clone, PartialEq)]
+pub struct TintWidget<T: DynamicWidget> {
+    child: T,
+    tint: TerminalColor,
+    factor: f32,
+}
+
+impl<T: DynamicWidget> TintWidget<T> {
+    /// Creates a tint widget with the given blend factor.
+    /// The factor is clamped to the range from 0 to 1.
+    /// A factor of 0 keeps the child unchanged, a factor of 1 replaces all colors with the tint.
+    pub const fn new(child: T, tint: TerminalColor, factor: f32) -> Self {
+        Self {
+            child,
+            tint,
+            factor: factor.clamp(0., 1.),
+        }
+    }
+
+    impl_getters!(pub const tint: TerminalColor, pub const factor: f32);
+
+    impl_setters!(pub const tint: TerminalColor);
+
+    /// Sets the blend factor.
+    /// The factor is clamped to the range from 0 to 1.
+    pub const fn set_factor(&mut self, factor: f32) {
+        self.factor = factor.clamp(0., 1.);
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for TintWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for TintWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        self.child.render_into(buf);
+        buf.map_colors_in_place(|color| {
+            if color == TerminalColor::Default ||
+                self.tint == TerminalColor::Default
+            {
+                return color;
+            }
+            TerminalColor::mix_weighted(
+                &[color, self.tint],
+                &[1. - self.factor, self.factor],
+            )
+        });
+    }
+}
+
+impl_single_child!([T: DynamicWidget] TintWidget<T>);
+
+impl<T: DynamicWidget> const Deref for TintWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for TintWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Caches the string data of the child, so it is only computed once until invalidated.
+/// Useful for widgets that rarely change, like static backgrounds.
+///
+/// The cache is not updated automatically.
+/// `invalidate` has to be called after mutating the child, otherwise the old data keeps being rendered.
+#[derive(StaticWidget, Debug, Clone, PartialEq, Eq)]
+pub struct CacheWidget<T: DynamicWidget> {
+    child: T,
+    cache: RefCell<Option<StringData>>,
+}
+
+impl<T: DynamicWidget> CacheWidget<T> {
+    pub const fn new(child: T) -> Self {
+        Self {
+            child,
+            cache: RefCell::new(None),
+        }
+    }
+
+    impl_getters!(pub const child: T);
+
+    /// Discards the cached data, so it is recomputed the next time it is needed.
+    pub fn invalidate(&mut self) {
+        self.cache.get_mut().take();
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for CacheWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        self.cache
+            .borrow_mut()
+            .get_or_insert_with(|| self.child.string_data())
+            .clone()
+    }
+}
+
+impl<T: DynamicWidget> Render for CacheWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        // Cloning into the buffer reuses its rows.
+        buf.data.clone_from(
+            &self
+                .cache
+                .borrow_mut()
+                .get_or_insert_with(|| self.child.string_data())
+                .data,
+        );
+    }
+}
+
+impl_single_child!([T: DynamicWidget] CacheWidget<T>);
+
+impl<T: DynamicWidget> const Deref for CacheWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for CacheWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Scales the child up by repeating each of its cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleWidget<T: DynamicWidget> {
+    child: T,
+    scale_x: usize,
+    scale_y: usize,
+}
+
+impl<T: DynamicWidget> ScaleWidget<T> {
+    impl_new!(pub const ScaleWidget<T>, child: T, scale_x: usize, scale_y: usize);
+
+    impl_getters!(pub const child: T, pub const scale_x: usize, pub const scale_y: usize);
+
+    impl_setters!(pub const scale_x: usize, pub const scale_y: usize);
+}
+
+impl<T: DynamicWidget> DynamicWidget for ScaleWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters() * self.scale_x
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters() * self.scale_y
+    }
+
+    fn string_data(&self) -> StringData {
+        StringData {
+            data: self
+                .child
+                .string_data()
+                .data
+                .into_iter()
+                .flat_map(|line| {
+                    let line = line
+                        .into_iter()
+                        .flat_map(|cell| vec![cell; self.scale_x])
+                        .collect::<Vec<_>>();
+                    vec![line; self.scale_y]
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for ScaleWidget<T> {
+    fn child_regions(&self) -> Vec<Region> {
+        vec![Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |_, x, y| {
+            self.child.widget_at(x / self.scale_x, y / self.scale_y)
+        })
+    }
+}
+
+impl_single_child!(describe [T: DynamicWidget] ScaleWidget<T>);
+
+impl<T: DynamicWidget> const Deref for ScaleWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for ScaleWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Rotates the character grid of the child by 90 degrees, clockwise or counterclockwise.
+/// Only the arrangement of the cells is rotated, the characters themselves stay upright.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotate90Widget<T: DynamicWidget> {
+    child: T,
+    clockwise: bool,
+}
+
+impl<T: DynamicWidget> Rotate90Widget<T> {
+    /// Builds a widget rotating the child clockwise or counterclockwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the child contains characters spanning multiple columns,
+    /// since those can not be rotated.
+    /// Wide characters introduced into the child later, e.g. through `DerefMut`,
+    /// are shown as spaces in their colors.
+    pub fn build(child: T, clockwise: bool) -> Result<Self, WidgetError> {
+        if let Some(cell) =
+            child.string_data().data.iter().flatten().find(|cell| {
+                UnicodeWidthChar::width(cell.character).unwrap_or(0) > 1
+            })
+        {
+            return Err(WidgetError::WideCharacter(cell.character));
+        }
+        Ok(Self { child, clockwise })
+    }
+
+    impl_getters!(pub const child: T, pub const clockwise: bool);
+
+    impl_setters!(pub const clockwise: bool);
+}
+
+impl<T: DynamicWidget> DynamicWidget for Rotate90Widget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let (width, height) =
+            (self.width_characters(), self.height_characters());
+        let mut data = StringData {
+            data: self
+                .child
+                .string_data()
+                .data
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .flat_map(|cell| {
+                            match UnicodeWidthChar::width(cell.character) {
+                                Some(columns @ 2..) => iter::repeat_n(
+                                    DataCell {
+                                        character: ' ',
+                                        ..cell
+                                    },
+                                    columns,
+                                ),
+                                _ => iter::repeat_n(cell, 1),
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        };
+        // The child may have changed since building, e.g. through `DerefMut`.
+        data.fit(height, width);
+        let data = data.data;
+        StringData {
+            data: (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| {
+                            if self.clockwise {
+                                data[width - 1 - x][y]
+                            }
+                            else {
+                                data[x][height - 1 - y]
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for Rotate90Widget<T> {
+    fn child_regions(&self) -> Vec<Region> {
+        vec![Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        let (width, height) =
+            (self.width_characters(), self.height_characters());
+        resolve_hit(self, column, row, |_, x, y| {
+            if self.clockwise {
+                self.child.widget_at(y, width - 1 - x)
+            }
+            else {
+                self.child.widget_at(height - 1 - y, x)
+            }
+        })
+    }
+}
+
+impl_single_child!(describe [T: DynamicWidget] Rotate90Widget<T>);
+
+impl<T: DynamicWidget> const Deref for Rotate90Widget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for Rotate90Widget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Alternates between showing the child and showing blank cells.
+/// The widget has no notion of time, `advance` has to be called once per frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlinkWidget<T: DynamicWidget> {
+    child: T,
+    period: usize,
+    frame: usize,
+}
+
+impl<T: DynamicWidget> BlinkWidget<T> {
+    /// Creates a blink widget that shows the child for `period` frames,
+    /// then blank cells for `period` frames, starting visible.
+    /// A period of 0 keeps the child visible.
+    pub const fn new(child: T, period: usize) -> Self {
+        Self {
+            child,
+            period,
+            frame: 0,
+        }
+    }
+
+    impl_getters!(pub const child: T, pub const period: usize);
+
+    /// Sets the number of frames per phase and restarts the blinking in the visible phase.
+    pub const fn set_period(&mut self, period: usize) {
+        self.period = period;
+        self.frame = 0;
+    }
+
+    /// Advances the blinking by one frame.
+    pub const fn advance(&mut self) {
+        if self.period > 0 {
+            self.frame = (self.frame + 1) % (2 * self.period);
+        }
+    }
+
+    /// Returns whether the child is currently shown.
+    pub const fn visible(&self) -> bool {
+        self.frame < self.period || self.period == 0
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for BlinkWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.child.width_characters()
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<T: DynamicWidget> Render for BlinkWidget<T> {
+    fn render_into(&self, buf: &mut StringData) {
+        if self.visible() {
+            self.child.render_into(buf);
+        }
+        else {
+            let height = self.height_characters();
+            buf.clear_rows(height);
+            buf.fit(self.width_characters(), height);
+        }
+    }
+}
+
+impl_single_child!([T: DynamicWidget] BlinkWidget<T>);
+
+impl<T: DynamicWidget> const Deref for BlinkWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for BlinkWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+/// Scrolls the child horizontally through a viewport of fixed width, like a news ticker.
+/// The child repeats endlessly with a gap of blank cells between the repetitions.
+/// `advance` has to be called once per frame to move the content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarqueeWidget<T: DynamicWidget> {
+    child: T,
+    width: usize,
+    gap: usize,
+    speed: usize,
+    offset: usize,
+}
+
+impl<T: DynamicWidget> MarqueeWidget<T> {
+    /// Creates a marquee widget showing `width` columns of the child,
+    /// moving it to the left by `speed` columns per call to `advance`.
+    pub const fn new(
+        child: T,
+        width: usize,
+        gap: usize,
+        speed: usize,
+    ) -> Self {
+        Self {
+            child,
+            width,
+            gap,
+            speed,
+            offset: 0,
+        }
+    }
+
+    impl_getters!(pub const child: T, pub const gap: usize, pub const speed: usize, pub const offset: usize);
+
+    impl_setters!(pub const gap: usize, pub const speed: usize);
+
+    /// Moves the content to the left by `speed` columns.
+    pub fn advance(&mut self) {
+        let period = self.period();
+        if period > 0 {
+            self.offset = (self.offset + self.speed) % period;
+        }
+    }
+
+    /// Returns the number of columns after which the content repeats.
+    fn period(&self) -> usize {
+        self.child.width_characters() + self.gap
+    }
+}
+
+impl<T: DynamicWidget> DynamicWidget for MarqueeWidget<T> {
+    fn width_characters(&self) -> usize {
+        self.width
+    }
+
+    fn height_characters(&self) -> usize {
+        self.child.height_characters()
+    }
+
+    fn string_data(&self) -> StringData {
+        let period = self.period();
+        StringData {
+            data: self
+                .child
+                .string_data()
+                .data
+                .into_iter()
+                .map(|mut line| {
+                    if period == 0 {
+                        return vec![DataCell::default(); self.width];
+                    }
+                    line.resize(period, DataCell::default());
+                    line.into_iter()
+                        .cycle()
+                        .skip(self.offset % period)
+                        .take(self.width)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: DynamicWidget> HitTest for MarqueeWidget<T> {
+    /// The child covers the whole widget, with its hidden parts cropped.
+    fn child_regions(&self) -> Vec<Region> {
+        vec![Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        )]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        let period = self.period();
+        resolve_hit(self, column, row, |_, x, y| {
+            if period == 0 {
+                return None;
+            }
+            let x = (x + self.offset) % period;
+            if x < self.child.width_characters() {
+                self.child.widget_at(x, y)
+            }
+            else {
+                None
+            }
+        })
+    }
+}
+
+impl_single_child!(describe [T: DynamicWidget] MarqueeWidget<T>);
+
+impl<T: DynamicWidget> const Deref for MarqueeWidget<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<T: DynamicWidget> const DerefMut for MarqueeWidget<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pixel::monochrome_pixel::SinglePixel,
+        pixel_display::StaticPixelDisplay,
+    };
+
+    use super::*;
+
+    mod uv_widget {
+        use crate::drawing::Line;
+
+        use super::*;
+
+        #[test]
+        fn texture_to_uv() {
+            let expected = 0.0005;
+            let actual = UvWidget::<
+                StaticPixelDisplay<SinglePixel, 1, 1>,
+                SinglePixel,
+            >::texture_to_uv(500, 1000, -0.5, 0.5);
+            let error = expected * 0.0001;
+            assert!((actual - 0.0005).abs() < error);
+        }
+
+        #[test]
+        fn uv_to_texture() {
+            let expected = 1500;
+            let actual = UvWidget::<
+                StaticPixelDisplay<SinglePixel, 1, 1>,
+                SinglePixel,
+            >::uv_to_texture(0.5, -1.0, 1.0, 2000);
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn pixel_non_square() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 4, 8>::new(false),
+                );
+            widget.set_uv_x_min(0.0);
+            widget.set_uv_x_max(1.0);
+            widget.set_uv_y_min(0.0);
+            widget.set_uv_y_max(1.0);
+            widget.set_pixel(0.3, 0.8, true).unwrap();
+            assert_eq!(widget.pixel(0.3, 0.8), Ok(true));
+            assert_eq!(widget.child().pixel(1, 6), Ok(true));
+        }
+
+        fn wrap_mode_widget(
+            mode: UvWrapMode,
+        ) -> UvWidget<StaticPixelDisplay<SinglePixel, 4, 1>, SinglePixel>
+        {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 4, 1>::new(false),
+                );
+            widget.set_uv_x_min(0.0);
+            widget.set_uv_x_max(1.0);
+            widget.set_uv_y_min(0.0);
+            widget.set_uv_y_max(1.0);
+            widget.set_wrap_mode(mode);
+            widget
+        }
+
+        #[test]
+        fn wrap_mode_clamp() {
+            let mut widget = wrap_mode_widget(UvWrapMode::default());
+            widget.set_pixel(1.1, 0.5, true).unwrap();
+            assert_eq!(widget.child().pixel(3, 0), Ok(true));
+            assert_eq!(widget.pixel(1.1, 0.5), Ok(true));
+            assert_eq!(widget.pixel(-0.1, 0.5), Ok(false));
+        }
+
+        #[test]
+        fn wrap_mode_wrap() {
+            let mut widget = wrap_mode_widget(UvWrapMode::Wrap);
+            widget.set_pixel(1.1, 0.5, true).unwrap();
+            assert_eq!(widget.child().pixel(0, 0), Ok(true));
+            assert_eq!(widget.pixel(0.1, 0.5), Ok(true));
+            assert_eq!(widget.pixel(-0.1, 0.5), Ok(false));
+        }
+
+        #[test]
+        fn wrap_mode_error() {
+            let mut widget = wrap_mode_widget(UvWrapMode::Error);
+            assert_eq!(
+                widget.set_pixel(1.1, 0.5, true),
+                Err(WidgetError::UvCoordinateOutOfBounds('x').into())
+            );
+            assert_eq!(
+                widget.pixel(1.1, 0.5),
+                Err(WidgetError::UvCoordinateOutOfBounds('x').into())
+            );
+            assert_eq!(widget.pixel(1.0, 0.5), Ok(false));
+        }
+
+        #[test]
+        fn bilinear_mixes_colors() {
+            use crate::{
+                color::RGBColor,
+                pixel::color_pixel::ColorSinglePixel,
+            };
+
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<ColorSinglePixel, 2, 1>::new(
+                        RGBColor::BLUE.into(),
+                    ),
+                );
+            widget.set_uv_x_min(0.0);
+            widget.set_uv_x_max(1.0);
+            widget.set_uv_y_min(0.0);
+            widget.set_uv_y_max(1.0);
+            widget.set_pixel(0.25, 0.5, RGBColor::RED.into()).unwrap();
+            widget.set_sampling(Sampling::Bilinear);
+            assert_eq!(widget.pixel(0.25, 0.5), Ok(RGBColor::RED.into()));
+            assert_eq!(
+                widget.pixel(0.5, 0.5),
+                Ok(RGBColor {
+                    r: 127,
+                    g: 0,
+                    b: 127
+                }
+                .into())
+            );
+        }
+
+        #[test]
+        fn bilinear_falls_back_to_nearest() {
+            let mut widget = wrap_mode_widget(UvWrapMode::Clamp);
+            widget.set_pixel(0.1, 0.5, true).unwrap();
+            widget.set_sampling(Sampling::Bilinear);
+            assert_eq!(widget.pixel(0.2, 0.5), Ok(true));
+            assert_eq!(widget.pixel(0.3, 0.5), Ok(false));
+        }
+
+        #[test]
+        fn log10_midpoint_is_geometric_mean() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 3, 1>::new(false),
+                );
+            widget.set_uv_x_min(1.);
+            widget.set_uv_x_max(100.);
+            widget.set_axis_x_scale(AxisScale::Log10);
+            assert!((widget.texture_to_uv_x(1) - 10.).abs() < 1e-4);
+            assert_eq!(widget.uv_x_to_texture(10.), 1);
+            widget.set_pixel(10., 0.5, true).unwrap();
+            assert_eq!(widget.child().pixel(1, 0), Ok(true));
+        }
+
+        #[test]
+        fn log10_clamps_non_positive() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 3, 1>::new(false),
+                );
+            widget.set_uv_x_min(1.);
+            widget.set_uv_x_max(100.);
+            widget.set_axis_x_scale(AxisScale::Log10);
+            assert_eq!(widget.uv_x_to_texture(-5.), 0);
+            assert!(AxisScale::Log10.apply(0.).is_finite());
+        }
+
+        #[test]
+        fn draw_clips_to_uv_bounds() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 4, 4>::new(false),
+                );
+            widget.set_uv_x_min(-1.);
+            widget.set_uv_x_max(1.);
+            widget.set_uv_y_min(-1.);
+            widget.set_uv_y_max(1.);
+            widget.draw(
+                &Line {
+                    x1: -3.,
+                    y1: 0.,
+                    x2: 3.,
+                    y2: 0.,
+                },
+                true,
+            );
+            for y in 0..4 {
+                for x in 0..4 {
+                    assert_eq!(widget.child().pixel(x, y), Ok(y == 2));
+                }
+            }
+        }
+
+        #[test]
+        fn draw_clips_to_flipped_uv_bounds() {
+            let mut widget =
+                UvWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 4, 4>::new(false),
+                );
+            widget.set_uv_x_min(-1.);
+            widget.set_uv_x_max(1.);
+            widget.set_uv_y_min(1.);
+            widget.set_uv_y_max(-1.);
+            assert_eq!(widget.texture_region(), Region::new(0, 0, 4, 4));
+            widget.draw(
+                &Line {
+                    x1: 0.5,
+                    y1: -3.,
+                    x2: 0.5,
+                    y2: 3.,
+                },
+                true,
+            );
+            for y in 0..4 {
+                for x in 0..4 {
+                    assert_eq!(widget.child().pixel(x, y), Ok(x == 3));
+                }
+            }
+        }
+    }
+
+    mod double_buffer_widget {
+        use crate::console_display::StaticConsoleDisplay;
+
+        use super::*;
+
+        #[test]
+        fn buffer_swap() {
+            let mut widget =
+                DoubleBufferWidget::new(StaticPixelDisplay::<
+                    SinglePixel,
+                    1,
+                    1,
+                >::new(false));
+            widget.set_pixel_static::<0, 0>(true);
+            assert!(!widget.pixel_static::<0, 0>());
+            widget.swap_buffers();
+            assert!(widget.pixel_static::<0, 0>());
+            assert_eq!(widget.string_data().to_string(), "█");
+        }
+
+        #[test]
+        fn swap_on_render() {
+            let mut widget =
+                DoubleBufferWidget::new(StaticPixelDisplay::<
+                    SinglePixel,
+                    1,
+                    1,
+                >::new(false));
+            widget.set_pixel_static::<0, 0>(true);
+            assert!(!widget.pixel_static::<0, 0>());
+            assert_eq!(widget.string_data().to_string(), "█");
+            assert!(widget.pixel_static::<0, 0>());
+            assert_eq!(widget.string_data().to_string(), "█");
+        }
+
+        #[test]
+        fn borrow_across_swap() {
+            let mut widget =
+                DoubleBufferWidget::new(StaticPixelDisplay::<
+                    SinglePixel,
+                    1,
+                    1,
+                >::new(false));
+            widget.set_pixel_static::<0, 0>(true);
+            let front = widget.child();
+            widget.swap_buffers();
+            let rendered = widget.string_data();
+            assert!(!front.pixel_static::<0, 0>());
+            assert!(widget.child().pixel_static::<0, 0>());
+            assert_eq!(rendered.to_string(), "█");
+        }
+    }
+    mod trail_widget {
+        use super::*;
+        use crate::{
+            color::RGBColor,
+            pixel::color_pixel::ColorSinglePixel,
+        };
+
+        #[test]
+        fn step() {
+            let mut widget = TrailWidget::new(
+                StaticPixelDisplay::<ColorSinglePixel, 2, 1>::new(
+                    RGBColor::BLACK.into(),
+                ),
+                RGBColor::BLACK.into(),
+                0.5,
+            );
+            widget.set_pixel(0, 0, RGBColor::WHITE.into()).unwrap();
+            widget.step();
+            assert_eq!(widget.pixel(0, 0), Ok(RGBColor::GRAY.into()));
+            assert_eq!(widget.pixel(1, 0), Ok(RGBColor::BLACK.into()));
+        }
+
+        #[test]
+        fn step_default_background() {
+            let mut widget = TrailWidget::new(
+                StaticPixelDisplay::<ColorSinglePixel, 1, 1>::new(
+                    RGBColor::RED.into(),
+                ),
+                TerminalColor::Default,
+                1.,
+            );
+            widget.step();
+            assert_eq!(
+                widget.pixel(0, 0),
+                Ok(ARGBColor {
+                    opacity: 0,
+                    color: RGBColor::RED
+                }
+                .into())
+            );
+        }
+    }
+
+    mod scroll_widget {
+        use super::*;
+        use crate::character_display::DynamicCharacterDisplay;
+
+        fn rows(widget: &impl DynamicWidget) -> Vec<String> {
+            widget
+                .string_data()
+                .data
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.character).collect())
+                .collect()
+        }
+
+        #[test]
+        fn scroll() {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "ab\ncd\nef\ngh\nij",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let mut widget = ScrollWidget::new(child, 1, 2);
+            assert_eq!(widget.width_characters(), 1);
+            assert_eq!(widget.height_characters(), 2);
+            assert_eq!(rows(&widget), ["a", "c"]);
+
+            widget.scroll_by(1, 2);
+            assert_eq!(rows(&widget), ["f", "h"]);
+
+            widget.scroll_by(-3, 10);
+            assert_eq!((*widget.offset_x(), *widget.offset_y()), (0, 3));
+            assert_eq!(rows(&widget), ["g", "i"]);
+
+            widget.scroll_to(5, 1);
+            assert_eq!((*widget.offset_x(), *widget.offset_y()), (1, 1));
+            assert_eq!(rows(&widget), ["d", "f"]);
+        }
+
+        #[test]
+        fn wide_characters() {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "a界b\n界cd",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let mut widget = ScrollWidget::new(child, 2, 2);
+            assert_eq!(rows(&widget), ["a ", "界"]);
+
+            widget.scroll_to(1, 0);
+            assert_eq!(rows(&widget), ["界", " c"]);
+
+            widget.scroll_to(2, 0);
+            assert_eq!(rows(&widget), [" b", "cd"]);
+        }
+    }
+
+    mod center_widget {
+        use super::*;
+        use crate::character_display::DynamicCharacterDisplay;
+
+        fn child() -> DynamicCharacterDisplay<CharacterPixel> {
+            DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "ab\ncd",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn center() {
+            let widget = CenterWidget::new(child(), 6, 6);
+            assert_eq!(widget.width_characters(), 6);
+            assert_eq!(widget.height_characters(), 6);
+            assert_eq!(widget.child_regions(), [Region::new(2, 2, 2, 2)]);
+
+            let data = widget.string_data().data;
+            assert_eq!(data.len(), 6);
+            assert!(data.iter().all(|row| row.len() == 6));
+            assert_eq!(data[2][2].character, 'a');
+            assert_eq!(data[3][3].character, 'd');
+            assert_eq!(data[0][0].character, ' ');
+            assert_eq!(data[5][5].character, ' ');
+        }
+
+        #[test]
+        fn uneven_space() {
+            let mut widget = CenterWidget::new(child(), 5, 5);
+            assert_eq!(widget.child_regions(), [Region::new(1, 1, 2, 2)]);
+
+            widget.set_width(1);
+            assert_eq!(widget.width_characters(), 2);
+            assert_eq!(widget.child_regions(), [Region::new(0, 1, 2, 2)]);
+        }
+
+        #[test]
+        fn wide_character() {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "あb",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let widget = CenterWidget::new(child, 5, 1);
+            assert_eq!(widget.string_data().to_string(), " あb ");
+        }
+    }
+
+    mod opacity_widget {
+        use super::*;
+        use crate::{
+            character_display::DynamicCharacterDisplay,
+            color::RGBColor,
+        };
+
+        #[test]
+        fn transparent() {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "ab",
+                RGBColor::RED.into(),
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let mut widget = OpacityWidget::new(child, 255);
+            assert_eq!(widget.string_data(), widget.child().string_data());
+
+            widget.set_opacity(0);
+            let data = widget.string_data().data;
+            assert_eq!(data[0][0].character, 'a');
+            assert_eq!(data[0][1].character, 'b');
+            for cell in &data[0] {
+                assert_eq!(
+                    cell.foreground,
+                    TerminalColor::ARGBColor(ARGBColor {
+                        opacity: 0,
+                        color: RGBColor::RED,
+                    })
+                );
+                assert_eq!(cell.background, TerminalColor::Default);
+            }
+        }
+    }
+
+    mod tint_widget {
+        use super::*;
+        use crate::{
+            color::RGBColor,
+            pixel::color_pixel::ColorSinglePixel,
+        };
+
+        #[test]
+        fn tint() {
+            let mut widget = TintWidget::new(
+                StaticPixelDisplay::<ColorSinglePixel, 2, 2>::new(
+                    RGBColor::WHITE.into(),
+                ),
+                RGBColor::RED.into(),
+                0.,
+            );
+            assert_eq!(widget.string_data(), widget.child().string_data());
+
+            widget.set_factor(1.);
+            for cell in widget.string_data().data.iter().flatten() {
+                assert_eq!(cell.foreground, RGBColor::RED.into());
+                assert_eq!(cell.background, RGBColor::RED.into());
+            }
+        }
+    }
+
+    mod cache_widget {
+        use super::*;
+        use crate::pixel_display::DynamicPixelDisplay;
+
+        #[test]
+        fn invalidate() {
+            let mut widget =
+                CacheWidget::new(DynamicPixelDisplay::<SinglePixel>::new(
+                    1, 1, false,
+                ));
+            let data = widget.string_data();
+            assert_eq!(widget.string_data(), data);
+
+            widget.set_pixel(0, 0, true).unwrap();
+            assert_eq!(widget.string_data(), data);
+
+            widget.invalidate();
+            assert_ne!(widget.string_data(), data);
+            assert_eq!(widget.string_data(), widget.child().string_data());
+        }
+    }
+
+    mod scale_widget {
+        use super::*;
+
+        #[test]
+        fn scale() {
+            let widget = ScaleWidget::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(true),
+                3,
+                2,
+            );
+            assert_eq!(widget.width_characters(), 3);
+            assert_eq!(widget.height_characters(), 2);
+
+            let cell = widget.child().string_data().data[0][0];
+            assert_eq!(widget.string_data().data, vec![vec![cell; 3]; 2]);
+        }
+    }
+
+    mod rotate_90_widget {
+        use super::*;
+        use crate::character_display::DynamicCharacterDisplay;
+
+        fn child(text: &str) -> DynamicCharacterDisplay<CharacterPixel> {
+            DynamicCharacterDisplay::<CharacterPixel>::fit(
+                text,
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap()
+        }
+
+        fn characters(widget: &impl DynamicWidget) -> Vec<Vec<char>> {
+            widget
+                .string_data()
+                .data
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.character).collect())
+                .collect()
+        }
+
+        #[test]
+        fn clockwise() {
+            let widget =
+                Rotate90Widget::build(child("abc"), true).unwrap();
+            assert_eq!(widget.width_characters(), 1);
+            assert_eq!(widget.height_characters(), 3);
+            assert_eq!(characters(&widget), [['a'], ['b'], ['c']]);
+
+            let widget =
+                Rotate90Widget::build(child("ab\ncd"), true).unwrap();
+            assert_eq!(characters(&widget), [['c', 'a'], ['d', 'b']]);
+        }
+
+        #[test]
+        fn counterclockwise() {
+            let widget =
+                Rotate90Widget::build(child("abc"), false).unwrap();
+            assert_eq!(characters(&widget), [['c'], ['b'], ['a']]);
+
+            let widget =
+                Rotate90Widget::build(child("ab\ncd"), false).unwrap();
+            assert_eq!(characters(&widget), [['b', 'd'], ['a', 'c']]);
+        }
+
+        #[test]
+        fn wide_character() {
+            let widget = Rotate90Widget::build(child("あ"), true);
+            assert_eq!(widget, Err(WidgetError::WideCharacter('あ')));
+        }
+
+        #[test]
+        fn wide_character_after_build() {
+            use crate::character_display::TextCursor;
+
+            let mut widget =
+                Rotate90Widget::build(child("abc"), true).unwrap();
+            TextCursor::new(&mut *widget).put_str("あ").unwrap();
+            assert_eq!(characters(&widget), [[' '], [' '], ['c']]);
+        }
+    }
+
+    mod blink_widget {
+        use super::*;
+
+        #[test]
+        fn blink() {
+            let mut widget = BlinkWidget::new(
+                StaticPixelDisplay::<SinglePixel, 2, 1>::new(true),
+                2,
+            );
+            let visible = widget.child().string_data();
+            let blank = StringData {
+                data: vec![vec![DataCell::default(); 2]],
+            };
+
+            let mut frames = Vec::new();
+            for _ in 0..5 {
+                frames.push(widget.string_data());
+                widget.advance();
+            }
+            assert_eq!(
+                frames,
+                [
+                    visible.clone(),
+                    visible.clone(),
+                    blank.clone(),
+                    blank,
+                    visible
+                ]
+            );
+
+            widget.set_period(0);
+            widget.advance();
+            assert!(widget.visible());
+        }
+    }
+
+    mod marquee_widget {
+        use super::*;
+        use crate::character_display::DynamicCharacterDisplay;
+
+        #[test]
+        fn advance() {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "abcde",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let mut widget = MarqueeWidget::new(child, 3, 2, 1);
+            assert_eq!(widget.width_characters(), 3);
+            assert_eq!(widget.height_characters(), 1);
+
+            let mut frames = Vec::new();
+            for _ in 0..8 {
+                frames.push(widget.string_data().to_plain_string());
+                widget.advance();
+            }
+            assert_eq!(
+                frames,
+                ["abc", "bcd", "cde", "de ", "e  ", "  a", " ab", "abc"]
+            );
+
+            widget.set_speed(3);
+            widget.advance();
+            assert_eq!(widget.string_data().to_plain_string(), "e  ");
+        }
+    }
+
+    mod align_widget {
+        use super::*;
+        use crate::character_display::DynamicCharacterDisplay;
+
+        fn offset(
+            horizontal_align: HorizontalAlign,
+            vertical_align: VerticalAlign,
+        ) -> (usize, usize) {
+            let child = DynamicCharacterDisplay::<CharacterPixel>::fit(
+                "ab\ncd",
+                TerminalColor::Default,
+                TerminalColor::Default,
+            )
+            .unwrap();
+            let widget = AlignWidget::new(
+                child,
+                7,
+                5,
+                horizontal_align,
+                vertical_align,
+            );
+            let data = widget.string_data().data;
+            assert_eq!(data.len(), 5);
+            assert!(data.iter().all(|row| row.len() == 7));
+            let region = widget.child_regions()[0];
+            assert_eq!(data[region.y][region.x].character, 'a');
+            (region.x, region.y)
+        }
+
+        #[test]
+        fn top_left() {
+            assert_eq!(
+                offset(HorizontalAlign::Left, VerticalAlign::Top),
+                (0, 0)
+            );
+        }
+
+        #[test]
+        fn top_right() {
+            assert_eq!(
+                offset(HorizontalAlign::Right, VerticalAlign::Top),
+                (5, 0)
+            );
+        }
+
+        #[test]
+        fn bottom_left() {
+            assert_eq!(
+                offset(HorizontalAlign::Left, VerticalAlign::Bottom),
+                (0, 3)
+            );
+        }
+
+        #[test]
+        fn bottom_right() {
+            assert_eq!(
+                offset(HorizontalAlign::Right, VerticalAlign::Bottom),
+                (5, 3)
+            );
+        }
+
+        #[test]
+        fn center() {
+            assert_eq!(
+                offset(HorizontalAlign::Center, VerticalAlign::Center),
+                (2, 1)
+            );
+        }
+    }
+
+    mod grayscale_widget {
+        use super::*;
+        use crate::{
+            color::RGBColor,
+            pixel::color_pixel::ColorSinglePixel,
+        };
+
+        #[test]
+        fn string_data() {
+            let widget = GrayscaleWidget::new(StaticPixelDisplay::<
+                ColorSinglePixel,
+                1,
+                1,
+            >::new(
+                RGBColor::GREEN.into()
+            ));
+            let cell = widget.string_data().data[0][0];
+            let gray = RGBColor {
+                r: 182,
+                g: 182,
+                b: 182,
+            };
+            assert_eq!(cell.foreground, gray.into());
+            assert_eq!(cell.background, gray.into());
+        }
+    }
+
+    mod padding_widget {
+        use super::*;
+
+        #[test]
+        fn dimensions() {
+            let widget = PaddingWidget::new(
+                StaticPixelDisplay::<SinglePixel, 1, 1>::new(false),
+                10,
+                20,
+                30,
+                40,
+            );
+            assert_eq!(widget.width_characters(), 31);
             assert_eq!(widget.height_characters(), 71);
         }
+
+        #[test]
+        fn widget_at() {
+            let widget = PaddingWidget::new(
+                StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+                1,
+                0,
+                2,
+                0,
+            );
+            assert_eq!(widget.widget_at(0, 0), Some(WidgetId::root()));
+            assert_eq!(
+                widget.widget_at(1, 2),
+                Some(WidgetId::new(vec![0]))
+            );
+            assert_eq!(
+                widget.widget_at(2, 3),
+                Some(WidgetId::new(vec![0]))
+            );
+            assert_eq!(widget.widget_at(3, 3), None);
+        }
     }
 }
@@ -18,8 +18,16 @@ use crate::{
     impl_getters,
     impl_setters,
     widget::{
+        Describe,
         DynamicWidget,
+        HitTest,
+        Region,
+        Render,
+        ScratchBuffer,
         StringData,
+        WidgetId,
+        describe_node,
+        resolve_hit,
     },
 };
 
@@ -106,6 +114,54 @@ impl<S: [const] DynamicWidget, T: [const] DynamicWidget> const
     }
 }
 
+impl<S: DynamicWidget, T: DynamicWidget> Render
+    for AlternativeWidget<S, T>
+{
+    fn render_into(&self, buf: &mut StringData) {
+        if self.child1_on_top {
+            self.children.0.render_into(buf);
+        }
+        else {
+            self.children.1.render_into(buf);
+        }
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> HitTest
+    for AlternativeWidget<S, T>
+{
+    /// Only the child that is currently displayed occupies the widget.
+    fn child_regions(&self) -> Vec<Region> {
+        let region = Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        );
+        if self.child1_on_top {
+            vec![region]
+        }
+        else {
+            vec![Region::new(0, 0, 0, 0), region]
+        }
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| match index {
+            0 => self.0.widget_at(x, y),
+            _ => self.1.widget_at(x, y),
+        })
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Describe
+    for AlternativeWidget<S, T>
+{
+    fn describe(&self) -> String {
+        describe_node(self, &[self.0.describe(), self.1.describe()])
+    }
+}
+
 impl<S: DynamicWidget, T: DynamicWidget> const Deref
     for AlternativeWidget<S, T>
 {
@@ -127,6 +183,7 @@ impl<S: DynamicWidget, T: DynamicWidget> const DerefMut
 #[derive(TwoWidget, Debug, Clone, PartialEq, Eq)]
 pub struct HorizontalTilingWidget<S: DynamicWidget, T: DynamicWidget> {
     children: (S, T),
+    scratch: ScratchBuffer,
 }
 
 impl<S: DynamicWidget, T: DynamicWidget> HorizontalTilingWidget<S, T> {
@@ -145,6 +202,7 @@ impl<S: DynamicWidget, T: DynamicWidget> HorizontalTilingWidget<S, T> {
         }
         Ok(Self {
             children: (child1, child2),
+            scratch: ScratchBuffer::new(),
         })
     }
 
@@ -172,6 +230,7 @@ impl<S: StaticWidget, T: StaticWidget> HorizontalTilingWidget<S, T> {
     {
         Self {
             children: (child1, child2),
+            scratch: ScratchBuffer::new(),
         }
     }
 }
@@ -198,19 +257,62 @@ impl<S: DynamicWidget, T: DynamicWidget> DynamicWidget
     }
 
     fn string_data(&self) -> StringData {
-        StringData {
-            data: self
-                .0
-                .string_data()
-                .data
-                .into_iter()
-                .zip(self.children.1.string_data().data)
-                .map(|lines| [lines.0, lines.1].concat())
-                .collect(),
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Render
+    for HorizontalTilingWidget<S, T>
+{
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the width they report and the height of the left child.
+    fn render_into(&self, buf: &mut StringData) {
+        let height = self.height_characters();
+        self.0.render_into(buf);
+        buf.fit(self.0.width_characters(), height);
+        let mut right = self.scratch.lock();
+        self.1.render_into(&mut right);
+        right.fit(self.1.width_characters(), height);
+        for (row, right_row) in buf.data.iter_mut().zip(&right.data) {
+            row.extend_from_slice(right_row);
         }
     }
 }
 
+impl<S: DynamicWidget, T: DynamicWidget> HitTest
+    for HorizontalTilingWidget<S, T>
+{
+    fn child_regions(&self) -> Vec<Region> {
+        let left_width = self.0.width_characters();
+        vec![
+            Region::new(0, 0, left_width, self.0.height_characters()),
+            Region::new(
+                left_width,
+                0,
+                self.1.width_characters(),
+                self.1.height_characters(),
+            ),
+        ]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| match index {
+            0 => self.0.widget_at(x, y),
+            _ => self.1.widget_at(x, y),
+        })
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Describe
+    for HorizontalTilingWidget<S, T>
+{
+    fn describe(&self) -> String {
+        describe_node(self, &[self.0.describe(), self.1.describe()])
+    }
+}
+
 impl<S: DynamicWidget, T: DynamicWidget> const Deref
     for HorizontalTilingWidget<S, T>
 {
@@ -303,10 +405,61 @@ impl<S: DynamicWidget, T: DynamicWidget> DynamicWidget
     }
 
     fn string_data(&self) -> StringData {
-        StringData {
-            data: [self.0.string_data().data, self.1.string_data().data]
-                .concat(),
-        }
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Render
+    for VerticalTilingWidget<S, T>
+{
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the height they report and the width of the top child.
+    fn render_into(&self, buf: &mut StringData) {
+        let width = self.width_characters();
+        let top_height = self.0.height_characters();
+        // The rows of the previous frame below the top child are reused for the bottom child.
+        let mut bottom = StringData {
+            data: buf.data.split_off(top_height.min(buf.data.len())),
+        };
+        self.0.render_into(buf);
+        buf.fit(width, top_height);
+        self.1.render_into(&mut bottom);
+        bottom.fit(width, self.1.height_characters());
+        buf.data.append(&mut bottom.data);
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> HitTest
+    for VerticalTilingWidget<S, T>
+{
+    fn child_regions(&self) -> Vec<Region> {
+        let top_height = self.0.height_characters();
+        vec![
+            Region::new(0, 0, self.0.width_characters(), top_height),
+            Region::new(
+                0,
+                top_height,
+                self.1.width_characters(),
+                self.1.height_characters(),
+            ),
+        ]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| match index {
+            0 => self.0.widget_at(x, y),
+            _ => self.1.widget_at(x, y),
+        })
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Describe
+    for VerticalTilingWidget<S, T>
+{
+    fn describe(&self) -> String {
+        describe_node(self, &[self.0.describe(), self.1.describe()])
     }
 }
 
@@ -331,6 +484,8 @@ impl<S: DynamicWidget, T: DynamicWidget> const DerefMut
 #[derive(StaticWidget, Debug, Clone, PartialEq, Eq)]
 pub struct OverlayWidget<S: DynamicWidget, T: DynamicWidget> {
     children: (S, T),
+    scratch: ScratchBuffer,
+    linear_blending: bool,
 }
 
 impl<S: StaticWidget, T: StaticWidget> OverlayWidget<S, T> {
@@ -341,6 +496,8 @@ impl<S: StaticWidget, T: StaticWidget> OverlayWidget<S, T> {
     {
         Self {
             children: (overlay, base),
+            scratch: ScratchBuffer::new(),
+            linear_blending: false,
         }
     }
 
@@ -380,8 +537,21 @@ impl<S: DynamicWidget, T: DynamicWidget> OverlayWidget<S, T> {
         }
         Ok(Self {
             children: (overlay, base),
+            scratch: ScratchBuffer::new(),
+            linear_blending: false,
         })
     }
+
+    /// Returns whether translucent colors are blended in linear light.
+    pub const fn linear_blending(&self) -> bool {
+        self.linear_blending
+    }
+
+    /// Sets whether translucent colors are blended in linear light instead of sRGB space.
+    /// Blending in sRGB space is the default.
+    pub const fn set_linear_blending(&mut self, linear_blending: bool) {
+        self.linear_blending = linear_blending;
+    }
 }
 
 impl<S: DynamicWidget, T: DynamicWidget> DynamicWidget
@@ -396,62 +566,67 @@ impl<S: DynamicWidget, T: DynamicWidget> DynamicWidget
     }
 
     fn string_data(&self) -> StringData {
-        let overlay = self.0.string_data().data;
-        let display = self.1.string_data().data;
-        StringData {
-            data: overlay
-                .into_iter()
-                .zip(display)
-                .map(|(overlay_row, display_row)| {
-                    overlay_row
-                        .into_iter()
-                        .zip(display_row)
-                        .map(|(cell_top, cell_bottom)| {
-                            let mut cell = cell_top;
-                            // TODO: Rework this blending
-                            if let TerminalColor::ARGBColor(foreground) =
-                                cell.foreground &&
-                                let TerminalColor::ARGBColor(background) =
-                                    cell.background &&
-                                background.opacity < u8::MAX / 2 &&
-                                foreground.opacity < u8::MAX / 2
-                            {
-                                cell.character = cell_bottom.character;
-
-                                cell.background = TerminalColor::blend(
-                                    &cell.background,
-                                    &cell_bottom.background,
-                                );
-                                cell.foreground = TerminalColor::blend(
-                                    &cell.foreground,
-                                    &cell_bottom.foreground,
-                                );
-                            }
-                            else if cell.background ==
-                                TerminalColor::Default ||
-                                cell.foreground ==
-                                    TerminalColor::Default
-                            {
-                                cell.character = cell_bottom.character;
-
-                                cell.background = TerminalColor::blend(
-                                    &cell.background,
-                                    &cell_bottom.background,
-                                );
-                                cell.foreground = TerminalColor::blend(
-                                    &cell.foreground,
-                                    &cell_bottom.foreground,
-                                );
-                            }
-                            cell
-                        })
-                        .collect()
-                })
-                .collect(),
+        let mut data = StringData::default();
+        self.render_into(&mut data);
+        data
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Render for OverlayWidget<S, T> {
+    /// Children whose dimensions changed after building are padded or truncated
+    /// to the dimensions of the overlay.
+    fn render_into(&self, buf: &mut StringData) {
+        let blend = if self.linear_blending {
+            TerminalColor::blend_linear
+        }
+        else {
+            TerminalColor::blend
+        };
+        let (width, height) =
+            (self.width_characters(), self.height_characters());
+        self.0.render_into(buf);
+        buf.fit(width, height);
+        let mut base = self.scratch.lock();
+        self.1.render_into(&mut base);
+        base.fit(width, height);
+        for (overlay_row, base_row) in buf.data.iter_mut().zip(&base.data)
+        {
+            for (cell_top, cell_bottom) in
+                overlay_row.iter_mut().zip(base_row)
+            {
+                *cell_top = cell_top.blend_over_with(cell_bottom, &blend);
+            }
         }
     }
 }
 
+impl<S: DynamicWidget, T: DynamicWidget> HitTest for OverlayWidget<S, T> {
+    /// Both children cover the whole widget.
+    /// As the overlay comes first, it receives all hits.
+    fn child_regions(&self) -> Vec<Region> {
+        let region = Region::new(
+            0,
+            0,
+            self.width_characters(),
+            self.height_characters(),
+        );
+        vec![region, region]
+    }
+
+    fn widget_at(&self, column: usize, row: usize) -> Option<WidgetId> {
+        resolve_hit(self, column, row, |index, x, y| match index {
+            0 => self.0.widget_at(x, y),
+            _ => self.1.widget_at(x, y),
+        })
+    }
+}
+
+impl<S: DynamicWidget, T: DynamicWidget> Describe for OverlayWidget<S, T> {
+    fn describe(&self) -> String {
+        describe_node(self, &[self.0.describe(), self.1.describe()])
+    }
+}
+
 impl<S: DynamicWidget, T: DynamicWidget> const Deref
     for OverlayWidget<S, T>
 {
@@ -474,6 +649,7 @@ impl<S: DynamicWidget, T: DynamicWidget> const DerefMut
 #[cfg(test)]
 mod tests {
     use crate::{
+        color::TerminalColor,
         pixel::{
             color_pixel::ColorSinglePixel,
             monochrome_pixel::SinglePixel,
@@ -593,6 +769,74 @@ mod tests {
                 horizontal_tiling.deref_mut().1
             );
         }
+
+        #[test]
+        fn resized_child() {
+            use crate::pixel_display::DynamicPixelDisplay;
+
+            let mut horizontal_tiling = HorizontalTilingWidget::build(
+                DynamicPixelDisplay::<SinglePixel>::new(2, 2, true),
+                DynamicPixelDisplay::<SinglePixel>::new(1, 2, true),
+            )
+            .unwrap();
+            horizontal_tiling.right_mut().resize(2, 1, true).unwrap();
+            assert_eq!(
+                horizontal_tiling.string_data().to_plain_string(),
+                "████\n██  "
+            );
+            horizontal_tiling.right_mut().resize(3, 3, true).unwrap();
+            assert_eq!(
+                horizontal_tiling.string_data().to_plain_string(),
+                "█████\n█████"
+            );
+        }
+    }
+
+    mod hit_test {
+        use super::*;
+
+        #[test]
+        fn horizontal_tiling() {
+            let widget = HorizontalTilingWidget::new(
+                StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+                VerticalTilingWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 3, 1>::new(false),
+                    StaticPixelDisplay::<SinglePixel, 3, 1>::new(false),
+                ),
+            );
+            assert_eq!(
+                widget.widget_at(1, 1),
+                Some(WidgetId::new(vec![0]))
+            );
+            assert_eq!(
+                widget.widget_at(4, 0),
+                Some(WidgetId::new(vec![1, 0]))
+            );
+            assert_eq!(
+                widget.widget_at(2, 1),
+                Some(WidgetId::new(vec![1, 1]))
+            );
+            assert_eq!(widget.widget_at(5, 0), None);
+            assert_eq!(widget.widget_at(0, 2), None);
+        }
+
+        #[test]
+        fn alternative() {
+            let mut widget = AlternativeWidget::new(
+                StaticPixelDisplay::<SinglePixel, 2, 2>::new(false),
+                StaticPixelDisplay::<SinglePixel, 2, 2>::new(true),
+                true,
+            );
+            assert_eq!(
+                widget.widget_at(1, 1),
+                Some(WidgetId::new(vec![0]))
+            );
+            widget.set_child1_on_top(false);
+            assert_eq!(
+                widget.widget_at(1, 1),
+                Some(WidgetId::new(vec![1]))
+            );
+        }
     }
 
     mod vertical_tiling {
@@ -648,6 +892,57 @@ mod tests {
                 vertical_tiling.deref_mut().1
             );
         }
+
+        #[test]
+        fn resized_child() {
+            use crate::pixel_display::DynamicPixelDisplay;
+
+            let mut vertical_tiling = VerticalTilingWidget::build(
+                DynamicPixelDisplay::<SinglePixel>::new(2, 1, true),
+                DynamicPixelDisplay::<SinglePixel>::new(2, 1, true),
+            )
+            .unwrap();
+            vertical_tiling.bottom_mut().resize(1, 2, true).unwrap();
+            assert_eq!(
+                vertical_tiling.string_data().to_plain_string(),
+                "██\n█ \n█ "
+            );
+            vertical_tiling.top_mut().resize(3, 1, true).unwrap();
+            assert_eq!(
+                vertical_tiling.string_data().to_plain_string(),
+                "███\n█  \n█  "
+            );
+        }
+    }
+
+    mod describe {
+        use crate::widget::single_widget::PaddingWidget;
+
+        use super::*;
+
+        #[test]
+        fn nested() {
+            let widget = VerticalTilingWidget::build(
+                HorizontalTilingWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 2, 1>::new(false),
+                    StaticPixelDisplay::<SinglePixel, 1, 1>::new(false),
+                ),
+                PaddingWidget::new(
+                    StaticPixelDisplay::<SinglePixel, 1, 1>::new(false),
+                    1,
+                    1,
+                    0,
+                    0,
+                ),
+            )
+            .unwrap();
+            assert_eq!(
+                widget.describe(),
+                "VerticalTilingWidget[3x2](\
+                 HorizontalTilingWidget[3x1](PixelDisplay[2x1], PixelDisplay[1x1]), \
+                 PaddingWidget[3x1](PixelDisplay[1x1]))"
+            );
+        }
     }
 
     mod overlay_widget {
@@ -737,5 +1032,45 @@ mod tests {
                 overlay.1.string_data().to_string()
             );
         }
+
+        #[test]
+        fn linear_blending() {
+            let top = StaticPixelDisplay::<ColorSinglePixel, 1, 1>::new(
+                ARGBColor {
+                    opacity: 100,
+                    color: RGBColor::WHITE,
+                }
+                .into(),
+            );
+            let bottom = StaticPixelDisplay::<ColorSinglePixel, 1, 1>::new(
+                RGBColor::BLACK.into(),
+            );
+            let mut overlay = OverlayWidget::new(top, bottom);
+            let srgb = overlay.string_data()[0][0].foreground;
+            overlay.set_linear_blending(true);
+            let linear = overlay.string_data()[0][0].foreground;
+
+            let (
+                TerminalColor::ARGBColor(srgb),
+                TerminalColor::ARGBColor(linear),
+            ) = (srgb, linear)
+            else {
+                panic!("Blended colors should not be default.");
+            };
+            assert!(linear.color.r > srgb.color.r);
+        }
+
+        #[test]
+        fn resized_child() {
+            let mut overlay = OverlayWidget::build(
+                DynamicPixelDisplay::<SinglePixel>::new(2, 2, false),
+                DynamicPixelDisplay::<SinglePixel>::new(2, 2, true),
+            )
+            .unwrap();
+            overlay.1.resize(1, 1, true).unwrap();
+            assert_eq!(overlay.string_data().to_plain_string(), "█ \n  ");
+            overlay.0.resize(1, 3, false).unwrap();
+            assert_eq!(overlay.string_data().to_plain_string(), "█\n \n ");
+        }
     }
 }